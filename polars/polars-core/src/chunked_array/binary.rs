@@ -0,0 +1,526 @@
+//! Variable length byte sequences backed by arrow's `LargeBinaryArray`.
+use crate::prelude::*;
+use crate::utils::NoNull;
+use arrow::array::{Array, ArrayRef, BinaryArray, LargeBinaryArray, LargeBinaryBuilder};
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push(HEX_CHARS[(b >> 4) as usize] as char);
+        out.push(HEX_CHARS[(b & 0xf) as usize] as char);
+    }
+    out
+}
+
+#[inline]
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Returns `None` if the string is not valid hexadecimal.
+pub(crate) fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.chunks(2)
+        .map(|pair| Some(hex_value(pair[0])? << 4 | hex_value(pair[1])?))
+        .collect()
+}
+
+/// Standard base64 with padding.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[inline]
+fn base64_value(c: u8) -> Option<u32> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as u32),
+        b'a'..=b'z' => Some((c - b'a' + 26) as u32),
+        b'0'..=b'9' => Some((c - b'0' + 52) as u32),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Returns `None` if the string is not valid (padded) base64.
+pub(crate) fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if s.len() % 4 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    let n_chunks = s.len() / 4;
+    for (i, chunk) in s.chunks(4).enumerate() {
+        // padding is only allowed at the end
+        let n_pad = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if n_pad > 2 || (n_pad > 0 && i + 1 != n_chunks) {
+            return None;
+        }
+        let mut n = 0u32;
+        for &c in &chunk[..4 - n_pad] {
+            n = n << 6 | base64_value(c)?;
+        }
+        n <<= 6 * n_pad as u32;
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        out.extend_from_slice(&bytes[..3 - n_pad]);
+    }
+    Some(out)
+}
+
+/// Convert a `BinaryArray` to the `LargeBinaryArray` that backs a `BinaryChunked`.
+pub(crate) fn to_large_binary(arr: &ArrayRef) -> ArrayRef {
+    let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+    let mut builder = LargeBinaryBuilder::new(arr.len());
+    for idx in 0..arr.len() {
+        if arr.is_valid(idx) {
+            builder.append_value(arr.value(idx)).unwrap();
+        } else {
+            builder.append_null().unwrap();
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+pub struct BinaryChunkedBuilder {
+    builder: LargeBinaryBuilder,
+    name: String,
+}
+
+impl BinaryChunkedBuilder {
+    /// Create a new BinaryChunkedBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes_capacity` - Number of bytes needed to store the values.
+    pub fn new(name: &str, bytes_capacity: usize) -> Self {
+        BinaryChunkedBuilder {
+            builder: LargeBinaryBuilder::new(bytes_capacity),
+            name: name.to_string(),
+        }
+    }
+
+    #[inline]
+    pub fn append_value<B: AsRef<[u8]>>(&mut self, v: B) {
+        self.builder.append_value(v.as_ref()).unwrap();
+    }
+
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.builder.append_null().unwrap();
+    }
+
+    #[inline]
+    pub fn append_option<B: AsRef<[u8]>>(&mut self, opt_v: Option<B>) {
+        match opt_v {
+            Some(v) => self.append_value(v),
+            None => self.append_null(),
+        }
+    }
+
+    pub fn finish(mut self) -> BinaryChunked {
+        let arr: ArrayRef = Arc::new(self.builder.finish());
+        ChunkedArray::new_from_chunks(&self.name, vec![arr])
+    }
+}
+
+impl BinaryChunked {
+    pub fn new_from_slice<B: AsRef<[u8]>>(name: &str, v: &[B]) -> Self {
+        let mut ca: Self = v.iter().map(Some).collect();
+        ca.rename(name);
+        ca
+    }
+
+    pub fn new_from_opt_slice<B: AsRef<[u8]>>(name: &str, opt_v: &[Option<B>]) -> Self {
+        let mut ca: Self = opt_v.iter().map(|opt| opt.as_ref()).collect();
+        ca.rename(name);
+        ca
+    }
+
+    fn downcast_chunks(&self) -> impl Iterator<Item = &LargeBinaryArray> + '_ {
+        self.chunks
+            .iter()
+            .map(|arr| arr.as_any().downcast_ref::<LargeBinaryArray>().unwrap())
+    }
+
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        let (chunk_idx, idx) = self.index_to_chunked_index(index);
+        let arr = self.chunks[chunk_idx]
+            .as_any()
+            .downcast_ref::<LargeBinaryArray>()
+            .unwrap();
+        if arr.is_valid(idx) {
+            Some(arr.value(idx))
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<&[u8]>> + Send + '_ {
+        self.downcast_chunks().flat_map(|arr| {
+            (0..arr.len()).map(move |idx| {
+                if arr.is_valid(idx) {
+                    Some(arr.value(idx))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Iterate over the values. Nulls are ignored, so only use this if there are no nulls.
+    pub fn into_no_null_iter(&self) -> impl Iterator<Item = &[u8]> + Send + '_ {
+        self.downcast_chunks()
+            .flat_map(|arr| (0..arr.len()).map(move |idx| arr.value(idx)))
+    }
+
+    fn collect_same_type<'a>(&self, it: impl Iterator<Item = Option<&'a [u8]>>) -> Self {
+        let mut ca: Self = it.collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    fn broadcast_iter(&self, len: usize) -> Box<dyn Iterator<Item = Option<&[u8]>> + '_> {
+        if self.len() == 1 && len != 1 {
+            Box::new(std::iter::repeat(self.get(0)).take(len))
+        } else {
+            Box::new(self.iter())
+        }
+    }
+
+    /// Number of bytes of every value.
+    pub fn bin_lengths(&self) -> UInt32Chunked {
+        let mut ca: UInt32Chunked = self.iter().map(|opt| opt.map(|v| v.len() as u32)).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Slice the bytes of every value. A negative `offset` is counted from the end of the value.
+    /// If `length` is `None` the slice runs until the end of the value.
+    pub fn bin_slice(&self, offset: i64, length: Option<usize>) -> Self {
+        self.collect_same_type(self.iter().map(|opt_v| {
+            opt_v.map(|v| {
+                let length = length.unwrap_or(v.len());
+                let (start, len) = crate::utils::slice_offsets(offset, length, v.len());
+                &v[start..start + len]
+            })
+        }))
+    }
+
+    /// Encode the values as lowercase hexadecimal strings.
+    pub fn encode_hex(&self) -> Utf8Chunked {
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 16);
+        for opt_v in self.iter() {
+            builder.append_option(opt_v.map(encode_hex))
+        }
+        builder.finish()
+    }
+
+    /// Encode the values as base64 strings.
+    pub fn encode_base64(&self) -> Utf8Chunked {
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 12);
+        for opt_v in self.iter() {
+            builder.append_option(opt_v.map(encode_base64))
+        }
+        builder.finish()
+    }
+
+    fn compare<F>(&self, rhs: &BinaryChunked, f: F) -> BooleanChunked
+    where
+        F: Fn(&[u8], &[u8]) -> bool,
+    {
+        let len = std::cmp::max(self.len(), rhs.len());
+        let mut ca: BooleanChunked = self
+            .broadcast_iter(len)
+            .zip(rhs.broadcast_iter(len))
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(f(l, r)),
+                _ => None,
+            })
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
+impl<'a> IntoIterator for &'a BinaryChunked {
+    type Item = Option<&'a [u8]>;
+    type IntoIter = Box<dyn Iterator<Item = Option<&'a [u8]>> + Send + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl Utf8Chunked {
+    fn decode_with<F>(&self, strict: bool, kind: &str, decode: F) -> Result<BinaryChunked>
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len() * 8);
+        for opt_s in self {
+            match opt_s.map(|s| (s, decode(s))) {
+                Some((_, Some(v))) => builder.append_value(v),
+                Some((s, None)) if strict => {
+                    return Err(PolarsError::ValueError(
+                        format!("invalid {} string: {}", kind, s).into(),
+                    ))
+                }
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Decode hexadecimal strings to bytes. Invalid strings return an error if `strict`, else
+    /// they become null.
+    pub fn decode_hex(&self, strict: bool) -> Result<BinaryChunked> {
+        self.decode_with(strict, "hex", decode_hex)
+    }
+
+    /// Decode base64 strings to bytes. Invalid strings return an error if `strict`, else they
+    /// become null.
+    pub fn decode_base64(&self, strict: bool) -> Result<BinaryChunked> {
+        self.decode_with(strict, "base64", decode_base64)
+    }
+}
+
+impl ChunkTake for BinaryChunked {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        self.take(indices)
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let ca = self.rechunk();
+        let arr = ca.downcast_chunks().next().unwrap();
+        let get = |idx: usize| {
+            if arr.is_valid(idx) {
+                Some(arr.value(idx))
+            } else {
+                None
+            }
+        };
+        match indices {
+            TakeIdx::Array(idx) => self.collect_same_type(
+                idx.iter()
+                    .map(|opt_idx| opt_idx.and_then(|i| get(i as usize))),
+            ),
+            TakeIdx::Iter(it) => self.collect_same_type(it.map(get)),
+            TakeIdx::IterNulls(it) => {
+                self.collect_same_type(it.map(|opt_idx| opt_idx.and_then(get)))
+            }
+        }
+    }
+}
+
+impl ChunkFilter<BinaryType> for BinaryChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<BinaryChunked> {
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.slice(0, 0)),
+            };
+        }
+        if filter.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "filter's length differs from that of the ChunkedArray/ Series".into(),
+            ));
+        }
+        let it = self
+            .iter()
+            .zip(filter)
+            .filter(|(_, mask)| mask.unwrap_or(false))
+            .map(|(v, _)| v);
+        Ok(self.collect_same_type(it))
+    }
+}
+
+impl ChunkOps for BinaryChunked {
+    fn rechunk(&self) -> Self {
+        if self.chunks.len() == 1 {
+            self.clone()
+        } else {
+            self.collect_same_type(self.iter())
+        }
+    }
+}
+
+impl ChunkReverse<BinaryType> for BinaryChunked {
+    fn reverse(&self) -> Self {
+        self.take((0..self.len()).rev().into())
+    }
+}
+
+impl ChunkSort<BinaryType> for BinaryChunked {
+    fn sort(&self, reverse: bool) -> BinaryChunked {
+        self.take((&self.argsort(reverse)).into())
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        *self = self.sort(reverse)
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        let mut idx: Vec<(u32, Option<&[u8]>)> = self
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i as u32, v))
+            .collect();
+        // nulls are sorted first, like the other dtypes
+        if reverse {
+            idx.sort_by(|(_, a), (_, b)| b.cmp(a));
+        } else {
+            idx.sort_by(|(_, a), (_, b)| a.cmp(b));
+        }
+        let mut ca: NoNull<UInt32Chunked> = idx.into_iter().map(|(i, _)| i).collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+}
+
+impl ChunkCompare<&BinaryChunked> for BinaryChunked {
+    fn eq_missing(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        let len = std::cmp::max(self.len(), rhs.len());
+        let mut ca: NoNull<BooleanChunked> = self
+            .broadcast_iter(len)
+            .zip(rhs.broadcast_iter(len))
+            .map(|(l, r)| l == r)
+            .collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+
+    fn eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l == r)
+    }
+
+    fn neq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l != r)
+    }
+
+    fn gt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l > r)
+    }
+
+    fn gt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l >= r)
+    }
+
+    fn lt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l < r)
+    }
+
+    fn lt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.compare(rhs, |l, r| l <= r)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hex_base64_round_trip() {
+        assert_eq!(encode_hex(&[0, 15, 255]), "000fff");
+        assert_eq!(decode_hex("000FfF"), Some(vec![0, 15, 255]));
+        assert_eq!(decode_hex("0g"), None);
+        assert_eq!(decode_hex("abc"), None);
+
+        for (raw, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foobar", "Zm9vYmFy"),
+        ]
+        .iter()
+        {
+            assert_eq!(&encode_base64(raw), encoded);
+            assert_eq!(decode_base64(encoded).as_deref(), Some(*raw));
+        }
+        assert_eq!(decode_base64("Zg=a"), None);
+        assert_eq!(decode_base64("Zg==Zg=="), None);
+    }
+
+    #[test]
+    fn test_binary_ops() {
+        let ca = BinaryChunked::new_from_opt_slice("a", &[Some(&b"abc"[..]), None, Some(b"de")]);
+        assert_eq!(ca.dtype(), &DataType::Binary);
+        assert_eq!(Vec::from(&ca.bin_lengths()), &[Some(3), None, Some(2)]);
+        let sliced = ca.bin_slice(-2, None);
+        assert_eq!(sliced.get(0), Some(&b"bc"[..]));
+        assert_eq!(sliced.get(2), Some(&b"de"[..]));
+
+        let hex = ca.encode_hex();
+        assert_eq!(hex.get(0), Some("616263"));
+        let decoded = hex.decode_hex(true).unwrap();
+        assert!(decoded.eq_missing(&ca).all_true());
+
+        let b64 = Utf8Chunked::new_from_slice("b", &["Zm9v", "not base64"]);
+        assert!(b64.decode_base64(true).is_err());
+        assert_eq!(b64.decode_base64(false).unwrap().null_count(), 1);
+
+        let other = BinaryChunked::new_from_slice("b", &[b"abd"]);
+        assert_eq!(Vec::from(&ca.lt(&other)), &[Some(true), None, Some(false)]);
+    }
+
+    #[test]
+    fn test_binary_keys() -> Result<()> {
+        let keys = BinaryChunked::new_from_slice("k", &[&b"a"[..], b"b", b"a"]).into_series();
+        let df = DataFrame::new(vec![keys.clone(), Series::new("v", &[1, 2, 3])])?;
+        let out = df.groupby("k")?.select("v").sum()?;
+        assert_eq!(out.height(), 2);
+
+        let right = DataFrame::new(vec![keys.slice(0, 2), Series::new("w", &[10, 20])])?;
+        let joined = df.inner_join(&right, "k", "k")?;
+        assert_eq!(joined.height(), 3);
+
+        // every row is compared with the list [b"b"]
+        let values = BinaryChunked::new_from_slice("", &[b"b", b"b", b"b"]).into_series();
+        let list = crate::chunked_array::list::build_list_array(
+            "l",
+            &values,
+            vec![1; 3].into_iter(),
+            None,
+        );
+        let mask = keys.is_in(&list)?;
+        assert_eq!(Vec::from(&mask), &[Some(false), Some(true), Some(false)]);
+        Ok(())
+    }
+}
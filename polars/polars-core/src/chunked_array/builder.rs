@@ -54,6 +54,8 @@ impl ChunkedBuilder<bool, BooleanType> for BooleanChunkedBuilder {
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -103,6 +105,8 @@ where
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -236,6 +240,8 @@ impl ChunkedBuilder<&str, CategoricalType> for CategoricalChunkedBuilder {
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: Some(Arc::new(self.reverse_mapping)),
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -290,6 +296,8 @@ impl Utf8ChunkedBuilder {
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -437,6 +445,8 @@ where
             chunk_id: vec![v.len()],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 
@@ -495,6 +505,8 @@ macro_rules! finish_list_builder {
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }};
 }
@@ -611,6 +623,11 @@ impl ListBuilderTrait for ListUtf8ChunkedBuilder {
 
     #[inline]
     fn append_series(&mut self, s: &Series) {
+        // a list stores the categories of a categorical, its codes are only valid with its dictionary
+        let s = match s.dtype() {
+            DataType::Categorical => Cow::Owned(s.cast::<Utf8Type>().unwrap()),
+            _ => Cow::Borrowed(s),
+        };
         let ca = s.utf8().unwrap();
         let value_builder = self.builder.values();
         for s in ca {
@@ -704,12 +721,15 @@ pub fn get_list_builder(
             Box::new(builder)
         }};
     }
-    match_arrow_data_type_apply_macro!(
-        dt,
-        get_primitive_builder,
-        get_utf8_builder,
-        get_bool_builder
-    )
+    match dt {
+        DataType::Categorical => get_utf8_builder!(),
+        _ => match_arrow_data_type_apply_macro!(
+            dt,
+            get_primitive_builder,
+            get_utf8_builder,
+            get_bool_builder
+        ),
+    }
 }
 
 #[cfg(test)]
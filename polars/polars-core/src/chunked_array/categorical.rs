@@ -0,0 +1,403 @@
+use crate::prelude::*;
+use crate::use_string_cache;
+use crate::utils::NoNull;
+use ahash::AHashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Maps the physical codes of a categorical array to the categories.
+pub type RevMapping = AHashMap<u32, String>;
+
+/// The order that is used when sorting categorical values or taking their minimum and maximum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CategoricalOrdering {
+    /// Order by the physical codes, i.e. the order in which the categories were first seen.
+    Physical,
+    /// Order by the string values of the categories.
+    Lexical,
+}
+
+impl Default for CategoricalOrdering {
+    fn default() -> Self {
+        CategoricalOrdering::Physical
+    }
+}
+
+impl CategoricalChunked {
+    /// Get the categories of this array ordered by their physical code.
+    pub fn get_categories(&self) -> Utf8Chunked {
+        let mut categories: Vec<_> = match &self.categorical_map {
+            Some(map) => map.iter().collect(),
+            None => vec![],
+        };
+        categories.sort_unstable_by_key(|(code, _)| **code);
+        let mut builder = Utf8ChunkedBuilder::new(
+            self.name(),
+            categories.len(),
+            categories.iter().map(|(_, cat)| cat.len()).sum(),
+        );
+        categories
+            .iter()
+            .for_each(|(_, cat)| builder.append_value(cat.as_str()));
+        builder.finish()
+    }
+
+    /// Get the order that is used by `sort`, `argsort`, `min` and `max`.
+    pub fn get_ordering(&self) -> CategoricalOrdering {
+        self.categorical_ordering
+    }
+
+    /// Set the order that is used by `sort`, `argsort`, `min` and `max`.
+    pub fn set_ordering(&mut self, ordering: CategoricalOrdering) {
+        if ordering != self.categorical_ordering {
+            // the values are sorted by the old ordering
+            self.sorted = IsSorted::Not;
+        }
+        self.categorical_ordering = ordering
+    }
+
+    /// Give the categories new names. The names are assigned in the order of
+    /// [get_categories](CategoricalChunked::get_categories).
+    ///
+    /// Categories that get the same name are merged.
+    pub fn rename_categories(&self, names: &[&str]) -> Result<CategoricalChunked> {
+        let categories = self.get_categories();
+        if categories.len() != names.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot rename {} categories with {} names",
+                    categories.len(),
+                    names.len()
+                )
+                .into(),
+            ));
+        }
+        let mapping: Vec<_> = categories
+            .into_no_null_iter()
+            .zip(names.iter().copied())
+            .collect();
+        Ok(self.remap(&mapping))
+    }
+
+    /// Replace categories by new values given as `(old, new)` pairs. Categories that are not
+    /// in `mapping` remain unchanged and categories that end up with the same value are merged.
+    pub fn remap(&self, mapping: &[(&str, &str)]) -> CategoricalChunked {
+        let map = match &self.categorical_map {
+            Some(map) => map,
+            None => return self.clone(),
+        };
+        let replace: AHashMap<&str, &str> = mapping.iter().copied().collect();
+        let mut categories: Vec<_> = map
+            .iter()
+            .map(|(code, cat)| {
+                let cat = cat.as_str();
+                (*code, replace.get(cat).copied().unwrap_or(cat))
+            })
+            .collect();
+        categories.sort_unstable_by_key(|(code, _)| *code);
+
+        let mut new_map = RevMapping::with_capacity(categories.len());
+        let mut recode = AHashMap::with_capacity(categories.len());
+        if use_string_cache() {
+            let mut cache = crate::STRING_CACHE.lock_map();
+            for (code, cat) in categories {
+                let new_code = match cache.get(cat) {
+                    Some(new_code) => *new_code,
+                    None => {
+                        let new_code = cache.len() as u32;
+                        cache.insert(cat.to_string(), new_code);
+                        new_code
+                    }
+                };
+                new_map.insert(new_code, cat.to_string());
+                recode.insert(code, new_code);
+            }
+        } else {
+            // a merged category keeps the lowest code
+            let mut codes = AHashMap::with_capacity(categories.len());
+            for (code, cat) in categories {
+                let new_code = *codes.entry(cat).or_insert(code);
+                new_map.insert(new_code, cat.to_string());
+                recode.insert(code, new_code);
+            }
+        }
+
+        let mut out = if recode.iter().all(|(code, new_code)| code == new_code) {
+            self.clone()
+        } else {
+            self.recode(&recode)
+        };
+        out.categorical_map = Some(Arc::new(new_map));
+        out
+    }
+
+    /// Get the code of the minimal or maximal value according to the ordering of this array.
+    pub(crate) fn extreme_code(&self, max: bool) -> Option<u32> {
+        match (self.categorical_ordering, &self.categorical_map) {
+            (CategoricalOrdering::Lexical, Some(map)) => {
+                let cmp = |a: &u32, b: &u32| map[a].cmp(&map[b]);
+                let codes = self.into_iter().flatten();
+                if max {
+                    codes.max_by(cmp)
+                } else {
+                    codes.min_by(cmp)
+                }
+            }
+            _ => {
+                let codes: &UInt32Chunked = self.deref();
+                if max {
+                    ChunkAgg::max(codes)
+                } else {
+                    ChunkAgg::min(codes)
+                }
+            }
+        }
+    }
+
+    /// Make the categories of `other` compatible with the categories of `self`.
+    ///
+    /// Returns `other` encoded with the codes of `self` and a mapping that is valid for both.
+    /// If the dictionaries don't conflict, e.g. because both arrays were created under the global
+    /// string cache, the dictionaries are merged and nothing is re-encoded.
+    pub(crate) fn align_categories(
+        &self,
+        other: &CategoricalChunked,
+    ) -> (CategoricalChunked, Arc<RevMapping>) {
+        let (left, right) = match (&self.categorical_map, &other.categorical_map) {
+            (Some(left), Some(right)) => (left, right),
+            (Some(map), None) | (None, Some(map)) => {
+                let mut other = other.clone();
+                other.categorical_map = Some(map.clone());
+                return (other, map.clone());
+            }
+            (None, None) => {
+                let map = Arc::new(RevMapping::new());
+                let mut other = other.clone();
+                other.categorical_map = Some(map.clone());
+                return (other, map);
+            }
+        };
+        if Arc::ptr_eq(left, right) {
+            return (other.clone(), left.clone());
+        }
+
+        let left_codes: AHashMap<&str, u32> = left
+            .iter()
+            .map(|(code, cat)| (cat.as_str(), *code))
+            .collect();
+        let compatible = right.iter().all(|(code, cat)| {
+            left.get(code).map_or(true, |left_cat| left_cat == cat)
+                && left_codes
+                    .get(cat.as_str())
+                    .map_or(true, |left_code| left_code == code)
+        });
+
+        let mut merged = (**left).clone();
+        if compatible {
+            merged.extend(right.iter().map(|(code, cat)| (*code, cat.clone())));
+            let merged = Arc::new(merged);
+            let mut other = other.clone();
+            other.categorical_map = Some(merged.clone());
+            return (other, merged);
+        }
+
+        // the categories of `other` that `self` doesn't have get new codes
+        let mut next_code = left.keys().max().map_or(0, |code| code + 1);
+        let recode: AHashMap<u32, u32> = right
+            .iter()
+            .map(|(code, cat)| {
+                let new_code = match left_codes.get(cat.as_str()) {
+                    Some(left_code) => *left_code,
+                    None => {
+                        let new_code = next_code;
+                        next_code += 1;
+                        merged.insert(new_code, cat.clone());
+                        new_code
+                    }
+                };
+                (*code, new_code)
+            })
+            .collect();
+
+        let merged = Arc::new(merged);
+        let mut other = other.recode(&recode);
+        other.categorical_map = Some(merged.clone());
+        (other, merged)
+    }
+
+    /// Replace every code by the code it maps to in `recode`.
+    fn recode(&self, recode: &AHashMap<u32, u32>) -> CategoricalChunked {
+        let mut codes: UInt32Chunked = if self.null_count() == 0 {
+            self.into_no_null_iter()
+                .map(|code| recode[&code])
+                .collect::<NoNull<UInt32Chunked>>()
+                .into_inner()
+        } else {
+            self.into_iter()
+                .map(|opt_code| opt_code.map(|code| recode[&code]))
+                .collect()
+        };
+        codes.rename(self.name());
+        let ca: CategoricalChunked = codes.cast().unwrap();
+        ca.set_state(self)
+    }
+
+    /// Append `other` and re-encode it if the categories of both arrays don't match.
+    pub(crate) fn append_categorical(&mut self, other: &CategoricalChunked) {
+        if self.is_empty() {
+            self.categorical_map = other.categorical_map.clone();
+        }
+        let (other, map) = self.align_categories(other);
+        self.categorical_map = Some(map);
+        let codes: &UInt32Chunked = other.deref();
+        (**self).append(codes);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::{use_string_cache, StringCacheHolder};
+
+    #[test]
+    fn test_append_categorical_different_dictionaries() {
+        let mut a = Utf8Chunked::new_from_slice("a", &["a", "b", "a"])
+            .cast::<CategoricalType>()
+            .unwrap();
+        let b = Utf8Chunked::new_from_slice("b", &["c", "a", "b"])
+            .cast::<CategoricalType>()
+            .unwrap();
+        a.append(&b);
+        let out = a.cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("a"),
+                Some("b"),
+                Some("a"),
+                Some("c"),
+                Some("a"),
+                Some("b")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_categorical_ordering() {
+        let mut ca = Utf8Chunked::new_from_slice("a", &["b", "c", "a", "b"])
+            .cast::<CategoricalType>()
+            .unwrap();
+        let sorted = ca.sort(false).cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(&sorted),
+            &[Some("b"), Some("b"), Some("c"), Some("a")]
+        );
+
+        ca.set_ordering(CategoricalOrdering::Lexical);
+        let sorted = ca.sort(false).cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(&sorted),
+            &[Some("a"), Some("b"), Some("b"), Some("c")]
+        );
+        let s = ca.into_series();
+        let min = s.min_as_series().cast::<Utf8Type>().unwrap();
+        let max = s.max_as_series().cast::<Utf8Type>().unwrap();
+        assert_eq!(min.utf8().unwrap().get(0), Some("a"));
+        assert_eq!(max.utf8().unwrap().get(0), Some("c"));
+    }
+
+    #[test]
+    fn test_rename_and_remap_categories() {
+        let s = Series::new("a", &["x", "y", "z", "x"])
+            .cast::<CategoricalType>()
+            .unwrap();
+        let cats = s.get_categories().unwrap();
+        assert_eq!(
+            Vec::from(cats.utf8().unwrap()),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+
+        let out = s.rename_categories(&["a", "b", "c"]).unwrap();
+        let out = out.cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[Some("a"), Some("b"), Some("c"), Some("a")]
+        );
+        assert!(s.rename_categories(&["a"]).is_err());
+
+        // merge "z" into "x"
+        let out = s.remap(&[("z", "x")]).unwrap();
+        assert_eq!(out.get_categories().unwrap().len(), 2);
+        let out = out.cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[Some("x"), Some("y"), Some("x"), Some("x")]
+        );
+    }
+
+    #[test]
+    fn test_categorical_keys_keep_dictionary() {
+        let mut df = df! {
+            "cat" => ["a", "b", "a"],
+            "val" => [1, 2, 3]
+        }
+        .unwrap();
+        df.apply("cat", |s| s.cast::<CategoricalType>().unwrap())
+            .unwrap();
+
+        let counts = df.column("cat").unwrap().value_counts().unwrap();
+        assert_eq!(
+            counts.column("cat").unwrap().dtype(),
+            &DataType::Categorical
+        );
+
+        let out = df.groupby("cat").unwrap().select("val").sum().unwrap();
+        let keys = out.column("cat").unwrap();
+        assert_eq!(keys.dtype(), &DataType::Categorical);
+        assert_eq!(keys.get_categories().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_is_in_categorical_different_dictionaries() {
+        let a = Utf8Chunked::new_from_slice("a", &["a", "b", "c"])
+            .cast::<CategoricalType>()
+            .unwrap()
+            .into_series();
+        // the codes of "c" and "b" differ from the codes in `a`
+        let b = Utf8Chunked::new_from_slice("b", &["c", "b"])
+            .cast::<CategoricalType>()
+            .unwrap()
+            .into_series();
+        let list: ListChunked = vec![b.clone(), b.clone(), b].into_iter().collect();
+        assert_eq!(list.inner_dtype(), DataType::Utf8);
+        assert_eq!(
+            Vec::from(&a.is_in(&list).unwrap()),
+            &[Some(false), Some(true), Some(true)]
+        );
+
+        let codes: ListChunked = vec![Series::new("", &[0u32, 1])].into_iter().collect();
+        assert!(a.head(Some(1)).is_in(&codes).is_err());
+    }
+
+    #[test]
+    fn test_string_cache_holder() {
+        {
+            let _holder = StringCacheHolder::hold();
+            let a = Utf8Chunked::new_from_slice("a", &["a", "b"])
+                .cast::<CategoricalType>()
+                .unwrap();
+            let b = Utf8Chunked::new_from_slice("b", &["b", "a"])
+                .cast::<CategoricalType>()
+                .unwrap();
+            let a = a.cast::<UInt32Type>().unwrap();
+            let b = b.cast::<UInt32Type>().unwrap();
+            assert_eq!(a.get(0), b.get(1));
+            {
+                let _nested = StringCacheHolder::hold();
+            }
+            assert!(use_string_cache());
+            // the holder is also seen on other threads, e.g. the threads of the thread pool
+            assert!(std::thread::spawn(use_string_cache).join().unwrap());
+        }
+    }
+}
@@ -428,6 +428,29 @@ impl NumComp for u16 {}
 impl NumComp for u32 {}
 impl NumComp for u64 {}
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Compare sorted values without nulls with a binary search. `f` is monotonic in sorted
+    /// values, so the mask is a run of equal values followed by a run of the opposite value.
+    /// Returns `None` if the values are not known to be sorted.
+    fn sorted_comparison(&self, f: impl Fn(T::Native) -> bool) -> Option<BooleanChunked> {
+        if self.is_sorted() == IsSorted::Not || self.null_count() > 0 {
+            return None;
+        }
+        let values = self.cont_slice().ok()?;
+        let first = f(*values.first()?);
+        let boundary = values.partition_point(|&v| f(v) == first);
+        let mut mask: BooleanChunked = std::iter::repeat(first)
+            .take(boundary)
+            .chain(std::iter::repeat(!first).take(values.len() - boundary))
+            .collect();
+        mask.rename(self.name());
+        Some(mask)
+    }
+}
+
 impl<T, Rhs> ChunkCompare<Rhs> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -449,22 +472,38 @@ where
     }
 
     fn gt(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(mask) = self.sorted_comparison(|v| v > rhs) {
+            return mask;
+        }
         self.apply_kernel_cast(|arr| Arc::new(gt_scalar(arr, rhs).unwrap()))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(mask) = self.sorted_comparison(|v| v >= rhs) {
+            return mask;
+        }
         self.apply_kernel_cast(|arr| Arc::new(gt_eq_scalar(arr, rhs).unwrap()))
     }
 
     fn lt(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(mask) = self.sorted_comparison(|v| v < rhs) {
+            return mask;
+        }
         self.apply_kernel_cast(|arr| Arc::new(lt_scalar(arr, rhs).unwrap()))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(mask) = self.sorted_comparison(|v| v <= rhs) {
+            return mask;
+        }
         self.apply_kernel_cast(|arr| Arc::new(lt_eq_scalar(arr, rhs).unwrap()))
     }
 }
@@ -725,6 +764,16 @@ impl ChunkEqualElement for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkEqualElement for BinaryChunked {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let ca_other = other.as_ref().as_ref();
+        debug_assert!(self.dtype() == other.dtype());
+        let ca_other = &*(ca_other as *const BinaryChunked);
+        self.get(idx_self) == ca_other.get(idx_other)
+    }
+}
+
 impl ChunkEqualElement for ListChunked {}
 impl ChunkEqualElement for CategoricalChunked {
     unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
@@ -0,0 +1,1054 @@
+//! Fixed precision decimals backed by `i128`.
+//!
+//! A decimal array stores the unscaled values, e.g. `12.34` with a scale of `2` is stored as
+//! `1234`. The precision and the scale are part of the `DataType` of the array. All arithmetic is
+//! exact; results that don't fit in the precision of the output return an error.
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::list::build_list_array;
+#[cfg(feature = "dtype-decimal")]
+use crate::frame::groupby::{GroupTuples, GroupsIndicator};
+use crate::prelude::*;
+use crate::utils::NoNull;
+use arrow::array::{Array, ArrayRef, DecimalArray, DecimalBuilder};
+use std::cmp::Ordering;
+#[cfg(feature = "dtype-decimal")]
+use std::collections::HashSet;
+
+/// The maximum number of digits an `i128` can hold.
+pub const MAX_DECIMAL_PRECISION: usize = 38;
+
+fn overflow_err(value: impl std::fmt::Display, precision: usize) -> PolarsError {
+    PolarsError::ValueError(
+        format!(
+            "decimal overflow: {} does not fit in a decimal with precision {}",
+            value, precision
+        )
+        .into(),
+    )
+}
+
+/// Check that a decimal with the given precision and scale can be represented.
+pub(crate) fn check_decimal_dtype(precision: usize, scale: usize) -> Result<()> {
+    if precision > MAX_DECIMAL_PRECISION || scale > precision {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "invalid decimal({}, {}): the precision can be at most {} and the scale at most the precision",
+                precision, scale, MAX_DECIMAL_PRECISION
+            )
+            .into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn pow10(exp: usize) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+/// Change the scale of an unscaled value. Digits are truncated towards zero if the scale
+/// decreases. Returns `None` on overflow.
+#[inline]
+fn rescale_value(v: i128, from: usize, to: usize) -> Option<i128> {
+    match to.cmp(&from) {
+        Ordering::Equal => Some(v),
+        Ordering::Greater => v.checked_mul(pow10(to - from)?),
+        Ordering::Less => Some(v / pow10(from - to)?),
+    }
+}
+
+#[inline]
+fn fits_precision(v: i128, precision: usize) -> bool {
+    match (v.checked_abs(), pow10(precision)) {
+        (Some(abs), Some(max)) => abs < max,
+        _ => false,
+    }
+}
+
+/// Sum of unscaled values; `None` if there are no values. Returns an error on overflow.
+fn checked_sum(values: impl Iterator<Item = i128>) -> Result<Option<i128>> {
+    let mut sum = None;
+    for v in values {
+        let acc = sum.unwrap_or(0i128).checked_add(v);
+        sum = Some(acc.ok_or_else(|| overflow_err("sum", MAX_DECIMAL_PRECISION))?);
+    }
+    match sum {
+        Some(sum) if !fits_precision(sum, MAX_DECIMAL_PRECISION) => {
+            Err(overflow_err("sum", MAX_DECIMAL_PRECISION))
+        }
+        _ => Ok(sum),
+    }
+}
+
+/// Compare two unscaled values with a different scale without losing precision.
+pub(crate) fn cmp_decimal(l: i128, l_scale: usize, r: i128, r_scale: usize) -> Ordering {
+    if l_scale <= r_scale {
+        match rescale_value(l, l_scale, r_scale) {
+            Some(l) => l.cmp(&r),
+            // `l` is larger in magnitude than anything that fits in an i128
+            None if l < 0 => Ordering::Less,
+            None => Ordering::Greater,
+        }
+    } else {
+        cmp_decimal(r, r_scale, l, l_scale).reverse()
+    }
+}
+
+/// Format an unscaled value as a decimal string.
+pub(crate) fn format_decimal(v: i128, scale: usize) -> String {
+    let sign = if v < 0 { "-" } else { "" };
+    let digits = v.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int, frac)
+}
+
+/// Parse a decimal string to an unscaled value with the given scale. Digits beyond the scale are
+/// truncated. Returns `None` if the string is not a decimal number or on overflow.
+pub(crate) fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int, frac) = match s.find('.') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if (int.is_empty() && frac.is_empty())
+        || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let frac = frac.bytes().chain(std::iter::repeat(b'0')).take(scale);
+    let mut v = 0i128;
+    for b in int.bytes().chain(frac) {
+        v = v.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+    Some(if negative { -v } else { v })
+}
+
+pub struct DecimalChunkedBuilder {
+    builder: DecimalBuilder,
+    name: String,
+    precision: usize,
+}
+
+impl DecimalChunkedBuilder {
+    /// Returns an error if the precision is larger than [MAX_DECIMAL_PRECISION] or the scale is
+    /// larger than the precision.
+    pub fn new(name: &str, capacity: usize, precision: usize, scale: usize) -> Result<Self> {
+        check_decimal_dtype(precision, scale)?;
+        Ok(Self::new_unchecked(name, capacity, precision, scale))
+    }
+
+    /// The caller must make sure that the precision and the scale are valid.
+    pub(crate) fn new_unchecked(
+        name: &str,
+        capacity: usize,
+        precision: usize,
+        scale: usize,
+    ) -> Self {
+        DecimalChunkedBuilder {
+            builder: DecimalBuilder::new(capacity, precision, scale),
+            name: name.to_string(),
+            precision,
+        }
+    }
+
+    /// Appends an unscaled value. Returns an error if the value doesn't fit in the precision.
+    pub fn append_value(&mut self, v: i128) -> Result<()> {
+        if !fits_precision(v, self.precision) {
+            return Err(overflow_err(v, self.precision));
+        }
+        self.builder.append_value(v)?;
+        Ok(())
+    }
+
+    pub fn append_null(&mut self) {
+        self.builder.append_null().unwrap();
+    }
+
+    pub fn append_option(&mut self, opt_v: Option<i128>) -> Result<()> {
+        match opt_v {
+            Some(v) => self.append_value(v),
+            None => {
+                self.append_null();
+                Ok(())
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> DecimalChunked {
+        let arr: ArrayRef = Arc::new(self.builder.finish());
+        ChunkedArray::new_from_chunks(&self.name, vec![arr])
+    }
+}
+
+impl DecimalChunked {
+    /// Create a new decimal array from unscaled values.
+    pub fn new_from_opt_iter<I>(name: &str, it: I, precision: usize, scale: usize) -> Result<Self>
+    where
+        I: IntoIterator<Item = Option<i128>>,
+    {
+        let it = it.into_iter();
+        let mut builder = DecimalChunkedBuilder::new(name, it.size_hint().0, precision, scale)?;
+        for opt_v in it {
+            builder.append_option(opt_v)?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Create a new decimal array from unscaled values.
+    pub fn new_from_slice(name: &str, v: &[i128], precision: usize, scale: usize) -> Result<Self> {
+        Self::new_from_opt_iter(name, v.iter().map(|v| Some(*v)), precision, scale)
+    }
+
+    /// Total number of digits.
+    pub fn precision(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    fn downcast_chunks(&self) -> impl Iterator<Item = &DecimalArray> + '_ {
+        self.chunks
+            .iter()
+            .map(|arr| arr.as_any().downcast_ref::<DecimalArray>().unwrap())
+    }
+
+    /// Get the unscaled value at `index`.
+    pub fn get(&self, index: usize) -> Option<i128> {
+        let (chunk_idx, idx) = self.index_to_chunked_index(index);
+        let arr = self.chunks[chunk_idx]
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+        if arr.is_valid(idx) {
+            Some(arr.value(idx))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the unscaled values.
+    pub fn iter(&self) -> impl Iterator<Item = Option<i128>> + '_ {
+        self.downcast_chunks().flat_map(|arr| {
+            (0..arr.len()).map(move |idx| {
+                if arr.is_valid(idx) {
+                    Some(arr.value(idx))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Collect values that are known to fit in the precision of `self`.
+    pub(crate) fn collect_same_type(
+        &self,
+        len: usize,
+        it: impl Iterator<Item = Option<i128>>,
+    ) -> Self {
+        let mut builder =
+            DecimalChunkedBuilder::new_unchecked(self.name(), len, self.precision(), self.scale());
+        for opt_v in it {
+            builder
+                .append_option(opt_v)
+                .expect("values fit in the precision of the array");
+        }
+        builder.finish()
+    }
+
+    /// Change the precision and the scale. Digits are truncated if the scale decreases.
+    pub fn rescale(&self, precision: usize, scale: usize) -> Result<Self> {
+        let from = self.scale();
+        let mut builder = DecimalChunkedBuilder::new(self.name(), self.len(), precision, scale)?;
+        for opt_v in self.iter() {
+            let opt_v = opt_v
+                .map(|v| rescale_value(v, from, scale).ok_or_else(|| overflow_err(v, precision)))
+                .transpose()?;
+            builder.append_option(opt_v)?;
+        }
+        Ok(builder.finish())
+    }
+
+    fn broadcast_iter(&self, len: usize) -> Box<dyn Iterator<Item = Option<i128>> + '_> {
+        if self.len() == 1 && len != 1 {
+            Box::new(std::iter::repeat(self.get(0)).take(len))
+        } else {
+            Box::new(self.iter())
+        }
+    }
+
+    /// The length of the output of a binary operation, where an array of length 1 is broadcast.
+    fn broadcast_len(&self, rhs: &DecimalChunked) -> Result<usize> {
+        match (self.len(), rhs.len()) {
+            (l, r) if l == r => Ok(l),
+            (1, r) => Ok(r),
+            (l, 1) => Ok(l),
+            _ => Err(PolarsError::ShapeMisMatch(
+                "cannot apply an operation on decimal arrays of different lengths".into(),
+            )),
+        }
+    }
+
+    fn apply_binary<F>(
+        &self,
+        rhs: &DecimalChunked,
+        precision: usize,
+        scale: usize,
+        op: F,
+    ) -> Result<Self>
+    where
+        F: Fn(i128, i128) -> Result<i128>,
+    {
+        let len = self.broadcast_len(rhs)?;
+        let mut builder = DecimalChunkedBuilder::new(self.name(), len, precision, scale)?;
+        for (l, r) in self.broadcast_iter(len).zip(rhs.broadcast_iter(len)) {
+            let opt_v = match (l, r) {
+                (Some(l), Some(r)) => Some(op(l, r)?),
+                _ => None,
+            };
+            builder.append_option(opt_v)?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Add two decimal arrays. The result has the largest scale of both inputs.
+    pub fn checked_add(&self, rhs: &DecimalChunked) -> Result<Self> {
+        self.add_sub(rhs, false)
+    }
+
+    /// Subtract two decimal arrays. The result has the largest scale of both inputs.
+    pub fn checked_sub(&self, rhs: &DecimalChunked) -> Result<Self> {
+        self.add_sub(rhs, true)
+    }
+
+    fn add_sub(&self, rhs: &DecimalChunked, subtract: bool) -> Result<Self> {
+        let (l_scale, r_scale) = (self.scale(), rhs.scale());
+        let scale = std::cmp::max(l_scale, r_scale);
+        let int_digits = std::cmp::max(self.precision() - l_scale, rhs.precision() - r_scale);
+        let precision = std::cmp::min(MAX_DECIMAL_PRECISION, int_digits + scale + 1);
+
+        self.apply_binary(rhs, precision, scale, |l, r| {
+            let l = rescale_value(l, l_scale, scale);
+            let r = rescale_value(r, r_scale, scale);
+            let out = match (l, r) {
+                (Some(l), Some(r)) if subtract => l.checked_sub(r),
+                (Some(l), Some(r)) => l.checked_add(r),
+                _ => None,
+            };
+            out.ok_or_else(|| overflow_err("result", precision))
+        })
+    }
+
+    /// Multiply two decimal arrays. The scale of the result is the sum of both scales.
+    pub fn checked_mul(&self, rhs: &DecimalChunked) -> Result<Self> {
+        let scale = self.scale() + rhs.scale();
+        if scale > MAX_DECIMAL_PRECISION {
+            return Err(overflow_err(
+                format!("scale {}", scale),
+                MAX_DECIMAL_PRECISION,
+            ));
+        }
+        let precision = std::cmp::min(MAX_DECIMAL_PRECISION, self.precision() + rhs.precision());
+        self.apply_binary(rhs, precision, scale, |l, r| {
+            l.checked_mul(r)
+                .ok_or_else(|| overflow_err("result", precision))
+        })
+    }
+
+    /// Divide two decimal arrays. The result has the scale of `self`; remaining digits are
+    /// truncated. Division by zero returns an error.
+    pub fn checked_div(&self, rhs: &DecimalChunked) -> Result<Self> {
+        let scale = self.scale();
+        let r_scale = rhs.scale();
+        let precision = std::cmp::min(MAX_DECIMAL_PRECISION, self.precision() + r_scale);
+        self.apply_binary(rhs, precision, scale, |l, r| {
+            if r == 0 {
+                return Err(PolarsError::ValueError("decimal division by zero".into()));
+            }
+            // l * 10^r_scale / r keeps the scale of l
+            rescale_value(l, 0, r_scale)
+                .map(|l| l / r)
+                .ok_or_else(|| overflow_err("result", precision))
+        })
+    }
+
+    /// Sum of the values. Returns an error on overflow.
+    pub fn sum(&self) -> Result<Option<i128>> {
+        checked_sum(self.iter().flatten())
+    }
+
+    /// Sum of the values as an array of length 1. The precision is widened to
+    /// [MAX_DECIMAL_PRECISION], so that the sum of values of any precision can be represented.
+    /// Returns an error on overflow.
+    pub fn sum_as_decimal(&self) -> Result<Self> {
+        let sum = self.sum()?;
+        Self::new_from_opt_iter(
+            self.name(),
+            std::iter::once(sum),
+            MAX_DECIMAL_PRECISION,
+            self.scale(),
+        )
+    }
+
+    /// Mean of the values as a float.
+    pub fn mean(&self) -> Option<f64> {
+        let count = self.len() - self.null_count();
+        if count == 0 {
+            return None;
+        }
+        let sum = self.to_f64().into_iter().flatten().sum::<f64>();
+        Some(sum / count as f64)
+    }
+
+    /// Minimal unscaled value.
+    pub fn min(&self) -> Option<i128> {
+        self.iter().flatten().min()
+    }
+
+    /// Maximal unscaled value.
+    pub fn max(&self) -> Option<i128> {
+        self.iter().flatten().max()
+    }
+
+    /// Cast to floating point values. This may lose precision.
+    pub fn to_f64(&self) -> Float64Chunked {
+        let divisor = 10f64.powi(self.scale() as i32);
+        let mut ca: Float64Chunked = self.iter().map(|v| v.map(|v| v as f64 / divisor)).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Format the values as strings.
+    pub fn to_utf8(&self) -> Utf8Chunked {
+        let scale = self.scale();
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 8);
+        for opt_v in self.iter() {
+            builder.append_option(opt_v.map(|v| format_decimal(v, scale)))
+        }
+        builder.finish()
+    }
+
+    /// Parse strings as decimals. Strings that aren't decimal numbers become null; values that
+    /// don't fit in the precision return an error.
+    pub fn from_utf8(ca: &Utf8Chunked, precision: usize, scale: usize) -> Result<Self> {
+        let mut builder = DecimalChunkedBuilder::new(ca.name(), ca.len(), precision, scale)?;
+        for opt_s in ca {
+            builder.append_option(opt_s.and_then(|s| parse_decimal(s, scale)))?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Convert floats to decimals, rounding to the nearest value with the given scale.
+    pub fn from_f64(ca: &Float64Chunked, precision: usize, scale: usize) -> Result<Self> {
+        let factor = 10f64.powi(scale as i32);
+        let mut builder = DecimalChunkedBuilder::new(ca.name(), ca.len(), precision, scale)?;
+        for opt_v in ca {
+            let opt_v = opt_v
+                .map(|v| {
+                    let scaled = (v * factor).round();
+                    // i128::MAX is ~1.7e38, which is larger than any decimal that fits
+                    if scaled.is_finite() && scaled.abs() < 1e38 {
+                        Ok(scaled as i128)
+                    } else {
+                        Err(overflow_err(v, precision))
+                    }
+                })
+                .transpose()?;
+            builder.append_option(opt_v)?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Convert integers to decimals.
+    pub fn from_i64(ca: &Int64Chunked, precision: usize, scale: usize) -> Result<Self> {
+        Self::from_integers(ca.name(), ca.len(), ca.into_iter(), precision, scale)
+    }
+
+    /// Convert unsigned integers to decimals. Values larger than `i64::MAX` are kept exactly.
+    pub fn from_u64(ca: &UInt64Chunked, precision: usize, scale: usize) -> Result<Self> {
+        Self::from_integers(ca.name(), ca.len(), ca.into_iter(), precision, scale)
+    }
+
+    fn from_integers<T>(
+        name: &str,
+        len: usize,
+        it: impl Iterator<Item = Option<T>>,
+        precision: usize,
+        scale: usize,
+    ) -> Result<Self>
+    where
+        T: Into<i128> + std::fmt::Display + Copy,
+    {
+        let mut builder = DecimalChunkedBuilder::new(name, len, precision, scale)?;
+        for opt_v in it {
+            let opt_v = opt_v
+                .map(|v| {
+                    rescale_value(v.into(), 0, scale).ok_or_else(|| overflow_err(v, precision))
+                })
+                .transpose()?;
+            builder.append_option(opt_v)?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Compare two decimal arrays of the same length; an array of length 1 is broadcast.
+    /// Returns an error if the lengths differ.
+    pub fn compare<F>(&self, rhs: &DecimalChunked, f: F) -> Result<BooleanChunked>
+    where
+        F: Fn(Ordering) -> bool,
+    {
+        let len = self.broadcast_len(rhs)?;
+        let (l_scale, r_scale) = (self.scale(), rhs.scale());
+        let mut ca: BooleanChunked = self
+            .broadcast_iter(len)
+            .zip(rhs.broadcast_iter(len))
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(f(cmp_decimal(l, l_scale, r, r_scale))),
+                _ => None,
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+/// Aggregations per group. The values are compared by their unscaled `i128` value.
+#[cfg(feature = "dtype-decimal")]
+impl DecimalChunked {
+    fn group_values<'a>(&'a self, g: GroupsIndicator<'a>) -> impl Iterator<Item = i128> + 'a {
+        g.iter().filter_map(move |i| self.get(i))
+    }
+
+    /// Sum of every group with the precision widened to [MAX_DECIMAL_PRECISION]. The sum of a
+    /// group without values is null. Returns an error on overflow.
+    pub(crate) fn agg_sum_decimal(&self, groups: &GroupTuples) -> Result<Self> {
+        let sums = groups
+            .iter()
+            .map(|g| checked_sum(self.group_values(g)))
+            .collect::<Result<Vec<_>>>()?;
+        Self::new_from_opt_iter(self.name(), sums, MAX_DECIMAL_PRECISION, self.scale())
+    }
+
+    /// Mean of every group as a float. The sum is computed exactly and returns an error on
+    /// overflow.
+    pub(crate) fn agg_mean_decimal(&self, groups: &GroupTuples) -> Result<Float64Chunked> {
+        let divisor = 10f64.powi(self.scale() as i32);
+        let means = groups
+            .iter()
+            .map(|g| {
+                let values: Vec<_> = self.group_values(g).collect();
+                let sum = checked_sum(values.iter().copied())?;
+                Ok(sum.map(|sum| sum as f64 / divisor / values.len() as f64))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut ca: Float64Chunked = means.into_iter().collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    pub(crate) fn agg_min_decimal(&self, groups: &GroupTuples) -> Self {
+        let it = groups.iter().map(|g| self.group_values(g).min());
+        self.collect_same_type(groups.len(), it)
+    }
+
+    pub(crate) fn agg_max_decimal(&self, groups: &GroupTuples) -> Self {
+        let it = groups.iter().map(|g| self.group_values(g).max());
+        self.collect_same_type(groups.len(), it)
+    }
+
+    pub(crate) fn agg_first_decimal(&self, groups: &GroupTuples) -> Self {
+        let it = groups
+            .iter()
+            .map(|g| g.iter().next().and_then(|i| self.get(i)));
+        self.collect_same_type(groups.len(), it)
+    }
+
+    pub(crate) fn agg_last_decimal(&self, groups: &GroupTuples) -> Self {
+        let it = groups
+            .iter()
+            .map(|g| g.iter().last().and_then(|i| self.get(i)));
+        self.collect_same_type(groups.len(), it)
+    }
+
+    /// Number of unique values of every group. Null counts as a value.
+    pub(crate) fn agg_n_unique_decimal(&self, groups: &GroupTuples) -> UInt32Chunked {
+        let ca: NoNull<UInt32Chunked> = groups
+            .iter()
+            .map(|g| {
+                let set: HashSet<_> = g.iter().map(|i| self.get(i)).collect();
+                set.len() as u32
+            })
+            .collect();
+        ca.into_inner()
+    }
+
+    pub(crate) fn agg_list_decimal(&self, groups: &GroupTuples) -> ListChunked {
+        let idx = groups.iter().flat_map(|g| g.iter());
+        let values = self.take(idx.into()).into_series();
+        build_list_array(self.name(), &values, groups.iter().map(|g| g.len()), None)
+    }
+}
+
+/// Cast a Series to a decimal with the given precision and scale.
+#[cfg(feature = "dtype-decimal")]
+pub(crate) fn cast_to_decimal(s: &Series, precision: usize, scale: usize) -> Result<Series> {
+    check_decimal_dtype(precision, scale)?;
+    let ca = match s.dtype() {
+        DataType::Decimal(_, _) => s.decimal()?.rescale(precision, scale)?,
+        DataType::Utf8 => DecimalChunked::from_utf8(s.utf8()?, precision, scale)?,
+        DataType::Float32 | DataType::Float64 => {
+            let s = s.cast::<Float64Type>()?;
+            DecimalChunked::from_f64(s.f64()?, precision, scale)?
+        }
+        DataType::Boolean
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64 => {
+            let s = s.cast::<Int64Type>()?;
+            DecimalChunked::from_i64(s.i64()?, precision, scale)?
+        }
+        // going through i64 would wrap the values above i64::MAX
+        DataType::UInt64 => DecimalChunked::from_u64(s.u64()?, precision, scale)?,
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot cast {:?} to decimal", dt).into(),
+            ))
+        }
+    };
+    Ok(ca.into_series())
+}
+
+impl ChunkTake for DecimalChunked {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        self.take(indices)
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let ca = self.rechunk();
+        let arr = ca.downcast_chunks().next().unwrap();
+        let get = |idx: usize| {
+            if arr.is_valid(idx) {
+                Some(arr.value(idx))
+            } else {
+                None
+            }
+        };
+        match indices {
+            TakeIdx::Array(idx) => {
+                let it = idx
+                    .iter()
+                    .map(|opt_idx| opt_idx.and_then(|i| get(i as usize)));
+                self.collect_same_type(idx.len(), it)
+            }
+            TakeIdx::Iter(it) => {
+                let len = it.size_hint().0;
+                self.collect_same_type(len, it.map(get))
+            }
+            TakeIdx::IterNulls(it) => {
+                let len = it.size_hint().0;
+                self.collect_same_type(len, it.map(|opt_idx| opt_idx.and_then(get)))
+            }
+        }
+    }
+}
+
+impl ChunkFilter<DecimalType> for DecimalChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<DecimalChunked> {
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.slice(0, 0)),
+            };
+        }
+        if filter.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "filter's length differs from that of the ChunkedArray/ Series".into(),
+            ));
+        }
+        let it = self
+            .iter()
+            .zip(filter)
+            .filter(|(_, mask)| mask.unwrap_or(false))
+            .map(|(v, _)| v);
+        Ok(self.collect_same_type(self.len(), it))
+    }
+}
+
+impl ChunkOps for DecimalChunked {
+    fn rechunk(&self) -> Self {
+        if self.chunks.len() == 1 {
+            self.clone()
+        } else {
+            self.collect_same_type(self.len(), self.iter())
+        }
+    }
+}
+
+impl ChunkReverse<DecimalType> for DecimalChunked {
+    fn reverse(&self) -> Self {
+        self.take((0..self.len()).rev().into())
+    }
+}
+
+impl ChunkSort<DecimalType> for DecimalChunked {
+    fn sort(&self, reverse: bool) -> DecimalChunked {
+        self.take((&self.argsort(reverse)).into())
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        *self = self.sort(reverse)
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        let mut idx: Vec<(u32, Option<i128>)> = self
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i as u32, v))
+            .collect();
+        // nulls are sorted first, like the other dtypes
+        if reverse {
+            idx.sort_by(|(_, a), (_, b)| b.cmp(a));
+        } else {
+            idx.sort_by(|(_, a), (_, b)| a.cmp(b));
+        }
+        let mut ca: NoNull<UInt32Chunked> = idx.into_iter().map(|(i, _)| i).collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+}
+
+// the comparison trait can't return an error, use `DecimalChunked::compare` to get one instead
+const LENGTH_MISMATCH: &str = "cannot compare decimal arrays of different lengths";
+
+impl ChunkCompare<&DecimalChunked> for DecimalChunked {
+    fn eq_missing(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let len = self.broadcast_len(rhs).expect(LENGTH_MISMATCH);
+        let (l_scale, r_scale) = (self.scale(), rhs.scale());
+        let mut ca: NoNull<BooleanChunked> = self
+            .broadcast_iter(len)
+            .zip(rhs.broadcast_iter(len))
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => cmp_decimal(l, l_scale, r, r_scale) == Ordering::Equal,
+                (None, None) => true,
+                _ => false,
+            })
+            .collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+
+    fn eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord == Ordering::Equal)
+            .expect(LENGTH_MISMATCH)
+    }
+
+    fn neq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord != Ordering::Equal)
+            .expect(LENGTH_MISMATCH)
+    }
+
+    fn gt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord == Ordering::Greater)
+            .expect(LENGTH_MISMATCH)
+    }
+
+    fn gt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord != Ordering::Less)
+            .expect(LENGTH_MISMATCH)
+    }
+
+    fn lt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord == Ordering::Less)
+            .expect(LENGTH_MISMATCH)
+    }
+
+    fn lt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        self.compare(rhs, |ord| ord != Ordering::Greater)
+            .expect(LENGTH_MISMATCH)
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl NumOpsDispatch for DecimalChunked {
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        self.checked_sub(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        self.checked_add(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        self.checked_mul(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        self.checked_div(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_format_decimal() {
+        assert_eq!(parse_decimal("12.345", 2), Some(1234));
+        assert_eq!(parse_decimal("-0.5", 3), Some(-500));
+        assert_eq!(parse_decimal("7", 1), Some(70));
+        assert_eq!(parse_decimal("1.2.3", 1), None);
+        assert_eq!(parse_decimal("", 1), None);
+        assert_eq!(format_decimal(1234, 2), "12.34");
+        assert_eq!(format_decimal(-5, 3), "-0.005");
+        assert_eq!(format_decimal(42, 0), "42");
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        let a = DecimalChunked::new_from_slice("a", &[1050, -200], 10, 2).unwrap();
+        let b = DecimalChunked::new_from_slice("b", &[5, 25], 5, 1).unwrap();
+
+        let out = a.checked_add(&b).unwrap();
+        assert_eq!(out.scale(), 2);
+        assert_eq!(out.iter().collect::<Vec<_>>(), &[Some(1100), Some(50)]);
+
+        let out = a.checked_mul(&b).unwrap();
+        assert_eq!(out.scale(), 3);
+        assert_eq!(out.iter().collect::<Vec<_>>(), &[Some(5250), Some(-5000)]);
+
+        // 10.50 / 0.5 = 21.00 and -2.00 / 2.5 = -0.80
+        let out = a.checked_div(&b).unwrap();
+        assert_eq!(out.scale(), 2);
+        assert_eq!(out.iter().collect::<Vec<_>>(), &[Some(2100), Some(-80)]);
+
+        assert_eq!(Vec::from(&a.gt(&b)), &[Some(true), Some(false)]);
+        assert_eq!(a.sum().unwrap(), Some(850));
+
+        let c = DecimalChunked::new_from_slice("c", &[1, 2, 3], 5, 1).unwrap();
+        assert!(a.compare(&c, |l, r| l == r).is_err());
+    }
+
+    #[test]
+    fn test_decimal_overflow() {
+        let max = pow10(MAX_DECIMAL_PRECISION).unwrap() - 1;
+        let a = DecimalChunked::new_from_slice("a", &[max], 38, 0).unwrap();
+        assert!(a.checked_add(&a).is_err());
+        assert!(a.checked_mul(&a).is_err());
+        assert!(DecimalChunked::new_from_slice("a", &[1000], 3, 0).is_err());
+        assert!(DecimalChunkedBuilder::new("a", 1, 39, 0).is_err());
+        assert!(DecimalChunkedBuilder::new("a", 1, 2, 3).is_err());
+
+        let zero = DecimalChunked::new_from_slice("b", &[0], 1, 0).unwrap();
+        assert!(a.checked_div(&zero).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_sum_as_series() {
+        // the sum no longer fits the precision of the input, but it does fit the widened precision
+        let a = DecimalChunked::new_from_slice("a", &[999, 999], 3, 1).unwrap();
+        let s = a.into_series();
+        let sum = s.try_sum_as_series().unwrap();
+        assert_eq!(sum.dtype(), &DataType::Decimal(MAX_DECIMAL_PRECISION, 1));
+        assert_eq!(sum.decimal().unwrap().get(0), Some(1998));
+
+        let max = pow10(MAX_DECIMAL_PRECISION).unwrap() - 1;
+        let s = DecimalChunked::new_from_slice("a", &[max, max], 38, 0)
+            .unwrap()
+            .into_series();
+        assert!(s.try_sum_as_series().is_err());
+        assert_eq!(s.sum_as_series().null_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_cast() {
+        let s = Series::new("a", &["1.25", "-3", "foo"]);
+        let s = s.cast_with_datatype(&DataType::Decimal(10, 2)).unwrap();
+        assert_eq!(s.dtype(), &DataType::Decimal(10, 2));
+        assert_eq!(
+            s.decimal().unwrap().iter().collect::<Vec<_>>(),
+            &[Some(125), Some(-300), None]
+        );
+
+        let out = s.cast::<Utf8Type>().unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[Some("1.25"), Some("-3.00"), None]
+        );
+        let out = s.cast::<Float64Type>().unwrap();
+        assert_eq!(out.f64().unwrap().get(0), Some(1.25));
+
+        let s = Series::new("a", &[1.005f64, 2.5]);
+        let s = s.cast_with_datatype(&DataType::Decimal(5, 1)).unwrap();
+        assert_eq!(
+            s.decimal().unwrap().iter().collect::<Vec<_>>(),
+            &[Some(10), Some(25)]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-decimal", feature = "dtype-u64"))]
+    fn test_decimal_cast_u64() {
+        let s = Series::new("a", &[u64::MAX, 1]);
+        let s = s.cast_with_datatype(&DataType::Decimal(25, 2)).unwrap();
+        assert_eq!(
+            s.decimal().unwrap().iter().collect::<Vec<_>>(),
+            &[Some(u64::MAX as i128 * 100), Some(100)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_groupby_key() {
+        let key = DecimalChunked::new_from_opt_iter(
+            "key",
+            vec![Some(150), Some(200), Some(150), None, None].into_iter(),
+            5,
+            2,
+        )
+        .unwrap()
+        .into_series();
+        let val = Series::new("val", &[1, 2, 3, 4, 5]);
+        let df = DataFrame::new(vec![key, val.clone()]).unwrap();
+
+        let out = df
+            .groupby_stable("key")
+            .unwrap()
+            .select("val")
+            .sum()
+            .unwrap();
+        assert_eq!(
+            out.column("key")
+                .unwrap()
+                .decimal()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some(150), Some(200), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("val_sum").unwrap().i32().unwrap()),
+            &[Some(4), Some(2), Some(9)]
+        );
+
+        // multiple keys hash and compare the decimal values
+        assert!(df.hash_rows(0).is_ok());
+        let other = Series::new("other", &[1, 1, 1, 1, 2]);
+        let df = DataFrame::new(vec![df.column("key").unwrap().clone(), other, val]).unwrap();
+        let out = df
+            .groupby_stable(&["key", "other"])
+            .unwrap()
+            .select("val")
+            .sum()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("val_sum").unwrap().i32().unwrap()),
+            &[Some(4), Some(2), Some(4), Some(5)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_groupby_values() {
+        let key = Series::new("key", &["a", "b", "a", "a"]);
+        let val = DecimalChunked::new_from_opt_iter(
+            "val",
+            vec![Some(125), Some(-50), None, Some(75)].into_iter(),
+            5,
+            2,
+        )
+        .unwrap()
+        .into_series();
+        let df = DataFrame::new(vec![key, val]).unwrap();
+        let gb = df.groupby_stable("key").unwrap();
+        let decimals = |df: DataFrame, name: &str| {
+            df.column(name)
+                .unwrap()
+                .decimal()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+
+        let out = gb.select("val").sum().unwrap();
+        assert_eq!(
+            out.column("val_sum").unwrap().dtype(),
+            &DataType::Decimal(MAX_DECIMAL_PRECISION, 2)
+        );
+        assert_eq!(decimals(out, "val_sum"), &[Some(200), Some(-50)]);
+        let out = gb.select("val").mean().unwrap();
+        assert_eq!(
+            Vec::from(out.column("val_mean").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(-0.5)]
+        );
+        let out = gb.select("val").min().unwrap();
+        assert_eq!(decimals(out, "val_min"), &[Some(75), Some(-50)]);
+        let out = gb.select("val").max().unwrap();
+        assert_eq!(decimals(out, "val_max"), &[Some(125), Some(-50)]);
+        let out = gb.select("val").first().unwrap();
+        assert_eq!(decimals(out, "val_first"), &[Some(125), Some(-50)]);
+        let out = gb.select("val").last().unwrap();
+        assert_eq!(decimals(out, "val_last"), &[Some(75), Some(-50)]);
+        let out = gb.select("val").n_unique().unwrap();
+        assert_eq!(
+            Vec::from(out.column("val_n_unique").unwrap().u32().unwrap()),
+            &[Some(3), Some(1)]
+        );
+
+        let out = gb.select("val").agg_list().unwrap();
+        let list = out.column("val_agg_list").unwrap().list().unwrap();
+        let first = list.get(0).unwrap();
+        assert_eq!(first.dtype(), &DataType::Decimal(5, 2));
+        assert_eq!(
+            first.decimal().unwrap().iter().collect::<Vec<_>>(),
+            &[Some(125), None, Some(75)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_groupby_overflow() {
+        let max = pow10(MAX_DECIMAL_PRECISION).unwrap() - 1;
+        let key = Series::new("key", &[1, 1, 2]);
+        let val = DecimalChunked::new_from_slice("val", &[max, max, 1], 38, 0)
+            .unwrap()
+            .into_series();
+        let df = DataFrame::new(vec![key, val]).unwrap();
+        let gb = df.groupby_stable("key").unwrap();
+        assert!(gb.select("val").sum().is_err());
+        assert!(gb.select("val").mean().is_err());
+
+        let s = df.column("val").unwrap();
+        assert_eq!(s.agg_sum(gb.get_groups()).unwrap().null_count(), 2);
+    }
+}
@@ -0,0 +1,504 @@
+//! Operations on the lists of a `ListChunked`.
+//!
+//! A list array stores the values of all lists in a single inner array and an offsets buffer that
+//! marks where every list starts. These kernels work on the inner values and the offsets directly,
+//! so the `ListChunked` is never exploded to a `DataFrame`-sized intermediate.
+use crate::frame::groupby::GroupTuples;
+use crate::prelude::*;
+use crate::utils::{get_supertype, NoNull};
+use arrow::array::{Array, ArrayData, ArrayRef, BooleanBufferBuilder, LargeListArray};
+use arrow::buffer::Buffer;
+use arrow::datatypes::ToByteSlice;
+use std::convert::TryFrom;
+
+/// The inner values of a list array and the `[start, len]` of every list in these values.
+struct ListValues {
+    values: Series,
+    // `[start, len]` per list. A null list has length 0.
+    lists: Vec<[u32; 2]>,
+    validity: Vec<bool>,
+}
+
+impl ListValues {
+    fn new(ca: &ListChunked) -> Self {
+        let ca = ca.rechunk();
+        let arr = ca
+            .downcast_iter()
+            .next()
+            .expect("a rechunked array has a single chunk");
+        let values = Series::try_from(("", arr.values())).unwrap();
+        let offsets = arr.value_offsets();
+
+        let mut lists = Vec::with_capacity(arr.len());
+        let mut validity = Vec::with_capacity(arr.len());
+        for i in 0..arr.len() {
+            let valid = arr.is_valid(i);
+            let start = offsets[i] as u32;
+            let len = if valid {
+                (offsets[i + 1] - offsets[i]) as u32
+            } else {
+                0
+            };
+            lists.push([start, len]);
+            validity.push(valid);
+        }
+        ListValues {
+            values,
+            lists,
+            validity,
+        }
+    }
+
+    fn groups(&self) -> GroupTuples {
+        GroupTuples::Slice(self.lists.clone())
+    }
+
+    /// The list every inner value belongs to, `None` for values that are not in a valid list.
+    fn list_ids(&self) -> Vec<Option<u32>> {
+        let mut ids = vec![None; self.values.len()];
+        for (i, [start, len]) in self.lists.iter().enumerate() {
+            let start = *start as usize;
+            ids[start..start + *len as usize]
+                .iter_mut()
+                .for_each(|id| *id = Some(i as u32));
+        }
+        ids
+    }
+
+    /// Set the aggregates of empty and null lists to null.
+    fn null_empty_lists(&self, s: Series) -> Series {
+        if self.lists.iter().all(|[_, len]| *len > 0) {
+            return s;
+        }
+        let idx: UInt32Chunked = self
+            .lists
+            .iter()
+            .enumerate()
+            .map(|(i, [_, len])| if *len > 0 { Some(i as u32) } else { None })
+            .collect();
+        s.take(&idx)
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.validity.iter().any(|valid| !valid)
+    }
+
+    /// Create a new list array that gathers `idx` from the inner values. `lengths` are the
+    /// lengths of the new lists.
+    fn take_lists(
+        &self,
+        name: &str,
+        values: &Series,
+        idx: &[u32],
+        lengths: impl Iterator<Item = usize>,
+    ) -> ListChunked {
+        let idx: NoNull<UInt32Chunked> = idx.iter().copied().collect();
+        let values = values.take(&idx.into_inner()).rechunk();
+        build_list_array(name, &values, lengths, Some(&self.validity))
+    }
+}
+
+/// Build a `ListChunked` from a single chunked values `Series` and the lengths of the lists.
+pub(crate) fn build_list_array(
+    name: &str,
+    values: &Series,
+    lengths: impl Iterator<Item = usize>,
+    validity: Option<&[bool]>,
+) -> ListChunked {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut offset = 0i64;
+    offsets.push(offset);
+    for len in lengths {
+        offset += len as i64;
+        offsets.push(offset);
+    }
+    debug_assert_eq!(offset as usize, values.len());
+
+    let inner = ArrowField::new("item", values.dtype().to_arrow(), true);
+    let mut builder = ArrayData::builder(ArrowDataType::LargeList(Box::new(inner)))
+        .len(offsets.len() - 1)
+        .add_buffer(Buffer::from(offsets.to_byte_slice()))
+        .add_child_data(values.chunks()[0].data().clone());
+
+    if let Some(validity) = validity {
+        if validity.iter().any(|valid| !valid) {
+            let mut bitmap_builder = BooleanBufferBuilder::new(validity.len());
+            for valid in validity {
+                bitmap_builder.append(*valid);
+            }
+            builder = builder.null_bit_buffer(bitmap_builder.finish());
+        }
+    }
+    let arr = Arc::new(LargeListArray::from(builder.build())) as ArrayRef;
+    ListChunked::new_from_chunks(name, vec![arr])
+}
+
+fn agg_err(agg: &str, dtype: &DataType) -> PolarsError {
+    PolarsError::InvalidOperation(format!("cannot take the {} of lists of {:?}", agg, dtype).into())
+}
+
+impl ListChunked {
+    /// Get the length of every list.
+    pub fn lst_lengths(&self) -> UInt32Chunked {
+        let lv = ListValues::new(self);
+        let mut ca: UInt32Chunked = lv
+            .lists
+            .iter()
+            .zip(&lv.validity)
+            .map(|([_, len], valid)| if *valid { Some(*len) } else { None })
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get the sum of every list. The sum of an empty or a null list is null; the sums of
+    /// boolean lists are counted as `UInt32`.
+    pub fn lst_sum(&self) -> Result<Series> {
+        let lv = ListValues::new(self);
+        let s = lv
+            .values
+            .agg_sum(&lv.groups())
+            .ok_or_else(|| agg_err("sum", lv.values.dtype()))?;
+        let mut s = lv.null_empty_lists(s);
+        s.rename(self.name());
+        Ok(s)
+    }
+
+    /// Get the mean of every list. The mean of an empty or a null list is null.
+    pub fn lst_mean(&self) -> Result<Series> {
+        let lv = ListValues::new(self);
+        let s = lv
+            .values
+            .agg_mean(&lv.groups())
+            .ok_or_else(|| agg_err("mean", lv.values.dtype()))?;
+        let mut s = lv.null_empty_lists(s);
+        s.rename(self.name());
+        Ok(s)
+    }
+
+    /// Get the minimum of every list. The minimum of an empty or a null list is null.
+    pub fn lst_min(&self) -> Result<Series> {
+        let lv = ListValues::new(self);
+        let s = lv
+            .values
+            .agg_min(&lv.groups())
+            .ok_or_else(|| agg_err("min", lv.values.dtype()))?;
+        let mut s = lv.null_empty_lists(s);
+        s.rename(self.name());
+        Ok(s)
+    }
+
+    /// Get the maximum of every list. The maximum of an empty or a null list is null.
+    pub fn lst_max(&self) -> Result<Series> {
+        let lv = ListValues::new(self);
+        let s = lv
+            .values
+            .agg_max(&lv.groups())
+            .ok_or_else(|| agg_err("max", lv.values.dtype()))?;
+        let mut s = lv.null_empty_lists(s);
+        s.rename(self.name());
+        Ok(s)
+    }
+
+    /// Get the value at `index` of every list. A negative index counts from the end of the list.
+    /// Lists that are too short give a null value.
+    pub fn lst_get(&self, index: i64) -> Series {
+        let lv = ListValues::new(self);
+        let idx: UInt32Chunked = lv
+            .lists
+            .iter()
+            .map(|[start, len]| {
+                let len = *len as i64;
+                let i = if index < 0 { len + index } else { index };
+                if i >= 0 && i < len {
+                    Some(*start + i as u32)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let mut s = lv.values.take(&idx);
+        s.rename(self.name());
+        s
+    }
+
+    /// Slice every list. A negative `offset` counts from the end of the list.
+    pub fn lst_slice(&self, offset: i64, length: usize) -> ListChunked {
+        let lv = ListValues::new(self);
+        let mut idx = Vec::with_capacity(lv.values.len());
+        let mut lengths = Vec::with_capacity(lv.lists.len());
+        for [start, len] in &lv.lists {
+            let len = *len as i64;
+            let begin = if offset < 0 { len + offset } else { offset };
+            let begin = begin.max(0).min(len);
+            let end = (begin + length as i64).min(len);
+            idx.extend((begin..end).map(|i| *start + i as u32));
+            lengths.push((end - begin) as usize);
+        }
+        lv.take_lists(self.name(), &lv.values, &idx, lengths.into_iter())
+    }
+
+    /// Check if every list contains `item`. `item` should have length 1.
+    pub fn lst_contains(&self, item: &Series) -> Result<BooleanChunked> {
+        if item.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                "the item to search for should have length 1".into(),
+            ));
+        }
+        let lv = ListValues::new(self);
+        let item = item.cast_with_datatype(lv.values.dtype())?;
+        let mask = lv.values.eq(&item);
+        let mask: Vec<bool> = mask.into_iter().map(|opt_v| opt_v == Some(true)).collect();
+
+        let mut ca: BooleanChunked = lv
+            .lists
+            .iter()
+            .zip(&lv.validity)
+            .map(|([start, len], valid)| {
+                if *valid {
+                    let start = *start as usize;
+                    Some(mask[start..start + *len as usize].iter().any(|v| *v))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Sort the values within every list.
+    pub fn lst_sort(&self, reverse: bool) -> ListChunked {
+        let lv = ListValues::new(self);
+        let ids = lv.list_ids();
+
+        // sort all inner values at once and scatter the sorted positions to the lists they
+        // belong to, so that every list is in sorted order
+        let mut cursors = Vec::with_capacity(lv.lists.len());
+        let mut offset = 0;
+        for [_, len] in &lv.lists {
+            cursors.push(offset);
+            offset += *len as usize;
+        }
+        let mut idx = vec![0u32; offset];
+        for j in lv.values.argsort(reverse).into_no_null_iter() {
+            if let Some(list) = ids[j as usize] {
+                let cursor = &mut cursors[list as usize];
+                idx[*cursor] = j;
+                *cursor += 1;
+            }
+        }
+        let lengths = lv.lists.iter().map(|[_, len]| *len as usize);
+        lv.take_lists(self.name(), &lv.values, &idx, lengths)
+    }
+
+    /// Keep the unique values of every list. The order of the first occurrences is maintained.
+    pub fn lst_unique(&self) -> Result<ListChunked> {
+        let lv = ListValues::new(self);
+        let ids = lv.list_ids();
+
+        // group all inner values by their list and value at once
+        let mut list_col: UInt32Chunked = ids.iter().copied().collect();
+        list_col.rename("list");
+        let mut values = lv.values.clone();
+        values.rename("value");
+        let df = DataFrame::new_no_checks(vec![list_col.into_series(), values]);
+        let gb = df.groupby(vec!["list", "value"])?;
+
+        // the lists are stored in order, so sorting the first occurrences by position gives the
+        // unique values of every list in the order of the lists
+        let mut idx: Vec<u32> = gb
+            .get_groups()
+            .iter_first()
+            .filter(|first| ids[*first as usize].is_some())
+            .collect();
+        idx.sort_unstable();
+        let mut lengths = vec![0usize; lv.lists.len()];
+        for first in &idx {
+            if let Some(list) = ids[*first as usize] {
+                lengths[list as usize] += 1;
+            }
+        }
+        Ok(lv.take_lists(self.name(), &lv.values, &idx, lengths.into_iter()))
+    }
+
+    /// Join the strings of every list with `separator`. Only valid for lists of Utf8.
+    pub fn lst_join(&self, separator: &str) -> Result<Utf8Chunked> {
+        let lv = ListValues::new(self);
+        let values = lv.values.utf8()?;
+        let mut ca: Utf8Chunked = lv
+            .lists
+            .iter()
+            .zip(&lv.validity)
+            .map(|([start, len], valid)| {
+                if *valid {
+                    let list = values.slice(*start as i64, *len as usize);
+                    let joined = list
+                        .into_iter()
+                        .map(|opt_s| opt_s.unwrap_or("null"))
+                        .collect::<Vec<_>>()
+                        .join(separator);
+                    Some(joined)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Concatenate the lists of `self` and `other` row by row. A null list is treated as an
+    /// empty list unless both lists are null.
+    pub fn lst_concat(&self, other: &ListChunked) -> Result<ListChunked> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "cannot concatenate lists of columns with a different length".into(),
+            ));
+        }
+        let left = ListValues::new(self);
+        let right = ListValues::new(other);
+        let dtype = get_supertype(left.values.dtype(), right.values.dtype())?;
+
+        let mut values = left.values.cast_with_datatype(&dtype)?;
+        let offset = values.len() as u32;
+        values.append(&right.values.cast_with_datatype(&dtype)?)?;
+
+        let mut idx = Vec::with_capacity(values.len());
+        let mut lengths = Vec::with_capacity(self.len());
+        for ([l_start, l_len], [r_start, r_len]) in left.lists.iter().zip(&right.lists) {
+            idx.extend(*l_start..*l_start + *l_len);
+            idx.extend(offset + *r_start..offset + *r_start + *r_len);
+            lengths.push((*l_len + *r_len) as usize);
+        }
+        let idx: NoNull<UInt32Chunked> = idx.into_iter().collect();
+        let values = values.take(&idx.into_inner()).rechunk();
+
+        let validity = if left.has_nulls() && right.has_nulls() {
+            let validity: Vec<_> = left
+                .validity
+                .iter()
+                .zip(&right.validity)
+                .map(|(l, r)| *l || *r)
+                .collect();
+            Some(validity)
+        } else {
+            None
+        };
+        Ok(build_list_array(
+            self.name(),
+            &values,
+            lengths.into_iter(),
+            validity.as_deref(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::builder::get_list_builder;
+
+    fn get_list() -> ListChunked {
+        let mut builder = get_list_builder(&DataType::Int32, 10, 4, "a");
+        builder.append_series(&Series::new("", &[3, 1, 2]));
+        builder.append_null();
+        builder.append_series(&Series::new("", &[5, 5]));
+        builder.append_series(&Series::new("", &[] as &[i32]));
+        builder.finish()
+    }
+
+    #[test]
+    fn test_list_aggregations() {
+        let ca = get_list();
+        assert_eq!(
+            Vec::from(&ca.lst_lengths()),
+            &[Some(3), None, Some(2), Some(0)]
+        );
+        let sum = ca.lst_sum().unwrap();
+        assert_eq!(sum.i32().unwrap().get(0), Some(6));
+        assert_eq!(sum.i32().unwrap().get(2), Some(10));
+        let max = ca.lst_max().unwrap();
+        assert_eq!(max.i32().unwrap().get(0), Some(3));
+    }
+
+    #[test]
+    fn test_list_aggregations_null_and_empty() {
+        // the lists are [3, 1, 2], null, [5, 5] and []
+        let ca = get_list();
+        for s in &[
+            ca.lst_sum().unwrap(),
+            ca.lst_min().unwrap(),
+            ca.lst_max().unwrap(),
+        ] {
+            assert_eq!(s.dtype(), &DataType::Int32);
+            assert_eq!(s.null_count(), 2);
+            assert!(s.i32().unwrap().get(1).is_none());
+            assert!(s.i32().unwrap().get(3).is_none());
+        }
+        assert_eq!(
+            Vec::from(ca.lst_min().unwrap().i32().unwrap()),
+            &[Some(1), None, Some(5), None]
+        );
+    }
+
+    #[test]
+    fn test_list_get_slice_contains() {
+        let ca = get_list();
+        let s = ca.lst_get(-1);
+        assert_eq!(Vec::from(s.i32().unwrap()), &[Some(2), None, Some(5), None]);
+        let sliced = ca.lst_slice(1, 5);
+        assert_eq!(
+            Vec::from(&sliced.lst_lengths()),
+            &[Some(2), None, Some(1), Some(0)]
+        );
+        let contains = ca.lst_contains(&Series::new("", &[5])).unwrap();
+        assert_eq!(
+            Vec::from(&contains),
+            &[Some(false), None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_list_sort_unique_concat() {
+        let ca = get_list();
+        let sorted = ca.lst_sort(false);
+        assert_eq!(
+            Vec::from(sorted.get(0).unwrap().i32().unwrap()),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(&sorted.lst_lengths()),
+            &[Some(3), None, Some(2), Some(0)]
+        );
+        let sorted = ca.lst_sort(true);
+        assert_eq!(
+            Vec::from(sorted.get(0).unwrap().i32().unwrap()),
+            &[Some(3), Some(2), Some(1)]
+        );
+        let unique = ca.lst_unique().unwrap();
+        assert_eq!(
+            Vec::from(&unique.lst_lengths()),
+            &[Some(3), None, Some(1), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(unique.get(0).unwrap().i32().unwrap()),
+            &[Some(3), Some(1), Some(2)]
+        );
+        let concat = ca.lst_concat(&ca).unwrap();
+        assert_eq!(
+            Vec::from(&concat.lst_lengths()),
+            &[Some(6), None, Some(4), Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_list_join() {
+        let mut builder = get_list_builder(&DataType::Utf8, 10, 2, "a");
+        builder.append_series(&Series::new("", &["a", "b"]));
+        builder.append_series(&Series::new("", &["c"]));
+        let ca = builder.finish();
+        let joined = ca.lst_join("-").unwrap();
+        assert_eq!(Vec::from(&joined), &[Some("a-b"), Some("c")]);
+    }
+}
@@ -3,9 +3,9 @@ use crate::chunked_array::builder::get_bitmap;
 use crate::prelude::*;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Date64Array, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, LargeStringArray, Time64NanosecondArray, UInt16Array, UInt32Array,
-        UInt64Array, UInt8Array,
+        ArrayRef, BooleanArray, Date64Array, DecimalArray, Float32Array, Float64Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, LargeStringArray, Time64NanosecondArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array,
     },
     buffer::Buffer,
     datatypes::TimeUnit,
@@ -19,13 +19,19 @@ use std::sync::Arc;
 pub mod ops;
 #[macro_use]
 pub mod arithmetic;
+#[cfg(feature = "dtype-binary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dtype-binary")))]
+pub mod binary;
 pub mod boolean;
 pub mod builder;
 pub mod cast;
+pub mod categorical;
 pub mod comparison;
+pub mod decimal;
 pub mod float;
 pub mod iterator;
 pub mod kernels;
+pub mod list;
 #[cfg(feature = "ndarray")]
 mod ndarray;
 
@@ -38,6 +44,9 @@ mod random;
 #[cfg(feature = "strings")]
 #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
 pub mod strings;
+#[cfg(feature = "dtype-struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dtype-struct")))]
+pub mod struct_;
 #[cfg(feature = "temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
 pub mod temporal;
@@ -161,6 +170,29 @@ pub struct ChunkedArray<T> {
     phantom: PhantomData<T>,
     /// maps categorical u32 indexes to String values
     pub(crate) categorical_map: Option<Arc<AHashMap<u32, String>>>,
+    /// the order used when sorting categorical values
+    pub(crate) categorical_ordering: CategoricalOrdering,
+    /// whether the values are known to be sorted
+    pub(crate) sorted: IsSorted,
+}
+
+/// Whether the values of a [ChunkedArray](ChunkedArray) are known to be sorted.
+///
+/// Null values are regarded as smaller than any other value, so they are at the start of an
+/// ascending array and at the end of a descending array, like the output of
+/// [sort](crate::chunked_array::ops::ChunkSort::sort).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IsSorted {
+    Ascending,
+    Descending,
+    /// The values are not sorted, or it is not known whether they are.
+    Not,
+}
+
+impl Default for IsSorted {
+    fn default() -> Self {
+        IsSorted::Not
+    }
 }
 
 impl<T> ChunkedArray<T> {
@@ -169,6 +201,17 @@ impl<T> ChunkedArray<T> {
         self.chunks.iter().map(|arr| arr.data()).collect()
     }
 
+    /// Get whether the values are known to be sorted.
+    pub fn is_sorted(&self) -> IsSorted {
+        self.sorted
+    }
+
+    /// Mark the values as sorted. Operations use this to skip or speed up work, so this should
+    /// only be set if the values are sorted as described by [IsSorted](IsSorted).
+    pub fn set_sorted(&mut self, sorted: IsSorted) {
+        self.sorted = sorted
+    }
+
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_categorical_map(&self) -> Option<&Arc<AHashMap<u32, String>>> {
         self.categorical_map.as_ref()
@@ -318,6 +361,7 @@ impl<T> ChunkedArray<T> {
         if self.field.data_type() == other.data_type() {
             self.chunks.push(other);
             self.chunk_id = create_chunk_id(&self.chunks);
+            self.sorted = IsSorted::Not;
             Ok(())
         } else {
             Err(PolarsError::DataTypeMisMatch(
@@ -340,6 +384,9 @@ impl<T> ChunkedArray<T> {
             chunk_id,
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            categorical_ordering: self.categorical_ordering,
+            // the new chunks may hold different values
+            sorted: IsSorted::Not,
         }
     }
 
@@ -375,7 +422,10 @@ impl<T> ChunkedArray<T> {
                 break;
             }
         }
-        self.copy_with_chunks(new_chunks)
+        let mut out = self.copy_with_chunks(new_chunks);
+        // a slice of sorted values is sorted
+        out.sorted = self.sorted;
+        out
     }
 
     /// Get a mask of the null values.
@@ -452,18 +502,26 @@ impl<T> ChunkedArray<T> {
     where
         Self: std::marker::Sized,
     {
-        if matches!(self.dtype(), DataType::Categorical) && !self.is_empty() {
-            assert!(Arc::ptr_eq(
-                self.categorical_map.as_ref().unwrap(),
-                other.categorical_map.as_ref().unwrap()
-            ));
+        if matches!(self.dtype(), DataType::Categorical) {
+            // Safety:
+            // the dtype is categorical, so both arrays are CategoricalChunked
+            let (this, other) = unsafe {
+                (
+                    &mut *(self as *mut Self as *mut CategoricalChunked),
+                    &*(other as *const Self as *const CategoricalChunked),
+                )
+            };
+            this.append_categorical(other);
+            return;
         }
 
         // replace an empty array
         if self.chunks.len() == 1 && self.is_empty() {
             self.chunks = other.chunks.clone();
+            self.sorted = other.sorted;
         } else {
-            self.chunks.extend_from_slice(&other.chunks)
+            self.chunks.extend_from_slice(&other.chunks);
+            self.sorted = IsSorted::Not;
         }
         self.chunk_id = create_chunk_id(&self.chunks);
     }
@@ -522,15 +580,12 @@ where
 {
     /// Create a new ChunkedArray from existing chunks.
     pub fn new_from_chunks(name: &str, chunks: Vec<ArrayRef>) -> Self {
-        // prevent List<Null> if the inner list type is known.
-        let datatype = if matches!(T::get_dtype(), DataType::List(_)) {
-            if let Some(arr) = chunks.get(0) {
-                arr.data_type().into()
-            } else {
-                T::get_dtype()
-            }
-        } else {
-            T::get_dtype()
+        let datatype = match (T::get_dtype(), chunks.get(0)) {
+            // prevent List<Null> if the inner list type is known.
+            (DataType::List(_), Some(arr)) => arr.data_type().into(),
+            // the precision and scale are only known by the arrow array.
+            (DataType::Decimal(_, _), Some(arr)) => arr.data_type().into(),
+            (dt, _) => dt,
         };
         let field = Arc::new(Field::new(name, datatype));
         let chunk_id = create_chunk_id(&chunks);
@@ -540,6 +595,8 @@ where
             chunk_id,
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 
@@ -608,6 +665,15 @@ where
                         .unwrap(),
                 )
             }
+            DataType::Decimal(_, _) => {
+                let arr = &*(arr as *const dyn Array as *const DecimalArray);
+                AnyValue::Decimal(arr.value(idx), arr.scale())
+            }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => {
+                let arr = &*(arr as *const dyn Array as *const arrow::array::LargeBinaryArray);
+                AnyValue::Binary(arr.value(idx))
+            }
             _ => unimplemented!(),
         }
     }
@@ -659,6 +725,8 @@ where
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -828,6 +896,8 @@ impl<T> Clone for ChunkedArray<T> {
             chunk_id: self.chunk_id.clone(),
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            categorical_ordering: self.categorical_ordering,
+            sorted: self.sorted,
         }
     }
 }
@@ -867,6 +937,7 @@ impl From<UInt32Chunked> for CategoricalChunked {
 impl CategoricalChunked {
     fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
         self.categorical_map = other.categorical_map.clone();
+        self.categorical_ordering = other.categorical_ordering;
         self
     }
 }
@@ -920,6 +991,73 @@ pub(crate) mod test {
         assert_eq!(b, [Some("a"), Some("b"), Some("c")]);
     }
 
+    #[test]
+    fn test_sorted_flag() {
+        let a = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(1), Some(2)]);
+        assert_eq!(a.is_sorted(), IsSorted::Not);
+        assert_eq!(a.detect_sorted(), IsSorted::Not);
+
+        let sorted = a.sort(false);
+        assert_eq!(sorted.is_sorted(), IsSorted::Ascending);
+        assert_eq!(sorted.detect_sorted(), IsSorted::Ascending);
+        assert_eq!(sorted.min(), Some(1));
+        assert_eq!(sorted.max(), Some(3));
+        assert_eq!(
+            Vec::from(&sorted.argsort(false)),
+            &[Some(0), Some(1), Some(2), Some(3)]
+        );
+        let desc = a.sort(true);
+        assert_eq!(desc.is_sorted(), IsSorted::Descending);
+        assert_eq!(Vec::from(&desc), &[Some(3), Some(2), Some(1), None]);
+        assert_eq!(desc.min(), Some(1));
+        assert_eq!(desc.max(), Some(3));
+
+        // slices and filters of sorted values remain sorted
+        assert_eq!(sorted.slice(1, 2).is_sorted(), IsSorted::Ascending);
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true, true]);
+        assert_eq!(
+            sorted.filter(&mask).unwrap().is_sorted(),
+            IsSorted::Ascending
+        );
+        assert_eq!(
+            sorted.take([3usize, 0].iter().copied().into()).is_sorted(),
+            IsSorted::Not
+        );
+        let mut appended = sorted.clone();
+        appended.append(&a);
+        assert_eq!(appended.is_sorted(), IsSorted::Not);
+
+        // comparisons with a scalar use a binary search
+        let mut b = Int32Chunked::new_from_slice("b", &[1, 2, 2, 5, 8]);
+        b.set_sorted(IsSorted::Ascending);
+        assert_eq!(
+            Vec::from(&b.gt(2)),
+            &[
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true)
+            ]
+        );
+        assert_eq!(
+            Vec::from(&b.lt_eq(2)),
+            &[Some(true), Some(true), Some(true), Some(false), Some(false)]
+        );
+        let b = b.sort(true);
+        assert_eq!(
+            Vec::from(&b.gt_eq(5)),
+            &[
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false)
+            ]
+        );
+        assert_eq!(Vec::from(&b.lt(0)), &[Some(false); 5]);
+    }
+
     #[test]
     fn arithmetic() {
         let s1 = get_chunked_array();
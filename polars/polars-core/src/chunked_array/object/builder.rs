@@ -79,6 +79,8 @@ where
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -145,6 +147,8 @@ where
             chunk_id: vec![len],
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
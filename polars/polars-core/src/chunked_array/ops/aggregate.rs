@@ -65,15 +65,14 @@ macro_rules! agg_float_with_nans {
 macro_rules! impl_quantile {
     ($self:expr, $quantile:expr) => {{
         let null_count = $self.null_count();
-        let opt = ChunkSort::sort($self, false)
-            .slice(
-                ((($self.len() - null_count) as f64) * $quantile + null_count as f64) as i64,
-                1,
-            )
+        let idx = ((($self.len() - null_count) as f64) * $quantile + null_count as f64) as usize;
+        // the maximum quantile would otherwise point one past the end of the array
+        let idx = std::cmp::min(idx, $self.len().saturating_sub(1));
+        ChunkSort::sort($self, false)
+            .slice(idx as i64, 1)
             .into_iter()
             .next()
-            .unwrap();
-        opt
+            .flatten()
     }};
 }
 
@@ -95,6 +94,16 @@ where
     }
 
     fn min(&self) -> Option<T::Native> {
+        // null values are at the start of ascending and at the end of descending values
+        match self.is_sorted() {
+            IsSorted::Ascending if self.null_count() < self.len() => {
+                return self.get(self.null_count())
+            }
+            IsSorted::Descending if self.null_count() < self.len() => {
+                return self.get(self.len() - self.null_count() - 1)
+            }
+            _ => {}
+        }
         match T::get_dtype() {
             DataType::Float32 => agg_float_with_nans!(self, min, f32),
             DataType::Float64 => agg_float_with_nans!(self, min, f64),
@@ -106,6 +115,13 @@ where
     }
 
     fn max(&self) -> Option<T::Native> {
+        match self.is_sorted() {
+            IsSorted::Ascending if self.null_count() < self.len() => {
+                return self.get(self.len() - 1)
+            }
+            IsSorted::Descending if self.null_count() < self.len() => return self.get(0),
+            _ => {}
+        }
         match T::get_dtype() {
             DataType::Float32 => agg_float_with_nans!(self, max, f32),
             DataType::Float64 => agg_float_with_nans!(self, max, f64),
@@ -449,7 +465,24 @@ impl ChunkAggSeries for Utf8Chunked {
     }
 }
 
-impl ChunkAggSeries for CategoricalChunked {}
+impl CategoricalChunked {
+    fn extreme_as_series(&self, max: bool) -> Series {
+        let v = self.extreme_code(max);
+        let mut ca: UInt32Chunked = [v].iter().copied().collect();
+        ca.rename(self.name());
+        let ca: CategoricalChunked = ca.cast().unwrap();
+        ca.set_state(self).into_series()
+    }
+}
+
+impl ChunkAggSeries for CategoricalChunked {
+    fn max_as_series(&self) -> Series {
+        self.extreme_as_series(true)
+    }
+    fn min_as_series(&self) -> Series {
+        self.extreme_as_series(false)
+    }
+}
 
 macro_rules! one_null_list {
     ($self:ident) => {{
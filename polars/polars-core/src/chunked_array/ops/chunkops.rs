@@ -29,7 +29,9 @@ where
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.sorted = self.sorted;
+            ca
         }
     }
 }
@@ -41,7 +43,9 @@ impl ChunkOps for BooleanChunked {
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.sorted = self.sorted;
+            ca
         }
     }
 }
@@ -53,7 +57,9 @@ impl ChunkOps for Utf8Chunked {
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.sorted = self.sorted;
+            ca
         }
     }
 }
@@ -63,10 +69,8 @@ impl ChunkOps for CategoricalChunked {
     where
         Self: std::marker::Sized,
     {
-        let cat_map = self.categorical_map.clone();
-        let mut ca = self.cast::<UInt32Type>().unwrap().rechunk().cast().unwrap();
-        ca.categorical_map = cat_map;
-        ca
+        let ca: CategoricalChunked = self.cast::<UInt32Type>().unwrap().rechunk().cast().unwrap();
+        ca.set_state(self)
     }
 }
 
@@ -8,25 +8,6 @@ use arrow::{
 use itertools::Itertools;
 use std::convert::TryFrom;
 
-/// Convert Arrow array offsets to indexes of the original list
-pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<usize> {
-    let mut idx = Vec::with_capacity(capacity);
-
-    let mut count = 0;
-    let mut last_idx = 0;
-    for &offset in offsets.iter().skip(1) {
-        while count < offset {
-            count += 1;
-            idx.push(last_idx)
-        }
-        last_idx += 1;
-    }
-    for _ in 0..(capacity - count as usize) {
-        idx.push(last_idx);
-    }
-    idx
-}
-
 impl ChunkExplode for ListChunked {
     fn explode_and_offsets(&self) -> Result<(Series, &[i64])> {
         // A list array's memory layout is actually already 'exploded', so we can just take the values array
@@ -46,7 +46,10 @@ where
             .zip(filter.downcast_iter())
             .map(|(left, mask)| filter_fn(left, mask).unwrap())
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        // the values that are kept remain sorted
+        ca.sorted = self.sorted;
+        Ok(ca)
     }
 }
 
@@ -67,7 +70,9 @@ impl ChunkFilter<BooleanType> for BooleanChunked {
             .zip(filter.downcast_iter())
             .map(|(left, mask)| filter_fn(left, mask).unwrap())
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        ca.sorted = self.sorted;
+        Ok(ca)
     }
 }
 
@@ -88,7 +93,9 @@ impl ChunkFilter<Utf8Type> for Utf8Chunked {
             .zip(filter.downcast_iter())
             .map(|(left, mask)| filter_fn(left, mask).unwrap())
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        ca.sorted = self.sorted;
+        Ok(ca)
     }
 }
 
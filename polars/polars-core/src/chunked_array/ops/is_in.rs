@@ -60,6 +60,36 @@ impl IsIn for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl IsIn for BinaryChunked {
+    fn is_in(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
+        match list_array.dtype() {
+            DataType::List(dt) if self.dtype() == dt => {
+                let ca: BooleanChunked = self
+                    .iter()
+                    .zip(list_array.into_iter())
+                    .map(|(value, series)| match (value, series) {
+                        (val, Some(series)) => {
+                            let ca = series.unpack::<BinaryType>().unwrap();
+                            ca.iter().any(|a| a == val)
+                        }
+                        _ => false,
+                    })
+                    .collect();
+                Ok(ca)
+            }
+            _ => Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot do is_in operation with left a dtype: {:?} and right a dtype {:?}",
+                    self.dtype(),
+                    list_array.dtype()
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
 impl IsIn for BooleanChunked {
     fn is_in(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
         match list_array.dtype() {
@@ -91,7 +121,18 @@ impl IsIn for BooleanChunked {
 
 impl IsIn for CategoricalChunked {
     fn is_in(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
-        self.cast::<UInt32Type>().unwrap().is_in(list_array)
+        match list_array.inner_dtype() {
+            // compare the categories, the codes of different dictionaries can't be compared.
+            // Lists store the categories of categorical values as utf8.
+            DataType::Utf8 => self.cast::<Utf8Type>()?.is_in(list_array),
+            dt => Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot do is_in operation with a categorical and a list of {:?}, the categories of the list are unknown",
+                    dt
+                )
+                .into(),
+            )),
+        }
     }
 }
 
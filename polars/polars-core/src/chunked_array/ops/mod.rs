@@ -175,6 +175,112 @@ pub trait ChunkWindow {
             "rolling mean not supported for this datatype".into(),
         ))
     }
+
+    /// Apply a rolling variance (moving variance) over the values in this array.
+    /// A window of length `window_size` will traverse the array. The values that fill this window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
+    /// values will be aggregated to their variance.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size` - The length of the window.
+    /// * `weight` - An optional slice with the same length of the window that will be multiplied
+    ///              elementwise with the values in the window.
+    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
+    ///                     `true` -> Null values will be ignored.
+    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
+    /// * `min_periods` -  Amount of elements in the window that should be filled before computing a result.
+    fn rolling_var(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        Err(PolarsError::InvalidOperation(
+            "rolling var not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling standard deviation (moving std) over the values in this array.
+    /// A window of length `window_size` will traverse the array. The values that fill this window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
+    /// values will be aggregated to their standard deviation.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size` - The length of the window.
+    /// * `weight` - An optional slice with the same length of the window that will be multiplied
+    ///              elementwise with the values in the window.
+    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
+    ///                     `true` -> Null values will be ignored.
+    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
+    /// * `min_periods` -  Amount of elements in the window that should be filled before computing a result.
+    fn rolling_std(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        Err(PolarsError::InvalidOperation(
+            "rolling std not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling median (moving median) over the values in this array.
+    /// A window of length `window_size` will traverse the array. The values that fill this window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
+    /// values will be aggregated to their median.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size` - The length of the window.
+    /// * `weight` - An optional slice with the same length of the window that will be multiplied
+    ///              elementwise with the values in the window.
+    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
+    ///                     `true` -> Null values will be ignored.
+    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
+    /// * `min_periods` -  Amount of elements in the window that should be filled before computing a result.
+    fn rolling_median(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        Err(PolarsError::InvalidOperation(
+            "rolling median not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling quantile (moving quantile) over the values in this array.
+    /// A window of length `window_size` will traverse the array. The values that fill this window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
+    /// values will be aggregated to their quantile.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantile` - The quantile to compute, between 0.0 and 1.0.
+    /// * `window_size` - The length of the window.
+    /// * `weight` - An optional slice with the same length of the window that will be multiplied
+    ///              elementwise with the values in the window.
+    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
+    ///                     `true` -> Null values will be ignored.
+    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
+    /// * `min_periods` -  Amount of elements in the window that should be filled before computing a result.
+    fn rolling_quantile(
+        &self,
+        _quantile: f64,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        Err(PolarsError::InvalidOperation(
+            "rolling quantile not supported for this datatype".into(),
+        ))
+    }
 }
 
 /// Custom rolling window functions
@@ -582,6 +688,14 @@ pub trait ChunkSort<T> {
 
     /// Retrieve the indexes needed to sort this array.
     fn argsort(&self, reverse: bool) -> UInt32Chunked;
+
+    /// Retrieve the indexes of the first `k` values of the sorted array.
+    ///
+    /// This is equal to `argsort(reverse).slice(0, k)`, but implementations may use partial
+    /// selection to avoid a full sort.
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        self.argsort(reverse).slice(0, k)
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -74,6 +74,35 @@ fn argsort_branch<T, Fd, Fr>(
     }
 }
 
+/// Select the first `k` values in sorted order without sorting the whole slice.
+///
+/// Ties are broken by the original index, which makes the result equal to the head of a
+/// stable argsort.
+fn arg_top_k_branch<T, F>(vals: &mut Vec<(u32, T)>, k: usize, order_fn: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let cmp = |(idx_a, a): &(u32, T), (idx_b, b): &(u32, T)| {
+        order_fn(a, b).then_with(|| idx_a.cmp(idx_b))
+    };
+    if k < vals.len() {
+        if k > 0 {
+            vals.select_nth_unstable_by(k - 1, &cmp);
+        }
+        vals.truncate(k);
+    }
+    vals.sort_unstable_by(&cmp);
+}
+
+/// The sortedness flag of values sorted in the given direction.
+fn sorted_flag(reverse: bool) -> IsSorted {
+    if reverse {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    }
+}
+
 /// If the sort should be ran parallel or not.
 fn sort_parallel<T>(ca: &ChunkedArray<T>) -> bool {
     ca.len()
@@ -84,6 +113,13 @@ fn sort_parallel<T>(ca: &ChunkedArray<T>) -> bool {
 
 macro_rules! argsort {
     ($self:expr, $reverse:expr) => {{
+        if $self.is_sorted() == sorted_flag($reverse) {
+            let ca: NoNull<UInt32Chunked> = (0..$self.len() as u32).collect();
+            let mut ca = ca.into_inner();
+            ca.rename($self.name());
+            ca.set_sorted(IsSorted::Ascending);
+            return ca;
+        }
         let sort_parallel = sort_parallel($self);
 
         let ca: NoNull<UInt32Chunked> = if $self.null_count() == 0 {
@@ -133,12 +169,111 @@ macro_rules! argsort {
     }};
 }
 
+macro_rules! argsort_top_k {
+    ($self:expr, $k:expr, $reverse:expr) => {{
+        let mut count: u32 = 0;
+        let ca: NoNull<UInt32Chunked> = if $self.null_count() == 0 {
+            let mut vals: Vec<_> = $self
+                .into_no_null_iter()
+                .map(|v| {
+                    let i = count;
+                    count += 1;
+                    (i, v)
+                })
+                .collect();
+
+            if $reverse {
+                arg_top_k_branch(&mut vals, $k, order_reverse)
+            } else {
+                arg_top_k_branch(&mut vals, $k, order_default)
+            }
+            vals.into_iter().map(|(idx, _v)| idx).collect()
+        } else {
+            let mut vals: Vec<_> = $self
+                .into_iter()
+                .map(|v| {
+                    let i = count;
+                    count += 1;
+                    (i, v)
+                })
+                .collect();
+
+            if $reverse {
+                arg_top_k_branch(&mut vals, $k, order_reverse_null)
+            } else {
+                arg_top_k_branch(&mut vals, $k, order_default_null)
+            }
+            vals.into_iter().map(|(idx, _v)| idx).collect()
+        };
+        let mut ca = ca.into_inner();
+        ca.rename($self.name());
+        ca
+    }};
+}
+
 impl<T> ChunkSort<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
     T::Native: std::cmp::PartialOrd,
 {
     fn sort(&self, reverse: bool) -> ChunkedArray<T> {
+        if self.is_sorted() == sorted_flag(reverse) {
+            return self.clone();
+        }
+        let mut out = self.sort_impl(reverse);
+        out.set_sorted(sorted_flag(reverse));
+        out
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        let sorted = self.sort(reverse);
+        self.chunks = sorted.chunks;
+        self.chunk_id = sorted.chunk_id;
+        self.sorted = sorted.sorted;
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        argsort!(self, reverse)
+    }
+
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        argsort_top_k!(self, k, reverse)
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: std::cmp::PartialOrd,
+{
+    /// Determine whether the values are sorted. If the sortedness flag isn't set, the values are
+    /// scanned up to the first pair that is out of order. Values that are all equal are reported
+    /// as ascending and values that can't be compared, like NaN, are never sorted.
+    pub fn detect_sorted(&self) -> IsSorted {
+        if self.is_sorted() != IsSorted::Not {
+            return self.is_sorted();
+        }
+        let (mut ascending, mut descending) = (true, true);
+        // `None < Some(_)`, which puts the nulls where `sort` puts them
+        for (a, b) in self.into_iter().tuple_windows() {
+            match a.partial_cmp(&b) {
+                Some(Ordering::Less) => descending = false,
+                Some(Ordering::Greater) => ascending = false,
+                Some(Ordering::Equal) => {}
+                None => return IsSorted::Not,
+            }
+            if !ascending && !descending {
+                return IsSorted::Not;
+            }
+        }
+        if ascending {
+            IsSorted::Ascending
+        } else {
+            IsSorted::Descending
+        }
+    }
+
+    fn sort_impl(&self, reverse: bool) -> ChunkedArray<T> {
         let sort_parallel = sort_parallel(self);
 
         if let Ok(vals) = self.cont_slice() {
@@ -189,29 +324,29 @@ where
             ca
         }
     }
-
-    fn sort_in_place(&mut self, reverse: bool) {
-        let sorted = self.sort(reverse);
-        self.chunks = sorted.chunks;
-    }
-
-    fn argsort(&self, reverse: bool) -> UInt32Chunked {
-        argsort!(self, reverse)
-    }
 }
 
 macro_rules! sort {
     ($self:ident, $reverse:ident) => {{
-        if $reverse {
+        if $self.is_sorted() == sorted_flag($reverse) {
+            return $self.clone();
+        }
+        let mut ca: Self = if $reverse {
             $self.into_iter().sorted_by(|a, b| b.cmp(a)).collect()
         } else {
             $self.into_iter().sorted_by(|a, b| a.cmp(b)).collect()
-        }
+        };
+        ca.rename($self.name());
+        ca.set_sorted(sorted_flag($reverse));
+        ca
     }};
 }
 
 impl ChunkSort<Utf8Type> for Utf8Chunked {
     fn sort(&self, reverse: bool) -> Utf8Chunked {
+        if self.is_sorted() == sorted_flag(reverse) {
+            return self.clone();
+        }
         let sort_parallel = sort_parallel(self);
 
         let mut v = Vec::from_iter(self);
@@ -226,30 +361,59 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
         // We don't collect from an iterator because we know the total value size
         let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
         v.into_iter().for_each(|opt_v| builder.append_option(opt_v));
-        builder.finish()
+        let mut ca = builder.finish();
+        ca.set_sorted(sorted_flag(reverse));
+        ca
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.chunk_id = sorted.chunk_id;
+        self.sorted = sorted.sorted;
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
         argsort!(self, reverse)
     }
+
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        argsort_top_k!(self, k, reverse)
+    }
 }
 
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort(&self, reverse: bool) -> Self {
-        self.as_ref().sort(reverse).cast().unwrap()
+        match self.categorical_ordering {
+            CategoricalOrdering::Physical => {
+                let ca: CategoricalChunked = self.as_ref().sort(reverse).cast().unwrap();
+                ca.set_state(self)
+            }
+            CategoricalOrdering::Lexical => self.take((&self.argsort(reverse)).into()),
+        }
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
-        self.deref_mut().sort_in_place(reverse)
+        match self.categorical_ordering {
+            CategoricalOrdering::Physical => self.deref_mut().sort_in_place(reverse),
+            CategoricalOrdering::Lexical => *self = self.sort(reverse),
+        }
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
-        self.deref().argsort(reverse)
+        match self.categorical_ordering {
+            CategoricalOrdering::Physical => self.deref().argsort(reverse),
+            CategoricalOrdering::Lexical => self.cast::<Utf8Type>().unwrap().argsort(reverse),
+        }
+    }
+
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        match self.categorical_ordering {
+            CategoricalOrdering::Physical => self.deref().argsort_top_k(k, reverse),
+            CategoricalOrdering::Lexical => {
+                self.cast::<Utf8Type>().unwrap().argsort_top_k(k, reverse)
+            }
+        }
     }
 }
 
@@ -290,9 +454,15 @@ impl ChunkSort<BooleanType> for BooleanChunked {
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.chunk_id = sorted.chunk_id;
+        self.sorted = sorted.sorted;
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
         argsort!(self, reverse)
     }
+
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        argsort_top_k!(self, k, reverse)
+    }
 }
@@ -884,6 +884,7 @@ impl ChunkTakeEvery<CategoricalType> for CategoricalChunked {
             self.into_iter().step_by(n).collect()
         };
         ca.categorical_map = self.categorical_map.clone();
+        ca.categorical_ordering = self.categorical_ordering;
         ca.cast().unwrap()
     }
 }
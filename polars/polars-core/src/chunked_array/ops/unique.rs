@@ -1,6 +1,6 @@
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectType;
-use crate::frame::groupby::GroupTuples;
+use crate::frame::groupby::{GroupIdxIter, GroupTuples};
 use crate::prelude::*;
 use crate::utils::{floating_encode_f64, integer_decode_f64, NoNull};
 use crate::{chunked_array::float::IntegerDecode, frame::groupby::IntoGroupTuples};
@@ -19,12 +19,9 @@ pub(crate) fn is_unique_helper(
     duplicated_val: bool,
 ) -> BooleanChunked {
     debug_assert_ne!(unique_val, duplicated_val);
-    groups.sort_unstable_by_key(|t| t.0);
+    groups.sort();
 
-    let mut unique_idx_iter = groups
-        .into_iter()
-        .filter(|(_, g)| g.len() == 1)
-        .map(|(first, _)| first);
+    let mut unique_idx_iter = groups.iter().filter(|g| g.len() == 1).map(|g| g.first());
 
     let mut next_unique_idx = unique_idx_iter.next();
     (0..len)
@@ -133,11 +130,9 @@ macro_rules! impl_value_counts {
     ($self:expr) => {{
         let group_tuples = $self.group_tuples(true);
         let values =
-            unsafe { $self.take_unchecked(group_tuples.iter().map(|t| t.0 as usize).into()) };
-        let mut counts: NoNull<UInt32Chunked> = group_tuples
-            .into_iter()
-            .map(|(_, groups)| groups.len() as u32)
-            .collect();
+            unsafe { $self.take_unchecked(group_tuples.iter_first().map(|i| i as usize).into()) };
+        let mut counts: NoNull<UInt32Chunked> =
+            group_tuples.iter().map(|g| g.len() as u32).collect();
         counts.rename("counts");
         let cols = vec![values.into_series(), counts.into_inner().into_series()];
         let df = DataFrame::new_no_checks(cols);
@@ -204,11 +199,38 @@ impl ChunkUnique<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkUnique<BinaryType> for BinaryChunked {
+    fn unique(&self) -> Result<Self> {
+        let idx = self.arg_unique()?;
+        Ok(self.take((&idx).into()))
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        Ok(UInt32Chunked::new_from_aligned_vec(
+            self.name(),
+            arg_unique_ca!(self),
+        ))
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        Ok(is_unique(self))
+    }
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        Ok(is_duplicated(self))
+    }
+
+    fn value_counts(&self) -> Result<DataFrame> {
+        impl_value_counts!(self)
+    }
+}
+
 impl ChunkUnique<CategoricalType> for CategoricalChunked {
     fn unique(&self) -> Result<Self> {
         let set = fill_set(self.into_iter(), self.len());
         let mut ca = UInt32Chunked::new_from_opt_iter(self.name(), set.iter().copied());
         ca.categorical_map = self.categorical_map.clone();
+        ca.categorical_ordering = self.categorical_ordering;
         ca.cast()
     }
 
@@ -232,17 +254,14 @@ impl ChunkUnique<CategoricalType> for CategoricalChunked {
 }
 
 #[cfg(feature = "dtype-u8")]
-fn dummies_helper(mut groups: Vec<u32>, len: usize, name: &str) -> UInt8Chunked {
-    groups.sort_unstable();
-
-    // let mut group_member_iter = groups.into_iter();
+fn dummies_helper(groups: GroupIdxIter, len: usize, name: &str) -> UInt8Chunked {
     let mut av = AlignedVec::with_capacity_aligned(len);
     for _ in 0..len {
         av.push(0u8)
     }
 
     for idx in groups {
-        let elem = unsafe { av.inner.get_unchecked_mut(idx as usize) };
+        let elem = unsafe { av.inner.get_unchecked_mut(idx) };
         *elem = 1;
     }
 
@@ -250,17 +269,14 @@ fn dummies_helper(mut groups: Vec<u32>, len: usize, name: &str) -> UInt8Chunked
 }
 
 #[cfg(not(feature = "dtype-u8"))]
-fn dummies_helper(mut groups: Vec<u32>, len: usize, name: &str) -> Int64Chunked {
-    groups.sort_unstable();
-
-    // let mut group_member_iter = groups.into_iter();
+fn dummies_helper(groups: GroupIdxIter, len: usize, name: &str) -> Int64Chunked {
     let mut av = AlignedVec::with_capacity_aligned(len);
     for _ in 0..len {
         av.push(0i64)
     }
 
     for idx in groups {
-        let elem = unsafe { av.inner.get_unchecked_mut(idx as usize) };
+        let elem = unsafe { av.inner.get_unchecked_mut(idx) };
         *elem = 1;
     }
 
@@ -280,11 +296,11 @@ impl ToDummies<Utf8Type> for Utf8Chunked {
         let col_name = self.name();
 
         let columns = groups
-            .into_par_iter()
-            .map(|(first, groups)| {
-                let val = unsafe { self.get_unchecked(first as usize) };
+            .par_iter()
+            .map(|g| {
+                let val = unsafe { self.get_unchecked(g.first() as usize) };
                 let name = format!("{}_{}", col_name, val);
-                let ca = dummies_helper(groups, self.len(), &name);
+                let ca = dummies_helper(g.iter(), self.len(), &name);
                 ca.into_series()
             })
             .collect();
@@ -303,11 +319,11 @@ where
         let col_name = self.name();
 
         let columns = groups
-            .into_par_iter()
-            .map(|(first, groups)| {
-                let val = unsafe { self.get_unchecked(first as usize) };
+            .par_iter()
+            .map(|g| {
+                let val = unsafe { self.get_unchecked(g.first() as usize) };
                 let name = format!("{}_{}", col_name, val);
-                let ca = dummies_helper(groups, self.len(), &name);
+                let ca = dummies_helper(g.iter(), self.len(), &name);
                 ca.into_series()
             })
             .collect();
@@ -352,6 +368,10 @@ impl ChunkUnique<BooleanType> for BooleanChunked {
     fn is_duplicated(&self) -> Result<BooleanChunked> {
         Ok(is_duplicated(self))
     }
+
+    fn value_counts(&self) -> Result<DataFrame> {
+        impl_value_counts!(self)
+    }
 }
 
 fn float_unique<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
@@ -471,6 +491,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn value_counts() {
+        let ca = BooleanChunked::new_from_slice("a", &[true, false, true]);
+        let out = ca.value_counts().unwrap();
+        assert_eq!(
+            Vec::from(out.column("a").unwrap().bool().unwrap()),
+            &[Some(true), Some(false)]
+        );
+
+        let s = Series::new("a", &[1, 2, 1])
+            .cast_with_datatype(&DataType::Date32)
+            .unwrap();
+        let out = s.value_counts().unwrap();
+        assert_eq!(out.column("a").unwrap().dtype(), &DataType::Date32);
+        assert_eq!(
+            Vec::from(out.column("counts").unwrap().u32().unwrap()),
+            &[Some(2), Some(1)]
+        );
+    }
+
     #[test]
     fn arg_unique() {
         let ca = ChunkedArray::<Int32Type>::new_from_slice("a", &[1, 2, 1, 1, 3]);
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use num::{Bounded, NumCast, One, Zero};
+use num::{Bounded, NumCast, One, ToPrimitive, Zero};
 use std::ops::{Add, Div, Mul, Sub};
 
 /// a fold function to compute the sum. Returns a Null if there is a single null in the window
@@ -228,6 +228,148 @@ pub enum InitFold {
     Min,
 }
 
+/// The aggregation that is applied to the values in a rolling window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RollingAgg {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Var,
+    Std,
+    Median,
+    /// Quantile between 0.0 and 1.0
+    Quantile(f64),
+}
+
+/// Aggregate the (non null) values of a window. The values may be reordered.
+fn agg_window(values: &mut [f64], agg: RollingAgg) -> Option<f64> {
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+    let sum = || values.iter().sum::<f64>();
+    let var = |values: &[f64]| {
+        if n < 2 {
+            return None;
+        }
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let sq_diff = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+        Some(sq_diff / (n - 1) as f64)
+    };
+    let sort = |values: &mut [f64]| {
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    };
+    match agg {
+        RollingAgg::Sum => Some(sum()),
+        RollingAgg::Mean => Some(sum() / n as f64),
+        RollingAgg::Min => values.iter().copied().fold(None, |acc, v| match acc {
+            Some(acc) if acc <= v => Some(acc),
+            _ => Some(v),
+        }),
+        RollingAgg::Max => values.iter().copied().fold(None, |acc, v| match acc {
+            Some(acc) if acc >= v => Some(acc),
+            _ => Some(v),
+        }),
+        RollingAgg::Var => var(values),
+        RollingAgg::Std => var(values).map(|v| v.sqrt()),
+        RollingAgg::Median => {
+            sort(values);
+            if n % 2 == 0 {
+                Some((values[n / 2 - 1] + values[n / 2]) / 2.0)
+            } else {
+                Some(values[n / 2])
+            }
+        }
+        RollingAgg::Quantile(quantile) => {
+            sort(values);
+            let idx = std::cmp::min((n as f64 * quantile) as usize, n - 1);
+            Some(values[idx])
+        }
+    }
+}
+
+/// Apply a rolling aggregation on floating point values. The windows are given by the `bounds`
+/// iterator, that yields the `(start, end)` (exclusive) offsets of the window of every value.
+///
+/// The optional `weight` is aligned with the end of the window.
+pub(crate) fn rolling_apply_float<I>(
+    name: &str,
+    values: &[Option<f64>],
+    bounds: I,
+    weight: Option<&[f64]>,
+    ignore_null: bool,
+    min_periods: u32,
+    agg: RollingAgg,
+) -> Float64Chunked
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    let mut buf = Vec::new();
+    let mut ca: Float64Chunked = bounds
+        .map(|(start, end)| {
+            buf.clear();
+            let window = &values[start..end];
+            let weight = weight.map(|w| &w[w.len() - window.len()..]);
+            for (i, opt_v) in window.iter().enumerate() {
+                match opt_v {
+                    Some(v) => buf.push(weight.map_or(*v, |w| v * w[i])),
+                    None if !ignore_null => return None,
+                    None => {}
+                }
+            }
+            if (buf.len() as u32) < min_periods {
+                None
+            } else {
+                agg_window(&mut buf, agg)
+            }
+        })
+        .collect();
+    ca.rename(name);
+    ca
+}
+
+/// Parse a duration string like "3d" or "1h30m" to milliseconds.
+/// Supported units are: ms, s, m, h, d and w.
+pub(crate) fn parse_duration_ms(duration: &str) -> Result<i64> {
+    let err = || PolarsError::ValueError(format!("could not parse duration {}", duration).into());
+    let mut total = 0i64;
+    let mut chars = duration.trim().chars().peekable();
+    if chars.peek().is_none() {
+        return Err(err());
+    }
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+            number.push(*c);
+            chars.next();
+        }
+        let mut unit = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+            unit.push(*c);
+            chars.next();
+        }
+        let n = number.parse::<i64>().map_err(|_| err())?;
+        let multiplier = match unit.as_str() {
+            "ms" => 1,
+            "s" => 1000,
+            "m" => 60 * 1000,
+            "h" => 60 * 60 * 1000,
+            "d" => 24 * 60 * 60 * 1000,
+            "w" => 7 * 24 * 60 * 60 * 1000,
+            _ => return Err(err()),
+        };
+        total = n
+            .checked_mul(multiplier)
+            .and_then(|ms| total.checked_add(ms))
+            .ok_or_else(err)?;
+    }
+    if total <= 0 {
+        return Err(err());
+    }
+    Ok(total)
+}
+
 impl<T> ChunkWindow for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -326,6 +468,76 @@ where
             min_periods,
         ))
     }
+
+    fn rolling_var(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        self.rolling_float(
+            window_size,
+            weight,
+            ignore_null,
+            min_periods,
+            RollingAgg::Var,
+        )
+    }
+
+    fn rolling_std(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        self.rolling_float(
+            window_size,
+            weight,
+            ignore_null,
+            min_periods,
+            RollingAgg::Std,
+        )
+    }
+
+    fn rolling_median(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        self.rolling_float(
+            window_size,
+            weight,
+            ignore_null,
+            min_periods,
+            RollingAgg::Median,
+        )
+    }
+
+    fn rolling_quantile(
+        &self,
+        quantile: f64,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Float64Chunked> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsError::ValueError(
+                "quantile should be between 0.0 and 1.0".into(),
+            ));
+        }
+        self.rolling_float(
+            window_size,
+            weight,
+            ignore_null,
+            min_periods,
+            RollingAgg::Quantile(quantile),
+        )
+    }
 }
 
 impl<T> ChunkWindowCustom<T::Native> for ChunkedArray<T>
@@ -395,6 +607,45 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    fn rolling_float(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+        agg: RollingAgg,
+    ) -> Result<Float64Chunked> {
+        check_input(window_size, min_periods)?;
+        if let Some(weight) = weight {
+            if weight.len() != window_size as usize {
+                return Err(PolarsError::ValueError(
+                    "the length of `weight` should be equal to `window_size`".into(),
+                ));
+            }
+        }
+        let values = self
+            .into_iter()
+            .map(|opt_v| opt_v.and_then(|v| v.to_f64()))
+            .collect::<Vec<_>>();
+        let window_size = window_size as usize;
+        let bounds = (0..values.len()).map(|i| ((i + 1).saturating_sub(window_size), i + 1));
+        Ok(rolling_apply_float(
+            self.name(),
+            &values,
+            bounds,
+            weight,
+            ignore_null,
+            min_periods,
+            agg,
+        ))
+    }
+}
+
 impl ChunkWindow for ListChunked {}
 impl ChunkWindow for Utf8Chunked {}
 impl ChunkWindow for BooleanChunked {}
@@ -480,4 +731,71 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_rolling_var_std_median_quantile() {
+        let ca = Int32Chunked::new_from_opt_slice(
+            "foo",
+            &[Some(1), Some(2), Some(4), None, Some(7), Some(9)],
+        );
+
+        let a = ca.rolling_var(3, None, true, 2).unwrap();
+        assert_eq!(a.get(0), None);
+        assert_eq!(a.get(1), Some(0.5));
+        assert!((a.get(2).unwrap() - 7.0 / 3.0).abs() < 1e-9);
+        assert_eq!(
+            Vec::from(&a.slice(3, 3)),
+            &[Some(2.0), Some(4.5), Some(2.0)]
+        );
+        let a = ca.rolling_std(3, None, true, 2).unwrap();
+        assert_eq!(a.get(5), Some(2.0f64.sqrt()));
+
+        let a = ca.rolling_median(3, None, true, 1).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[
+                Some(1.0),
+                Some(1.5),
+                Some(2.0),
+                Some(3.0),
+                Some(5.5),
+                Some(8.0)
+            ]
+        );
+        // a null in the window leads to a null
+        let a = ca.rolling_median(3, None, false, 1).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[Some(1.0), Some(1.5), Some(2.0), None, None, None]
+        );
+
+        let a = ca.rolling_quantile(1.0, 2, None, true, 1).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(4.0),
+                Some(4.0),
+                Some(7.0),
+                Some(9.0)
+            ]
+        );
+        assert!(ca.rolling_quantile(1.5, 2, None, true, 1).is_err());
+        assert!(ca.rolling_median(2, Some(&[1.0]), true, 1).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use super::parse_duration_ms;
+        assert_eq!(parse_duration_ms("3d").unwrap(), 3 * 24 * 3600 * 1000);
+        assert_eq!(parse_duration_ms("1h30m").unwrap(), 90 * 60 * 1000);
+        assert_eq!(parse_duration_ms("10ms").unwrap(), 10);
+        assert!(parse_duration_ms("3x").is_err());
+        assert!(parse_duration_ms("").is_err());
+        assert!(parse_duration_ms("0d").is_err());
+        // overflows are an error
+        assert!(parse_duration_ms("9999999999999999w").is_err());
+        assert!(parse_duration_ms("9000000000000000000ms1ms1000000000000000000ms").is_err());
+    }
 }
@@ -0,0 +1,390 @@
+//! Struct arrays hold a number of named child `Series` of equal length.
+use crate::prelude::*;
+use arrow::array::{Array, ArrayData, ArrayRef, BooleanBufferBuilder, StructArray};
+use std::convert::TryFrom;
+
+/// A ChunkedArray-like container of nested records. Every field is stored as a separate `Series`.
+///
+/// A struct value is null where its validity is `false` or null, regardless of the values of the
+/// fields at that position.
+#[derive(Clone)]
+pub struct StructChunked {
+    field: Field,
+    fields: Vec<Series>,
+    // `None` if all struct values are valid.
+    validity: Option<BooleanChunked>,
+    // a single `StructArray` that shares the (rechunked) buffers of the fields.
+    chunks: Vec<ArrayRef>,
+    chunk_id: Vec<usize>,
+}
+
+fn struct_dtype(fields: &[Series]) -> DataType {
+    DataType::Struct(fields.iter().map(|s| s.field().clone()).collect())
+}
+
+impl StructChunked {
+    /// Create a new `StructChunked` from the given fields. All fields should have the same length
+    /// and a unique name.
+    pub fn new(name: &str, fields: &[Series]) -> Result<Self> {
+        if let Some(first) = fields.first() {
+            if fields.iter().any(|s| s.len() != first.len()) {
+                return Err(PolarsError::ShapeMisMatch(
+                    "all fields of a struct should have the same length".into(),
+                ));
+            }
+        }
+        for (i, s) in fields.iter().enumerate() {
+            if fields[..i].iter().any(|other| other.name() == s.name()) {
+                return Err(PolarsError::Duplicate(
+                    format!("field {} is defined more than once in the struct", s.name()).into(),
+                ));
+            }
+        }
+        Ok(Self::new_unchecked(name, fields, None))
+    }
+
+    /// Set the validity of the struct values; a struct value is null where `validity` is `false`
+    /// or null.
+    pub fn with_validity(self, validity: BooleanChunked) -> Result<Self> {
+        if validity.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the validity of a struct should have the same length as its fields".into(),
+            ));
+        }
+        Ok(Self::new_unchecked(
+            self.name(),
+            &self.fields,
+            Some(validity),
+        ))
+    }
+
+    /// Fields must have the same length and unique names and the validity must have the same
+    /// length as the fields.
+    fn new_unchecked(name: &str, fields: &[Series], validity: Option<BooleanChunked>) -> Self {
+        let fields: Vec<_> = fields.iter().map(|s| s.rechunk()).collect();
+        // an all valid mask is the same as no mask
+        let validity = validity.filter(|v| v.into_iter().any(|opt_v| opt_v != Some(true)));
+        let arrays: Vec<_> = fields
+            .iter()
+            .map(|s| {
+                (
+                    s.field().to_arrow(),
+                    s.chunks()
+                        .get(0)
+                        .cloned()
+                        .unwrap_or_else(|| arrow::array::new_empty_array(&s.dtype().to_arrow())),
+                )
+            })
+            .collect();
+        let len = fields.first().map(|s| s.len()).unwrap_or(0);
+        let chunks: Vec<ArrayRef> = match (&validity, arrays.is_empty()) {
+            (_, true) => vec![],
+            (None, false) => vec![Arc::new(StructArray::from(arrays))],
+            (Some(validity), false) => {
+                let mut bitmap = BooleanBufferBuilder::new(len);
+                validity
+                    .into_iter()
+                    .for_each(|opt_v| bitmap.append(opt_v == Some(true)));
+                vec![Arc::new(StructArray::from((arrays, bitmap.finish())))]
+            }
+        };
+
+        StructChunked {
+            field: Field::new(name, struct_dtype(&fields)),
+            fields,
+            validity,
+            chunks,
+            chunk_id: vec![len],
+        }
+    }
+
+    /// Create a `StructChunked` from Arrow `StructArray`s.
+    pub fn new_from_chunks(name: &str, chunks: Vec<ArrayRef>) -> Result<Self> {
+        let arrow_fields = match chunks.get(0).map(|arr| arr.data_type()) {
+            Some(ArrowDataType::Struct(fields)) => fields.clone(),
+            Some(dt) => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("expected a struct array, got {:?}", dt).into(),
+                ))
+            }
+            None => {
+                return Err(PolarsError::NoData(
+                    "cannot create a struct without chunks".into(),
+                ))
+            }
+        };
+
+        let fields = arrow_fields
+            .iter()
+            .enumerate()
+            .map(|(i, fld)| {
+                let field_chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr
+                            .as_any()
+                            .downcast_ref::<StructArray>()
+                            .expect("all chunks have the same type");
+                        arr.column(i).clone()
+                    })
+                    .collect();
+                Series::try_from((fld.name().as_str(), field_chunks))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let ca = Self::new(name, &fields)?;
+
+        if chunks.iter().all(|arr| arr.null_count() == 0) {
+            return Ok(ca);
+        }
+        let validity: BooleanChunked = chunks
+            .iter()
+            .flat_map(|arr| (0..arr.len()).map(move |i| arr.is_valid(i)))
+            .collect();
+        ca.with_validity(validity)
+    }
+
+    /// Get the fields of this struct.
+    pub fn fields(&self) -> &[Series] {
+        &self.fields
+    }
+
+    /// Get a field of this struct by name.
+    pub fn field(&self, name: &str) -> Result<Series> {
+        self.fields
+            .iter()
+            .find(|s| s.name() == name)
+            .cloned()
+            .ok_or_else(|| PolarsError::NotFound(name.into()))
+    }
+
+    /// Get the validity of the struct values, `None` if all values are valid.
+    pub fn validity(&self) -> Option<&BooleanChunked> {
+        self.validity.as_ref()
+    }
+
+    /// Apply a function to every field and collect the result in a new struct. The function
+    /// should return `Series` of equal length.
+    ///
+    /// The function is also applied to the validity, so it should only move values around, e.g.
+    /// by slicing, filtering or gathering them.
+    pub fn apply_fields<F>(&self, f: F) -> Self
+    where
+        F: Fn(&Series) -> Series,
+    {
+        self.try_apply_fields(|s| Ok(f(s))).unwrap()
+    }
+
+    /// Fallible version of [apply_fields](StructChunked::apply_fields).
+    pub fn try_apply_fields<F>(&self, f: F) -> Result<Self>
+    where
+        F: Fn(&Series) -> Result<Series>,
+    {
+        let fields = self.fields.iter().map(&f).collect::<Result<Vec<_>>>()?;
+        let validity = match &self.validity {
+            Some(validity) => Some(f(&validity.clone().into_series())?.bool()?.clone()),
+            None => None,
+        };
+        Ok(Self::new_unchecked(self.name(), &fields, validity))
+    }
+
+    /// Cast the fields to the given fields. The validity is kept.
+    pub(crate) fn cast_fields(&self, fields: &[Field]) -> Result<Self> {
+        let casted = self
+            .fields
+            .iter()
+            .zip(fields)
+            .map(|(s, fld)| {
+                let mut s = s.cast_with_datatype(fld.data_type())?;
+                s.rename(fld.name());
+                Ok(s)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let ca = Self::new(self.name(), &casted)?;
+        match &self.validity {
+            Some(validity) => ca.with_validity(validity.clone()),
+            None => Ok(ca),
+        }
+    }
+
+    /// Number of null struct values.
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|arr| arr.null_count()).sum()
+    }
+
+    /// Get a mask of the valid struct values.
+    pub fn is_not_null(&self) -> BooleanChunked {
+        match &self.validity {
+            Some(validity) => {
+                let mut ca: BooleanChunked = validity
+                    .into_iter()
+                    .map(|opt_v| opt_v == Some(true))
+                    .collect();
+                ca.rename(self.name());
+                ca
+            }
+            None => BooleanChunked::full(self.name(), true, self.len()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.field = Field::new(name, self.field.data_type().clone())
+    }
+
+    pub fn ref_field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.field.data_type()
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.first().map(|s| s.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.chunks
+    }
+
+    pub fn chunk_id(&self) -> &Vec<usize> {
+        &self.chunk_id
+    }
+
+    pub fn array_data(&self) -> Vec<&ArrayData> {
+        self.chunks.iter().map(|arr| arr.data()).collect()
+    }
+
+    /// Append the fields of another struct with the same fields.
+    pub fn append(&mut self, other: &StructChunked) -> Result<()> {
+        if self.dtype() != other.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!("cannot append {:?} to {:?}", other.dtype(), self.dtype()).into(),
+            ));
+        }
+        let mut fields = self.fields.clone();
+        for (l, r) in fields.iter_mut().zip(other.fields.iter()) {
+            l.append(r)?;
+        }
+        let validity = match (&self.validity, &other.validity) {
+            (None, None) => None,
+            _ => {
+                let mut validity = self.is_not_null();
+                validity.append(&other.is_not_null());
+                Some(validity)
+            }
+        };
+        *self = Self::new_unchecked(self.name(), &fields, validity);
+        Ok(())
+    }
+
+    /// Get the values of all fields at `index`, or `AnyValue::Null` if the struct value is null.
+    pub fn get_any_value(&self, index: usize) -> AnyValue {
+        if let Some(validity) = &self.validity {
+            if validity.get(index) != Some(true) {
+                return AnyValue::Null;
+            }
+        }
+        let values = self.fields.iter().map(|s| s.get(index)).collect();
+        match self.dtype() {
+            DataType::Struct(flds) => AnyValue::Struct(values, flds),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl DataFrame {
+    /// Replace the struct columns by their fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // column "point" has the fields "x" and "y"
+    ///     // the output has the columns ["x", "y"] where "point" used to be
+    ///     df.unnest(&["point"])
+    /// }
+    /// ```
+    pub fn unnest<'a, J, S: crate::frame::select::Selection<'a, J>>(
+        &self,
+        columns: S,
+    ) -> Result<DataFrame> {
+        let to_unnest = self.select_series(columns)?;
+        let mut new_cols = Vec::with_capacity(self.width());
+        for s in self.get_columns() {
+            if to_unnest.iter().any(|u| u.name() == s.name()) {
+                new_cols.extend_from_slice(s.struct_()?.fields());
+            } else {
+                new_cols.push(s.clone());
+            }
+        }
+        DataFrame::new(new_cols)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_struct_field_and_unnest() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["x", "y", "z"]);
+        let ca = StructChunked::new("s", &[a.clone(), b])?;
+        assert_eq!(ca.len(), 3);
+        assert!(ca.field("a")?.series_equal(&a));
+        assert!(ca.field("c").is_err());
+
+        let s = ca.into_series();
+        let out = s.slice(1, 2);
+        assert_eq!(out.struct_()?.field("b")?.utf8()?.get(0), Some("y"));
+
+        let df = DataFrame::new(vec![Series::new("idx", &[0, 1, 2]), s])?;
+        let df = df.unnest(&["s"])?;
+        assert_eq!(df.get_column_names(), &["idx", "a", "b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_validity() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let validity = BooleanChunked::new_from_slice("", &[true, false, true]);
+        let ca = StructChunked::new("s", &[a])?.with_validity(validity)?;
+        assert_eq!(ca.null_count(), 1);
+        assert_eq!(ca.get_any_value(1), AnyValue::Null);
+        assert_ne!(ca.get_any_value(0), AnyValue::Null);
+
+        let s = ca.into_series();
+        let out = s.slice(1, 2);
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(Vec::from(&out.is_null()), &[Some(true), Some(false)]);
+
+        let out = s.take(&UInt32Chunked::new_from_opt_slice("", &[Some(2), None]));
+        assert_eq!(Vec::from(&out.is_null()), &[Some(false), Some(true)]);
+
+        // the validity survives a round-trip through arrow
+        let out = Series::try_from(("s", s.chunks().clone()))?;
+        assert_eq!(
+            Vec::from(&out.is_null()),
+            &[Some(false), Some(true), Some(false)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_length_mismatch() {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &[1, 2]);
+        assert!(StructChunked::new("s", &[a.clone(), b]).is_err());
+        assert!(StructChunked::new("s", &[a.clone(), a]).is_err());
+    }
+}
@@ -21,6 +21,8 @@ impl<T> Default for ChunkedArray<T> {
             chunk_id: Default::default(),
             phantom: PhantomData,
             categorical_map: None,
+            categorical_ordering: Default::default(),
+            sorted: Default::default(),
         }
     }
 }
@@ -130,6 +132,23 @@ where
     }
 }
 
+// FromIterator for BinaryChunked.
+
+#[cfg(feature = "dtype-binary")]
+impl<Ptr> FromIterator<Option<Ptr>> for BinaryChunked
+where
+    Ptr: AsRef<[u8]>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Ptr>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = BinaryChunkedBuilder::new("", get_iter_capacity(&iter) * 8);
+        for opt_v in iter {
+            builder.append_option(opt_v)
+        }
+        builder.finish()
+    }
+}
+
 impl<Ptr> FromIterator<Ptr> for ListChunked
 where
     Ptr: Borrow<Series>,
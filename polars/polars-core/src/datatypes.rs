@@ -6,6 +6,7 @@
 //! [See the AnyType variants](enum.AnyType.html#variants) for the data types that
 //! are currently supported.
 //!
+use crate::chunked_array::decimal::{cmp_decimal, MAX_DECIMAL_PRECISION};
 use crate::prelude::*;
 pub use arrow::datatypes::DataType as ArrowDataType;
 pub use arrow::datatypes::{
@@ -25,6 +26,11 @@ pub struct ListType {}
 
 pub struct CategoricalType {}
 
+pub struct DecimalType {}
+
+#[cfg(feature = "dtype-binary")]
+pub struct BinaryType {}
+
 pub trait PolarsDataType: Send + Sync {
     fn get_dtype() -> DataType;
 }
@@ -90,6 +96,20 @@ impl PolarsDataType for CategoricalType {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl PolarsDataType for BinaryType {
+    fn get_dtype() -> DataType {
+        DataType::Binary
+    }
+}
+
+impl PolarsDataType for DecimalType {
+    fn get_dtype() -> DataType {
+        // the precision and scale of an array are stored in its field.
+        DataType::Decimal(MAX_DECIMAL_PRECISION, 0)
+    }
+}
+
 #[cfg(feature = "object")]
 #[cfg_attr(docsrs, doc(cfg(feature = "object")))]
 pub struct ObjectType<T>(T);
@@ -130,6 +150,9 @@ pub type DurationNanosecondChunked = ChunkedArray<DurationNanosecondType>;
 pub type DurationMillisecondChunked = ChunkedArray<DurationMillisecondType>;
 pub type Time64NanosecondChunked = ChunkedArray<Time64NanosecondType>;
 pub type CategoricalChunked = ChunkedArray<CategoricalType>;
+pub type DecimalChunked = ChunkedArray<DecimalType>;
+#[cfg(feature = "dtype-binary")]
+pub type BinaryChunked = ChunkedArray<BinaryType>;
 
 pub trait PolarsPrimitiveType: ArrowPrimitiveType + Send + Sync + PolarsDataType {}
 // impl PolarsPrimitiveType for BooleanType {}
@@ -228,6 +251,14 @@ pub enum AnyValue<'a> {
     #[cfg(feature = "object")]
     /// Use as_any to get a dyn Any
     Object(&'a str),
+    /// A fixed precision decimal given as the unscaled value and the scale.
+    Decimal(i128, usize),
+    #[cfg(feature = "dtype-struct")]
+    /// The values of the fields of a struct.
+    Struct(Vec<AnyValue<'a>>, &'a [Field]),
+    #[cfg(feature = "dtype-binary")]
+    /// Raw bytes.
+    Binary(&'a [u8]),
 }
 
 impl Display for DataType {
@@ -255,6 +286,11 @@ impl Display for DataType {
             #[cfg(feature = "object")]
             DataType::Object => "object",
             DataType::Categorical => "cat",
+            DataType::Decimal(precision, scale) => {
+                return write!(f, "decimal({}, {})", precision, scale)
+            }
+            DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            DataType::Binary => "binary",
             _ => panic!("{:?} not implemented", self),
         };
         f.write_str(s)
@@ -282,6 +318,13 @@ impl<'a> PartialEq for AnyValue<'a> {
             (Time64(l, _), Time64(r, _)) => l == r,
             (Duration(l, _), Duration(r, _)) => l == r,
             (Boolean(l), Boolean(r)) => l == r,
+            (Decimal(l, l_scale), Decimal(r, r_scale)) => {
+                cmp_decimal(*l, *l_scale, *r, *r_scale) == std::cmp::Ordering::Equal
+            }
+            #[cfg(feature = "dtype-struct")]
+            (Struct(l, _), Struct(r, _)) => l == r,
+            #[cfg(feature = "dtype-binary")]
+            (Binary(l), Binary(r)) => l == r,
             (List(_), List(_)) => panic!("eq between list series not supported"),
             #[cfg(feature = "object")]
             (Object(_), Object(_)) => panic!("eq between object not supported"),
@@ -319,6 +362,13 @@ pub enum DataType {
     Object,
     Null,
     Categorical,
+    /// A fixed precision decimal with the total number of digits and the number of digits
+    /// after the decimal point, stored as `i128`.
+    Decimal(usize, usize),
+    /// Nested records with the given fields.
+    Struct(Vec<Field>),
+    /// Variable length byte sequences.
+    Binary,
 }
 
 impl DataType {
@@ -348,6 +398,9 @@ impl DataType {
             #[cfg(feature = "object")]
             Object => unimplemented!(),
             Categorical => ArrowDataType::UInt16,
+            Decimal(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
+            Struct(fields) => ArrowDataType::Struct(fields.iter().map(|f| f.to_arrow()).collect()),
+            Binary => ArrowDataType::LargeBinary,
         }
     }
 }
@@ -359,7 +412,7 @@ impl PartialEq<ArrowDataType> for DataType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Field {
     name: String,
     data_type: DataType,
@@ -528,6 +581,11 @@ impl From<&ArrowDataType> for DataType {
                 DataType::Duration(TimeUnit::Millisecond)
             }
             ArrowDataType::Utf8 => DataType::Utf8,
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
+            ArrowDataType::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|f| f.into()).collect())
+            }
+            ArrowDataType::Binary | ArrowDataType::LargeBinary => DataType::Binary,
             dt => panic!("Arrow datatype {:?} not supported by Polars", dt),
         }
     }
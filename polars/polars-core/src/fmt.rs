@@ -1,3 +1,4 @@
+use crate::chunked_array::decimal::format_decimal;
 use crate::prelude::*;
 
 #[cfg(feature = "temporal")]
@@ -312,6 +313,33 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => format_array!(
+                limit,
+                f,
+                self.decimal().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => format_array!(
+                limit,
+                f,
+                self.struct_().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => format_array!(
+                limit,
+                f,
+                self.binary().unwrap(),
+                "binary",
+                self.name(),
+                "Series"
+            ),
             _ => unimplemented!(),
         }
     }
@@ -537,6 +565,27 @@ impl Display for AnyValue<'_> {
             AnyValue::List(s) => write!(f, "{:?}", s.fmt_list()),
             #[cfg(feature = "object")]
             AnyValue::Object(_) => write!(f, "object"),
+            AnyValue::Decimal(v, scale) => write!(f, "{}", format_decimal(*v, *scale)),
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(values, fields) => {
+                write!(f, "{{")?;
+                for (i, (v, fld)) in values.iter().zip(fields.iter()).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", fld.name(), v)?;
+                }
+                write!(f, "}}")
+            }
+            #[cfg(feature = "dtype-binary")]
+            AnyValue::Binary(v) => {
+                let escaped: String = v
+                    .iter()
+                    .flat_map(|b| std::ascii::escape_default(*b))
+                    .map(char::from)
+                    .collect();
+                write!(f, "b\"{}\"", escaped)
+            }
             _ => unimplemented!(),
         }
     }
@@ -0,0 +1,330 @@
+use crate::prelude::*;
+use ahash::RandomState;
+use std::collections::HashMap;
+
+/// Name of the column that holds the statistic names in the output of
+/// [describe](DataFrame::describe).
+pub const DESCRIBE_COLUMN: &str = "describe";
+
+#[derive(Clone, Copy, PartialEq)]
+enum DescribeKind {
+    Numeric,
+    Text,
+    Date,
+}
+
+fn describe_kind(dtype: &DataType) -> Option<DescribeKind> {
+    use DataType::*;
+    match dtype {
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+            Some(DescribeKind::Numeric)
+        }
+        Utf8 | Categorical => Some(DescribeKind::Text),
+        Date32 | Date64 => Some(DescribeKind::Date),
+        _ => None,
+    }
+}
+
+fn percentile_label(percentile: f64) -> String {
+    format!("{}%", (percentile * 1e4).round() / 100.0)
+}
+
+/// Determine the schema of the output of [describe](DataFrame::describe).
+///
+/// Numeric columns are described as `Float64`, utf8 and categorical columns as `Utf8` and
+/// date columns keep their data type. Columns of any other type are left out.
+pub fn describe_schema(schema: &Schema) -> Schema {
+    let mut fields = Vec::with_capacity(schema.len() + 1);
+    fields.push(Field::new(DESCRIBE_COLUMN, DataType::Utf8));
+    fields.extend(schema.fields().iter().filter_map(|fld| {
+        describe_kind(fld.data_type()).map(|kind| {
+            let dtype = match kind {
+                DescribeKind::Numeric => DataType::Float64,
+                DescribeKind::Text => DataType::Utf8,
+                DescribeKind::Date => fld.data_type().clone(),
+            };
+            Field::new(fld.name(), dtype)
+        })
+    }));
+    Schema::new(fields)
+}
+
+/// The statistics of a single column, keyed by the row label.
+struct Described<T> {
+    values: Vec<(String, T)>,
+}
+
+impl<T: Clone> Described<T> {
+    fn new() -> Self {
+        Described { values: vec![] }
+    }
+
+    fn push<S: Into<String>>(&mut self, label: S, value: T) {
+        self.values.push((label.into(), value))
+    }
+
+    fn to_column(&self, labels: &[String]) -> Vec<Option<T>> {
+        labels
+            .iter()
+            .map(|label| {
+                self.values
+                    .iter()
+                    .find(|(l, _)| l == label)
+                    .map(|(_, v)| v.clone())
+            })
+            .collect()
+    }
+}
+
+fn describe_numeric(s: &Series, percentiles: &[f64]) -> Result<Described<Option<f64>>> {
+    let s = s.cast::<Float64Type>()?;
+    let ca = s.f64()?;
+    let mut out = Described::new();
+    out.push("count", Some((ca.len() - ca.null_count()) as f64));
+    out.push("null_count", Some(ca.null_count() as f64));
+    out.push("mean", ca.mean());
+    out.push("std", ca.std());
+    out.push("min", ca.min());
+    for &p in percentiles {
+        out.push(percentile_label(p), ca.quantile(p)?);
+    }
+    out.push("max", ca.max());
+    Ok(out)
+}
+
+fn describe_text(s: &Series) -> Result<Described<Option<String>>> {
+    let s = s.cast::<Utf8Type>()?;
+    let ca = s.utf8()?;
+
+    // value -> (count, first occurrence), so that ties are resolved by the first value seen
+    let mut counts: HashMap<&str, (usize, usize), RandomState> = HashMap::default();
+    ca.into_iter()
+        .enumerate()
+        .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx, v)))
+        .for_each(|(idx, v)| counts.entry(v).or_insert((0, idx)).0 += 1);
+    let top = counts
+        .iter()
+        .max_by(|(_, (count_a, idx_a)), (_, (count_b, idx_b))| {
+            count_a.cmp(count_b).then(idx_b.cmp(idx_a))
+        })
+        .map(|(v, (count, _))| (v.to_string(), *count));
+
+    let mut out = Described::new();
+    out.push("count", Some((ca.len() - ca.null_count()).to_string()));
+    out.push("null_count", Some(ca.null_count().to_string()));
+    out.push("n_unique", Some(counts.len().to_string()));
+    out.push("top", top.as_ref().map(|(v, _)| v.clone()));
+    out.push("freq", top.map(|(_, count)| count.to_string()));
+    Ok(out)
+}
+
+fn describe_date<T>(ca: &ChunkedArray<T>) -> Described<Option<T::Native>>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: ChunkAgg<T::Native>,
+{
+    let mut out = Described::new();
+    out.push("min", ca.min());
+    out.push("max", ca.max());
+    out
+}
+
+fn date_column<T>(name: &str, described: Described<Option<T::Native>>, labels: &[String]) -> Series
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: NewChunkedArray<T, T::Native> + IntoSeries,
+{
+    let values: Vec<_> = described
+        .to_column(labels)
+        .into_iter()
+        .map(|opt_v| opt_v.flatten())
+        .collect();
+    ChunkedArray::<T>::new_from_opt_slice(name, &values).into_series()
+}
+
+impl DataFrame {
+    /// Summary statistics of the columns of the DataFrame.
+    ///
+    /// The first column of the output is named `"describe"` and holds the name of the statistic
+    /// of every row. Then follows one column per described column:
+    ///
+    /// * numeric columns get the `count`, `null_count`, `mean`, `std`, `min`, the given
+    ///   `percentiles` and `max` as `Float64`. The rows of the percentiles are named like `"25%"`.
+    /// * utf8 and categorical columns get the `count`, `null_count`, the number of unique
+    ///   values `n_unique`, the most frequent value `top` and its frequency `freq` as `Utf8`.
+    /// * date columns get their `min` and `max`.
+    ///
+    /// Only the rows needed by the described columns are in the output and statistics that don't
+    /// apply to a column are null. Columns of other data types are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3, 4],
+    ///              "b" => &["x", "y", "x", "z"]).unwrap();
+    ///
+    /// let summary = df.describe(&[0.25, 0.5, 0.75]).unwrap();
+    /// assert_eq!(summary.get_column_names(), &["describe", "a", "b"]);
+    /// ```
+    ///
+    /// Outputs:
+    ///
+    /// ```text
+    /// +--------------+------+------+
+    /// | describe     | a    | b    |
+    /// | ---          | ---  | ---  |
+    /// | str          | f64  | str  |
+    /// +==============+======+======+
+    /// | "count"      | 4    | "4"  |
+    /// +--------------+------+------+
+    /// | "null_count" | 0    | "0"  |
+    /// +--------------+------+------+
+    /// | "mean"       | 2.5  | null |
+    /// +--------------+------+------+
+    /// | ...          | ...  | ...  |
+    /// +--------------+------+------+
+    /// | "top"        | null | "x"  |
+    /// +--------------+------+------+
+    /// | "freq"       | null | "2"  |
+    /// +--------------+------+------+
+    /// ```
+    pub fn describe(&self, percentiles: &[f64]) -> Result<DataFrame> {
+        let kinds: Vec<_> = self
+            .columns
+            .iter()
+            .map(|s| describe_kind(s.dtype()))
+            .collect();
+        let has_kind = |kind| kinds.iter().any(|k| *k == Some(kind));
+        let numeric = has_kind(DescribeKind::Numeric);
+        let text = has_kind(DescribeKind::Text);
+        let date = has_kind(DescribeKind::Date);
+
+        let mut labels: Vec<String> = vec![];
+        if numeric || text {
+            labels.push("count".into());
+            labels.push("null_count".into());
+        }
+        if numeric {
+            labels.push("mean".into());
+            labels.push("std".into());
+        }
+        if numeric || date {
+            labels.push("min".into());
+        }
+        if numeric {
+            labels.extend(percentiles.iter().map(|&p| percentile_label(p)));
+        }
+        if numeric || date {
+            labels.push("max".into());
+        }
+        if text {
+            labels.push("n_unique".into());
+            labels.push("top".into());
+            labels.push("freq".into());
+        }
+
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(Series::new(DESCRIBE_COLUMN, &labels));
+        for (s, kind) in self.columns.iter().zip(kinds) {
+            let column = match kind {
+                Some(DescribeKind::Numeric) => {
+                    let values: Vec<_> = describe_numeric(s, percentiles)?
+                        .to_column(&labels)
+                        .into_iter()
+                        .map(|opt_v| opt_v.flatten())
+                        .collect();
+                    Float64Chunked::new_from_opt_slice(s.name(), &values).into_series()
+                }
+                Some(DescribeKind::Text) => {
+                    let values: Vec<_> = describe_text(s)?
+                        .to_column(&labels)
+                        .into_iter()
+                        .map(|opt_v| opt_v.flatten())
+                        .collect();
+                    Utf8Chunked::new_from_opt_slice(s.name(), &values).into_series()
+                }
+                Some(DescribeKind::Date) => match s.dtype() {
+                    DataType::Date32 => {
+                        date_column::<Date32Type>(s.name(), describe_date(s.date32()?), &labels)
+                    }
+                    _ => date_column::<Date64Type>(s.name(), describe_date(s.date64()?), &labels),
+                },
+                None => continue,
+            };
+            columns.push(column);
+        }
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_describe() {
+        let df = df!("num" => &[Some(1.0), Some(2.0), None, Some(4.0), Some(3.0)],
+            "text" => &[Some("b"), Some("a"), Some("b"), None, Some("a")],
+            "flag" => &[true, false, true, true, false]
+        )
+        .unwrap();
+
+        let summary = df.describe(&[0.5]).unwrap();
+        assert_eq!(summary.get_column_names(), &["describe", "num", "text"]);
+        assert_eq!(
+            Vec::from(summary.column("describe").unwrap().utf8().unwrap()),
+            &[
+                Some("count"),
+                Some("null_count"),
+                Some("mean"),
+                Some("std"),
+                Some("min"),
+                Some("50%"),
+                Some("max"),
+                Some("n_unique"),
+                Some("top"),
+                Some("freq")
+            ]
+        );
+        let num = Vec::from(summary.column("num").unwrap().f64().unwrap());
+        assert_eq!(&num[..3], &[Some(4.0), Some(1.0), Some(2.5)]);
+        assert_eq!(
+            &num[4..],
+            &[Some(1.0), Some(3.0), Some(4.0), None, None, None]
+        );
+        // ties are resolved by the first value seen
+        assert_eq!(
+            Vec::from(summary.column("text").unwrap().utf8().unwrap()),
+            &[
+                Some("4"),
+                Some("1"),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("2"),
+                Some("b"),
+                Some("2")
+            ]
+        );
+        assert_eq!(summary.schema(), describe_schema(&df.schema()));
+    }
+
+    #[test]
+    fn test_describe_date() {
+        let dates = Date32Chunked::new_from_opt_slice("date", &[Some(10), None, Some(3)]);
+        let df = DataFrame::new(vec![dates.into_series()]).unwrap();
+
+        let summary = df.describe(&[0.25, 0.75]).unwrap();
+        assert_eq!(
+            Vec::from(summary.column("describe").unwrap().utf8().unwrap()),
+            &[Some("min"), Some("max")]
+        );
+        assert_eq!(
+            Vec::from(summary.column("date").unwrap().date32().unwrap()),
+            &[Some(3), Some(10)]
+        );
+    }
+}
@@ -1,13 +1,139 @@
-use crate::chunked_array::ops::explode::offsets_to_indexes;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::utils::get_supertype;
+#[cfg(feature = "strings")]
+use regex::Regex;
 use std::collections::VecDeque;
 
-fn get_exploded(series: &Series) -> Result<(Series, &[i64])> {
+/// Arguments for [melt_with](DataFrame::melt_with).
+///
+/// The value columns are the union of `value_vars`, the columns of a type in `value_dtypes`
+/// and the columns whose name matches `value_regex`. If none of these are given, all columns
+/// that are not in `id_vars` are melted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeltArgs {
+    /// Columns that are repeated for every melted column.
+    pub id_vars: Vec<String>,
+    /// Columns that are melted.
+    pub value_vars: Vec<String>,
+    /// Also melt the columns of these data types.
+    pub value_dtypes: Vec<DataType>,
+    /// Also melt the columns whose name matches this regular expression.
+    /// Requires the `strings` feature.
+    pub value_regex: Option<String>,
+    /// Name of the column with the names of the melted columns. Defaults to `"variable"`.
+    pub variable_name: Option<String>,
+    /// Name of the column with the melted values. Defaults to `"value"`.
+    pub value_name: Option<String>,
+}
+
+impl MeltArgs {
+    pub fn new(id_vars: Vec<String>, value_vars: Vec<String>) -> Self {
+        MeltArgs {
+            id_vars,
+            value_vars,
+            ..Default::default()
+        }
+    }
+
+    pub fn variable_name(&self) -> &str {
+        self.variable_name.as_deref().unwrap_or("variable")
+    }
+
+    pub fn value_name(&self) -> &str {
+        self.value_name.as_deref().unwrap_or("value")
+    }
+
+    /// Resolve the dtype and regex selections against `schema`. The returned arguments only
+    /// use `value_vars`, ordered as given followed by the other selected columns in schema order.
+    pub fn resolve(&self, schema: &Schema) -> Result<MeltArgs> {
+        #[cfg(feature = "strings")]
+        let regex = self
+            .value_regex
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| PolarsError::InvalidOperation(format!("{}", e).into()))?;
+        #[cfg(not(feature = "strings"))]
+        if self.value_regex.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "selecting melt columns by regex requires the 'strings' feature".into(),
+            ));
+        }
+        let select_rest = self.value_vars.is_empty()
+            && self.value_dtypes.is_empty()
+            && self.value_regex.is_none();
+
+        let mut value_vars = self.value_vars.clone();
+        for field in schema.fields() {
+            let name = field.name();
+            if self.id_vars.contains(name) || value_vars.contains(name) {
+                continue;
+            }
+            #[cfg(feature = "strings")]
+            let matches_regex = regex.as_ref().map_or(false, |re| re.is_match(name));
+            #[cfg(not(feature = "strings"))]
+            let matches_regex = false;
+
+            if select_rest || matches_regex || self.value_dtypes.contains(field.data_type()) {
+                value_vars.push(name.clone())
+            }
+        }
+        for name in self.id_vars.iter().chain(&value_vars) {
+            schema.field_with_name(name)?;
+        }
+
+        Ok(MeltArgs {
+            id_vars: self.id_vars.clone(),
+            value_vars,
+            value_dtypes: vec![],
+            value_regex: None,
+            variable_name: self.variable_name.clone(),
+            value_name: self.value_name.clone(),
+        })
+    }
+
+    /// The supertype of the value columns, i.e. the type of the value column in the output.
+    /// Expects resolved arguments.
+    pub fn value_dtype(&self, schema: &Schema) -> Result<DataType> {
+        let mut dtypes = self
+            .value_vars
+            .iter()
+            .map(|name| schema.field_with_name(name).map(|f| f.data_type()));
+        let first = dtypes
+            .next()
+            .ok_or_else(|| PolarsError::NoData("No data in melt operation".into()))??
+            .clone();
+        dtypes.try_fold(first, |acc, dtype| get_supertype(&acc, dtype?))
+    }
+}
+
+/// Explode a column and return the exploded values and the number of values of every row.
+/// Null values and empty lists have no values.
+fn get_exploded(series: &Series) -> Result<(Series, Vec<usize>)> {
     match series.dtype() {
-        DataType::List(_) => series.list().unwrap().explode_and_offsets(),
-        DataType::Utf8 => series.utf8().unwrap().explode_and_offsets(),
-        _ => Err(PolarsError::InvalidOperation("".into())),
+        DataType::List(_) => {
+            let (exploded, offsets) = series.list().unwrap().explode_and_offsets()?;
+            // the offsets don't include the end of the last list
+            let lengths = offsets
+                .iter()
+                .zip(offsets.iter().skip(1).chain(&[exploded.len() as i64]))
+                .map(|(start, end)| (end - start) as usize)
+                .collect();
+            Ok((exploded, lengths))
+        }
+        DataType::Utf8 => {
+            let ca = series.utf8().unwrap();
+            let (exploded, _) = ca.explode_and_offsets()?;
+            let lengths = ca
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| v.chars().count()).unwrap_or(0))
+                .collect();
+            Ok((exploded, lengths))
+        }
+        dt => Err(PolarsError::InvalidOperation(
+            format!("cannot explode dtype: {:?}", dt).into(),
+        )),
     }
 }
 
@@ -70,8 +196,16 @@ impl DataFrame {
     ///  | 2   | 3   | 1   |
     ///  +-----+-----+-----+
     /// ```
+    ///
+    /// Several columns are exploded in lockstep, so the lists in every row must have the same
+    /// length. Null values and empty lists are kept as a single row with a null value.
     pub fn explode<'a, J, S: Selection<'a, J>>(&self, columns: S) -> Result<DataFrame> {
-        let columns = self.select_series(columns)?;
+        let mut columns = self.select_series(columns)?;
+        if columns.is_empty() {
+            return Ok(self.clone());
+        }
+        // insert the exploded columns back in their original order
+        columns.sort_by_key(|s| self.name_to_idx(s.name()).unwrap());
 
         // first remove all the exploded columns
         let mut df = self.clone();
@@ -79,27 +213,67 @@ impl DataFrame {
             df = df.drop(s.name())?;
         }
 
-        for (i, s) in columns.iter().enumerate() {
-            if let Ok((exploded, offsets)) = get_exploded(s) {
-                let col_idx = self.name_to_idx(s.name())?;
-
-                // expand all the other columns based the exploded first column
-                if i == 0 {
-                    let row_idx = offsets_to_indexes(offsets, exploded.len());
-                    df = unsafe { df.take_iter_unchecked(row_idx.into_iter()) };
+        let mut exploded_columns = Vec::with_capacity(columns.len());
+        let mut lengths: Option<Vec<usize>> = None;
+        for s in &columns {
+            let (exploded, row_lengths) = get_exploded(s)?;
+            match &lengths {
+                Some(first_lengths) => {
+                    if let Some(row) = first_lengths
+                        .iter()
+                        .zip(&row_lengths)
+                        .position(|(a, b)| a != b)
+                    {
+                        return Err(PolarsError::ShapeMisMatch(
+                            format!(
+                                "cannot explode columns '{}' and '{}' in lockstep: row {} has {} and {} values",
+                                columns[0].name(),
+                                s.name(),
+                                row,
+                                first_lengths[row],
+                                row_lengths[row]
+                            )
+                            .into(),
+                        ));
+                    }
                 }
-                if exploded.len() == df.height() {
-                    df.columns.insert(col_idx, exploded);
+                None => lengths = Some(row_lengths),
+            }
+            exploded_columns.push(exploded);
+        }
+        let lengths = lengths.unwrap();
+
+        // a row without values becomes a single null row
+        let has_empty = lengths.iter().any(|&len| len == 0);
+        let row_idx = lengths
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &len)| std::iter::repeat(i).take(std::cmp::max(len, 1)));
+        df = unsafe { df.take_iter_unchecked(row_idx) };
+
+        let value_idx = if has_empty {
+            let mut value_idx = Vec::with_capacity(df.height());
+            let mut start = 0;
+            for &len in &lengths {
+                if len == 0 {
+                    value_idx.push(None)
                 } else {
-                    return Err(PolarsError::ShapeMisMatch(
-                        format!("The exploded columns don't have the same length. Length DataFrame: {}. Length exploded column {}: {}", df.height(), exploded.name(), exploded.len()).into(),
-                    ));
+                    value_idx.extend((start..start + len).map(Some))
                 }
-            } else {
-                return Err(PolarsError::InvalidOperation(
-                    format!("cannot explode dtype: {:?}", s.dtype()).into(),
-                ));
+                start += len;
             }
+            Some(value_idx)
+        } else {
+            None
+        };
+
+        for (s, exploded) in columns.iter().zip(exploded_columns) {
+            let exploded = match &value_idx {
+                Some(idx) => exploded.take_opt_iter(&mut idx.iter().copied()),
+                None => exploded,
+            };
+            let col_idx = self.name_to_idx(s.name())?;
+            df.columns.insert(col_idx, exploded);
         }
         Ok(df)
     }
@@ -166,19 +340,52 @@ impl DataFrame {
         id_vars: SelId,
         value_vars: SelValue,
     ) -> Result<Self> {
-        let ids = self.select(id_vars)?;
-        let value_vars = value_vars.to_selection_vec();
+        let to_strings =
+            |sel: Vec<&str>| -> Vec<String> { sel.into_iter().map(|s| s.to_string()).collect() };
+        let args = MeltArgs::new(
+            to_strings(id_vars.to_selection_vec()),
+            to_strings(value_vars.to_selection_vec()),
+        );
+        self.melt_with(&args)
+    }
+
+    /// Unpivot a `DataFrame` from wide to long format with the given [MeltArgs].
+    ///
+    /// Value columns of different numeric types are cast to their supertype. The variable
+    /// column is categorical.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    ///
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // melt all columns except "A"
+    ///     let args = MeltArgs {
+    ///         id_vars: vec!["A".into()],
+    ///         variable_name: Some("column".into()),
+    ///         ..Default::default()
+    ///     };
+    ///     df.melt_with(&args)
+    /// }
+    /// ```
+    pub fn melt_with(&self, args: &MeltArgs) -> Result<Self> {
+        let schema = self.schema();
+        let args = args.resolve(&schema)?;
+        let value_dtype = args.value_dtype(&schema)?;
+        let ids = self.select(&args.id_vars)?;
         let len = self.height();
 
-        let mut dataframe_chunks = VecDeque::with_capacity(value_vars.len());
+        let mut dataframe_chunks = VecDeque::with_capacity(args.value_vars.len());
 
-        for value_column_name in value_vars {
-            let variable_col = Utf8Chunked::full("variable", value_column_name, len).into_series();
-            let mut value_col = self.column(value_column_name)?.clone();
-            value_col.rename("value");
+        for value_column_name in &args.value_vars {
+            let mut value_col = self
+                .column(value_column_name)?
+                .cast_with_datatype(&value_dtype)?;
+            value_col.rename(args.value_name());
 
             let mut df_chunk = ids.clone();
-            df_chunk.hstack_mut(&[variable_col, value_col])?;
+            df_chunk.hstack_mut(&[value_col])?;
             dataframe_chunks.push_back(df_chunk)
         }
 
@@ -189,6 +396,18 @@ impl DataFrame {
         while let Some(df) = dataframe_chunks.pop_front() {
             main_df.vstack_mut(&df)?;
         }
+
+        // the variable column is created at once, so that it has a single categorical mapping
+        let mut variable_col: Utf8Chunked = args
+            .value_vars
+            .iter()
+            .flat_map(|name| std::iter::repeat(name.as_str()).take(len))
+            .collect();
+        variable_col.rename(args.variable_name());
+        let variable_col = variable_col
+            .into_series()
+            .cast_with_datatype(&DataType::Categorical)?;
+        main_df.insert_at_idx(ids.width(), variable_col)?;
         Ok(main_df)
     }
 }
@@ -232,6 +451,64 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_explode_lockstep() {
+        let a: ListChunked = vec![
+            Some(Series::new("", &[1, 2])),
+            None,
+            Some(Series::new("", &[] as &[i32])),
+            Some(Series::new("", &[3])),
+        ]
+        .into_iter()
+        .collect();
+        let b: ListChunked = vec![
+            Some(Series::new("", &[10, 20])),
+            None,
+            Some(Series::new("", &[] as &[i32])),
+            Some(Series::new("", &[30])),
+        ]
+        .into_iter()
+        .collect();
+        let mut a = a.into_series();
+        a.rename("a");
+        let mut b = b.into_series();
+        b.rename("b");
+        let k = Series::new("k", &[1, 2, 3, 4]);
+        let df = DataFrame::new(vec![k, a, b]).unwrap();
+
+        let exploded = df.explode(&["a", "b"]).unwrap();
+        assert_eq!(exploded.get_column_names(), &["k", "a", "b"]);
+        assert_eq!(
+            Vec::from(exploded.column("k").unwrap().i32().unwrap()),
+            &[Some(1), Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("a").unwrap().i32().unwrap()),
+            &[Some(1), Some(2), None, None, Some(3)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("b").unwrap().i32().unwrap()),
+            &[Some(10), Some(20), None, None, Some(30)]
+        );
+
+        // the lists of a row must have the same length
+        let c: ListChunked = vec![
+            Series::new("", &[1]),
+            Series::new("", &[2]),
+            Series::new("", &[3]),
+            Series::new("", &[4]),
+        ]
+        .into_iter()
+        .collect();
+        let mut c = c.into_series();
+        c.rename("c");
+        let df = df.hstack(&[c]).unwrap();
+        assert!(matches!(
+            df.explode(&["a", "c"]),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
+    }
+
     #[test]
     fn test_melt() {
         let df = df!("A" => &["a", "b", "a"],
@@ -247,4 +524,53 @@ mod test {
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
         )
     }
+
+    #[test]
+    fn test_melt_with() {
+        let df = df!("A" => &["a", "b", "a"],
+         "B" => &[1, 3, 5],
+         "C" => &[10.5, 11.5, 12.5],
+         "D" => &[2, 4, 6]
+        )
+        .unwrap();
+
+        // all other columns, named output columns and a supertype cast
+        let args = MeltArgs {
+            id_vars: vec!["A".into()],
+            variable_name: Some("column".into()),
+            value_name: Some("v".into()),
+            ..Default::default()
+        };
+        let melted = df.melt_with(&args).unwrap();
+        assert_eq!(melted.get_column_names(), &["A", "column", "v"]);
+        assert_eq!(melted.shape(), (9, 3));
+        assert_eq!(
+            melted.column("column").unwrap().dtype(),
+            &DataType::Categorical
+        );
+        assert_eq!(
+            Vec::from(melted.column("v").unwrap().f64().unwrap()),
+            &[
+                Some(1.0),
+                Some(3.0),
+                Some(5.0),
+                Some(10.5),
+                Some(11.5),
+                Some(12.5),
+                Some(2.0),
+                Some(4.0),
+                Some(6.0)
+            ]
+        );
+
+        // select the value columns by dtype
+        let args = MeltArgs {
+            id_vars: vec!["A".into()],
+            value_dtypes: vec![DataType::Int32],
+            ..Default::default()
+        };
+        let melted = df.melt_with(&args).unwrap();
+        assert_eq!(melted.shape(), (6, 3));
+        assert_eq!(melted.column("value").unwrap().dtype(), &DataType::Int32);
+    }
 }
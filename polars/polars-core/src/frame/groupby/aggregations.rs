@@ -1,6 +1,6 @@
 use crate::POOL;
 use ahash::RandomState;
-use num::{Bounded, Num, NumCast, ToPrimitive, Zero};
+use num::{Bounded, CheckedMul, Num, NumCast, One, ToPrimitive, Zero};
 use polars_arrow::prelude::*;
 use rayon::prelude::*;
 use std::collections::HashSet;
@@ -9,33 +9,49 @@ use std::hash::Hash;
 use crate::chunked_array::kernels::take_agg::{
     take_agg_no_null_primitive_iter_unchecked, take_agg_primitive_iter_unchecked,
 };
+use crate::frame::groupby::{GroupTuples, GroupsIndicator};
 use crate::prelude::*;
 use crate::utils::NoNull;
 
 pub(crate) trait NumericAggSync {
-    fn agg_mean(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_mean(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
-    fn agg_min(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_min(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
-    fn agg_max(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_max(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
-    fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_sum(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
-    fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_std(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
-    fn agg_var(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_var(&self, _groups: &GroupTuples) -> Option<Series> {
+        None
+    }
+    fn agg_arg_min(&self, _groups: &GroupTuples) -> Option<Series> {
+        None
+    }
+    fn agg_arg_max(&self, _groups: &GroupTuples) -> Option<Series> {
+        None
+    }
+    fn agg_product(&self, _groups: &GroupTuples) -> Option<Series> {
+        None
+    }
+    fn agg_any(&self, _groups: &GroupTuples) -> Option<Series> {
+        None
+    }
+    fn agg_all(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
 }
 
-fn agg_helper<T, F>(groups: &[(u32, Vec<u32>)], f: F) -> Option<Series>
+fn agg_helper<T, F>(groups: &GroupTuples, f: F) -> Option<Series>
 where
-    F: Fn(&(u32, Vec<u32>)) -> Option<T::Native> + Send + Sync,
+    F: Fn(GroupsIndicator) -> Option<T::Native> + Send + Sync,
     T: PolarsNumericType,
     ChunkedArray<T>: IntoSeries,
 {
@@ -43,16 +59,92 @@ where
     Some(ca.into_series())
 }
 
+/// Multiply the values of every group. The product of a group that overflows is null.
+fn agg_checked_product<T>(ca: &ChunkedArray<T>, groups: &GroupTuples) -> Option<Series>
+where
+    T: PolarsNumericType,
+    T::Native: CheckedMul + One,
+    ChunkedArray<T>: IntoSeries,
+{
+    agg_helper::<T, _>(groups, |g| {
+        let mut product = None;
+        for v in g.iter().filter_map(|i| ca.get(i)) {
+            product = Some(product.unwrap_or_else(T::Native::one).checked_mul(&v)?);
+        }
+        product
+    })
+}
+
+/// Get the position within every group of the value that is `better` than all others, e.g. the
+/// minimum for `a < b`. On ties the first position wins and null values are ignored.
+fn agg_arg_by<T, F>(ca: &ChunkedArray<T>, groups: &GroupTuples, better: F) -> Series
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> bool + Send + Sync,
+{
+    let out: UInt32Chunked = POOL.install(|| {
+        groups
+            .par_iter()
+            .map(|g| {
+                let mut best: Option<(u32, T::Native)> = None;
+                for (i, idx) in g.iter().enumerate() {
+                    if let Some(v) = ca.get(idx) {
+                        match best {
+                            Some((_, b)) if !better(v, b) => {}
+                            _ => best = Some((i as u32, v)),
+                        }
+                    }
+                }
+                best.map(|(i, _)| i)
+            })
+            .collect()
+    });
+    out.into_series()
+}
+
+/// Get the values of a group. A group of consecutive rows is sliced instead of gathered.
+///
+/// # Safety
+///
+/// The indexes of the group must be in bounds.
+unsafe fn take_group<T>(ca: &ChunkedArray<T>, g: GroupsIndicator) -> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTake,
+{
+    match g {
+        GroupsIndicator::Slice([first, len]) => ca.slice(first as i64, len as usize),
+        GroupsIndicator::Idx(_) => ca.take_unchecked(g.iter().into()),
+    }
+}
+
 impl NumericAggSync for BooleanChunked {
-    fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_min(&self, groups: &GroupTuples) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_min(groups)
     }
-    fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_max(&self, groups: &GroupTuples) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_max(groups)
     }
-    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_sum(&self, groups: &GroupTuples) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_sum(groups)
     }
+    fn agg_any(&self, groups: &GroupTuples) -> Option<Series> {
+        let ca: BooleanChunked = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| g.iter().any(|i| self.get(i) == Some(true)))
+                .collect()
+        });
+        Some(ca.into_series())
+    }
+    fn agg_all(&self, groups: &GroupTuples) -> Option<Series> {
+        let ca: BooleanChunked = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| g.iter().all(|i| self.get(i) != Some(false)))
+                .collect()
+        });
+        Some(ca.into_series())
+    }
 }
 impl NumericAggSync for Utf8Chunked {}
 impl NumericAggSync for ListChunked {}
@@ -64,54 +156,60 @@ impl<T> NumericAggSync for ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
     T::Native: std::ops::Add<Output = T::Native> + Num + NumCast + Bounded,
-    ChunkedArray<T>: IntoSeries,
+    ChunkedArray<T>: IntoSeries + ChunkCast,
 {
-    fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<Float64Type, _>(groups, |(first, idx)| {
-            if idx.len() == 1 {
-                self.get(*first as usize).map(|sum| sum.to_f64().unwrap())
+    fn agg_mean(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<Float64Type, _>(groups, |g| {
+            // a filtered group can be empty
+            if g.is_empty() {
+                None
+            } else if g.len() == 1 {
+                self.get(g.first() as usize)
+                    .map(|sum| sum.to_f64().unwrap())
             } else {
                 match (self.null_count(), self.chunks.len()) {
                     (0, 1) => unsafe {
                         take_agg_no_null_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| a + b,
                             T::Native::zero(),
                         )
                     }
                     .to_f64()
-                    .map(|sum| sum / idx.len() as f64),
+                    .map(|sum| sum / g.len() as f64),
                     (_, 1) => unsafe {
                         take_agg_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| a + b,
                             T::Native::zero(),
                         )
                     }
-                    .map(|sum| sum.to_f64().map(|sum| sum / idx.len() as f64).unwrap()),
+                    .map(|sum| sum.to_f64().map(|sum| sum / g.len() as f64).unwrap()),
                     _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+                        let take = unsafe { take_group(self, g) };
                         let opt_sum: Option<T::Native> = take.sum();
-                        opt_sum.map(|sum| sum.to_f64().unwrap() / idx.len() as f64)
+                        opt_sum.map(|sum| sum.to_f64().unwrap() / g.len() as f64)
                     }
                 }
             }
         })
     }
 
-    fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(first, idx)| {
-            if idx.len() == 1 {
-                self.get(*first as usize)
+    fn agg_min(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            // a filtered group can be empty
+            if g.is_empty() {
+                None
+            } else if g.len() == 1 {
+                self.get(g.first() as usize)
             } else {
                 match (self.null_count(), self.chunks.len()) {
                     (0, 1) => Some(unsafe {
                         take_agg_no_null_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| if a < b { a } else { b },
                             T::Native::max_value(),
                         )
@@ -119,14 +217,13 @@ where
                     (_, 1) => unsafe {
                         take_agg_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| if a < b { a } else { b },
                             T::Native::max_value(),
                         )
                     },
                     _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+                        let take = unsafe { take_group(self, g) };
                         take.min()
                     }
                 }
@@ -134,16 +231,19 @@ where
         })
     }
 
-    fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(first, idx)| {
-            if idx.len() == 1 {
-                self.get(*first as usize)
+    fn agg_max(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            // a filtered group can be empty
+            if g.is_empty() {
+                None
+            } else if g.len() == 1 {
+                self.get(g.first() as usize)
             } else {
                 match (self.null_count(), self.chunks.len()) {
                     (0, 1) => Some(unsafe {
                         take_agg_no_null_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| if a > b { a } else { b },
                             T::Native::min_value(),
                         )
@@ -151,14 +251,13 @@ where
                     (_, 1) => unsafe {
                         take_agg_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| if a > b { a } else { b },
                             T::Native::min_value(),
                         )
                     },
                     _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+                        let take = unsafe { take_group(self, g) };
                         take.max()
                     }
                 }
@@ -166,16 +265,16 @@ where
         })
     }
 
-    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(first, idx)| {
-            if idx.len() == 1 {
-                self.get(*first as usize)
+    fn agg_sum(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            if g.len() == 1 {
+                self.get(g.first() as usize)
             } else {
                 match (self.null_count(), self.chunks.len()) {
                     (0, 1) => Some(unsafe {
                         take_agg_no_null_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| a + b,
                             T::Native::zero(),
                         )
@@ -183,23 +282,22 @@ where
                     (_, 1) => unsafe {
                         take_agg_primitive_iter_unchecked(
                             self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
+                            g.iter(),
                             |a, b| a + b,
                             T::Native::zero(),
                         )
                     },
                     _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+                        let take = unsafe { take_group(self, g) };
                         take.sum()
                     }
                 }
             }
         })
     }
-    fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(_first, idx)| {
-            let take = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+    fn agg_var(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            let take = unsafe { take_group(self, g) };
             take.into_series()
                 .var_as_series()
                 .unpack::<T>()
@@ -207,9 +305,9 @@ where
                 .get(0)
         })
     }
-    fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(_first, idx)| {
-            let take = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+    fn agg_std(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            let take = unsafe { take_group(self, g) };
             take.into_series()
                 .std_as_series()
                 .unpack::<T>()
@@ -217,20 +315,103 @@ where
                 .get(0)
         })
     }
+    fn agg_arg_min(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(agg_arg_by(self, groups, |a, b| a < b))
+    }
+    fn agg_arg_max(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(agg_arg_by(self, groups, |a, b| a > b))
+    }
+    fn agg_product(&self, groups: &GroupTuples) -> Option<Series> {
+        match self.dtype() {
+            DataType::Float32 | DataType::Float64 => agg_helper::<T, _>(groups, |g| {
+                g.iter()
+                    .filter_map(|i| self.get(i))
+                    .fold(None, |acc, v| Some(acc.unwrap_or_else(T::Native::one) * v))
+            }),
+            // integers are multiplied as u64 or i64 to make overflows less likely
+            #[cfg(feature = "dtype-u64")]
+            DataType::UInt64 => agg_checked_product(&self.cast::<UInt64Type>().ok()?, groups),
+            _ => agg_checked_product(&self.cast::<Int64Type>().ok()?, groups),
+        }
+    }
+}
+
+impl Series {
+    /// Get the most frequent value of every group. On ties the value that occurs first in the
+    /// group is taken and null values are ignored. Returns `None` for data types that cannot be
+    /// grouped.
+    pub fn agg_mode(&self, groups: &GroupTuples) -> Option<Series> {
+        match self.dtype() {
+            DataType::List(_) | DataType::Decimal(_, _) | DataType::Struct(_) => return None,
+            #[cfg(feature = "object")]
+            DataType::Object => return None,
+            _ => {}
+        }
+        let idx: UInt32Chunked = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| {
+                    let group = unsafe { take_group_series(self, g) };
+                    let nulls = if group.null_count() > 0 {
+                        Some(group.is_null())
+                    } else {
+                        None
+                    };
+                    group
+                        .group_tuples(false)
+                        .iter()
+                        .filter(|values| {
+                            nulls.as_ref().map_or(true, |nulls| {
+                                nulls.get(values.first() as usize) != Some(true)
+                            })
+                        })
+                        .max_by(|a, b| a.len().cmp(&b.len()).then(b.first().cmp(&a.first())))
+                        .map(|values| g.get(values.first() as usize))
+                })
+                .collect()
+        });
+        Some(self.take(&idx))
+    }
+
+    /// Count the null values in every group.
+    pub fn agg_n_nulls(&self, groups: &GroupTuples) -> UInt32Chunked {
+        if self.null_count() == 0 {
+            return groups
+                .iter()
+                .map(|_| 0u32)
+                .collect::<NoNull<UInt32Chunked>>()
+                .into_inner();
+        }
+        let nulls = self.is_null();
+        POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| g.iter().filter(|&i| nulls.get(i) == Some(true)).count() as u32)
+                .collect::<NoNull<UInt32Chunked>>()
+                .into_inner()
+        })
+    }
 }
 
 pub(crate) trait AggFirst {
-    fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series;
+    fn agg_first(&self, _groups: &GroupTuples) -> Series;
 }
 
 macro_rules! impl_agg_first {
     ($self:ident, $groups:ident, $ca_type:ty) => {{
         let mut ca = $groups
             .iter()
-            .map(|(first, _idx)| $self.get(*first as usize))
+            .map(|g| {
+                if g.is_empty() {
+                    None
+                } else {
+                    $self.get(g.first() as usize)
+                }
+            })
             .collect::<$ca_type>();
 
         ca.categorical_map = $self.categorical_map.clone();
+        ca.categorical_ordering = $self.categorical_ordering;
         ca.into_series()
     }};
 }
@@ -240,31 +421,31 @@ where
     T: PolarsPrimitiveType + Send,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
         impl_agg_first!(self, groups, ChunkedArray<T>)
     }
 }
 
 impl AggFirst for BooleanChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
         impl_agg_first!(self, groups, BooleanChunked)
     }
 }
 
 impl AggFirst for Utf8Chunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
         impl_agg_first!(self, groups, Utf8Chunked)
     }
 }
 
 impl AggFirst for ListChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
         impl_agg_first!(self, groups, ListChunked)
     }
 }
 
 impl AggFirst for CategoricalChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
         let out = self
             .cast::<UInt32Type>()
             .unwrap()
@@ -279,23 +460,30 @@ impl AggFirst for CategoricalChunked {
 
 #[cfg(feature = "object")]
 impl<T> AggFirst for ObjectChunked<T> {
-    fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, _groups: &GroupTuples) -> Series {
         todo!()
     }
 }
 
 pub(crate) trait AggLast {
-    fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series;
+    fn agg_last(&self, _groups: &GroupTuples) -> Series;
 }
 
 macro_rules! impl_agg_last {
     ($self:ident, $groups:ident, $ca_type:ty) => {{
         let mut ca = $groups
             .iter()
-            .map(|(_first, idx)| $self.get(idx[idx.len() - 1] as usize))
+            .map(|g| {
+                if g.is_empty() {
+                    None
+                } else {
+                    $self.get(g.last() as usize)
+                }
+            })
             .collect::<$ca_type>();
 
         ca.categorical_map = $self.categorical_map.clone();
+        ca.categorical_ordering = $self.categorical_ordering;
         ca.into_series()
     }};
 }
@@ -305,25 +493,25 @@ where
     T: PolarsPrimitiveType + Send,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
         impl_agg_last!(self, groups, ChunkedArray<T>)
     }
 }
 
 impl AggLast for BooleanChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
         impl_agg_last!(self, groups, BooleanChunked)
     }
 }
 
 impl AggLast for Utf8Chunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
         impl_agg_last!(self, groups, Utf8Chunked)
     }
 }
 
 impl AggLast for CategoricalChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
         self.cast::<UInt32Type>()
             .unwrap()
             .agg_last(groups)
@@ -333,20 +521,20 @@ impl AggLast for CategoricalChunked {
 }
 
 impl AggLast for ListChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
         impl_agg_last!(self, groups, ListChunked)
     }
 }
 
 #[cfg(feature = "object")]
 impl<T> AggLast for ObjectChunked<T> {
-    fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, _groups: &GroupTuples) -> Series {
         todo!()
     }
 }
 
 pub(crate) trait AggNUnique {
-    fn agg_n_unique(&self, _groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, _groups: &GroupTuples) -> Option<UInt32Chunked> {
         None
     }
 }
@@ -354,19 +542,19 @@ pub(crate) trait AggNUnique {
 macro_rules! impl_agg_n_unique {
     ($self:ident, $groups:ident, $ca_type:ty) => {{
         $groups
-            .into_par_iter()
-            .map(|(_first, idx)| {
+            .par_iter()
+            .map(|g| {
                 if $self.null_count() == 0 {
                     let mut set = HashSet::with_hasher(RandomState::new());
-                    for i in idx {
-                        let v = unsafe { $self.get_unchecked(*i as usize) };
+                    for i in g.iter() {
+                        let v = unsafe { $self.get_unchecked(i) };
                         set.insert(v);
                     }
                     set.len() as u32
                 } else {
                     let mut set = HashSet::with_hasher(RandomState::new());
-                    for i in idx {
-                        let opt_v = $self.get(*i as usize);
+                    for i in g.iter() {
+                        let opt_v = $self.get(i);
                         set.insert(opt_v);
                     }
                     set.len() as u32
@@ -382,7 +570,7 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: Hash + Eq,
 {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
@@ -392,12 +580,13 @@ impl AggNUnique for Float32Chunked {}
 impl AggNUnique for Float64Chunked {}
 impl AggNUnique for ListChunked {}
 impl AggNUnique for CategoricalChunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
         self.cast::<UInt32Type>()
             .unwrap()
             .agg_n_unique(groups)
             .map(|mut ca| {
                 ca.categorical_map = self.categorical_map.clone();
+                ca.categorical_ordering = self.categorical_ordering;
                 ca
             })
     }
@@ -407,19 +596,19 @@ impl<T> AggNUnique for ObjectChunked<T> {}
 
 // TODO: could be faster as it can only be null, true, or false
 impl AggNUnique for BooleanChunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
 
 impl AggNUnique for Utf8Chunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
 
 pub(crate) trait AggList {
-    fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_list(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
 }
@@ -428,7 +617,7 @@ where
     T: PolarsDataType,
     ChunkedArray<T>: IntoSeries + ChunkCast,
 {
-    fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_list(&self, groups: &GroupTuples) -> Option<Series> {
         let s = match self.dtype() {
             DataType::Categorical => self.cast::<Utf8Type>().unwrap().into_series(),
             _ => self.clone().into_series(),
@@ -436,17 +625,15 @@ where
 
         // TODO! use collect, can be faster
         // needed capacity for the list
-        let values_cap = groups.iter().fold(0, |acc, g| acc + g.1.len());
+        let values_cap = groups.n_rows();
 
         macro_rules! impl_gb {
             ($type:ty, $agg_col:expr) => {{
                 let values_builder = PrimitiveArrayBuilder::<$type>::new(values_cap);
                 let mut builder =
                     ListPrimitiveChunkedBuilder::new("", values_builder, groups.len());
-                for (_first, idx) in groups {
-                    let s = unsafe {
-                        $agg_col.take_iter_unchecked(&mut idx.into_iter().map(|i| *i as usize))
-                    };
+                for g in groups.iter() {
+                    let s = unsafe { take_group_series(&$agg_col, g) };
                     builder.append_opt_series(Some(&s))
                 }
                 builder.finish().into_series()
@@ -457,10 +644,8 @@ where
             ($agg_col:expr) => {{
                 let values_builder = LargeStringBuilder::with_capacity(values_cap * 5, values_cap);
                 let mut builder = ListUtf8ChunkedBuilder::new("", values_builder, groups.len());
-                for (_first, idx) in groups {
-                    let s = unsafe {
-                        $agg_col.take_iter_unchecked(&mut idx.into_iter().map(|i| *i as usize))
-                    };
+                for g in groups.iter() {
+                    let s = unsafe { take_group_series(&$agg_col, g) };
                     builder.append_series(&s)
                 }
                 builder.finish().into_series()
@@ -471,10 +656,8 @@ where
             ($agg_col:expr) => {{
                 let values_builder = BooleanArrayBuilder::new(values_cap);
                 let mut builder = ListBooleanChunkedBuilder::new("", values_builder, groups.len());
-                for (_first, idx) in groups {
-                    let s = unsafe {
-                        $agg_col.take_iter_unchecked(&mut idx.into_iter().map(|i| *i as usize))
-                    };
+                for g in groups.iter() {
+                    let s = unsafe { take_group_series(&$agg_col, g) };
                     builder.append_series(&s)
                 }
                 builder.finish().into_series()
@@ -491,12 +674,24 @@ where
     }
 }
 
+/// Get the values of a group as a Series. A group of consecutive rows is sliced instead of gathered.
+///
+/// # Safety
+///
+/// The indexes of the group must be in bounds.
+unsafe fn take_group_series(s: &Series, g: GroupsIndicator) -> Series {
+    match g {
+        GroupsIndicator::Slice([first, len]) => s.slice(first as i64, len as usize),
+        GroupsIndicator::Idx(_) => s.take_iter_unchecked(&mut g.iter()),
+    }
+}
+
 pub(crate) trait AggQuantile {
-    fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+    fn agg_quantile(&self, _groups: &GroupTuples, _quantile: f64) -> Option<Series> {
         None
     }
 
-    fn agg_median(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_median(&self, _groups: &GroupTuples) -> Option<Series> {
         None
     }
 }
@@ -507,9 +702,9 @@ where
     T::Native: PartialOrd + Num + NumCast + Zero,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(_first, idx)| {
-            let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+    fn agg_quantile(&self, groups: &GroupTuples, quantile: f64) -> Option<Series> {
+        agg_helper::<T, _>(groups, |g| {
+            let group_vals = unsafe { take_group(self, g) };
             let sorted_idx_ca = group_vals.argsort(false);
             let sorted_idx = sorted_idx_ca.downcast_iter().next().unwrap().values();
             let quant_idx = (quantile * (sorted_idx.len() - 1) as f64) as usize;
@@ -518,9 +713,9 @@ where
         })
     }
 
-    fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-        agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
-            let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+    fn agg_median(&self, groups: &GroupTuples) -> Option<Series> {
+        agg_helper::<Float64Type, _>(groups, |g| {
+            let group_vals = unsafe { take_group(self, g) };
             group_vals.median()
         })
     }
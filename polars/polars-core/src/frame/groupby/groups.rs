@@ -0,0 +1,488 @@
+use crate::prelude::*;
+use crate::utils::NoNull;
+use crate::POOL;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Row indexes of the groups stored in flat buffers.
+///
+/// The indexes of group `i` are `all[offsets[i]..offsets[i + 1]]` and `first[i]` is
+/// the first index of that group. No allocation is needed per group.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GroupsIdx {
+    first: Vec<u32>,
+    offsets: Vec<u32>,
+    all: Vec<u32>,
+}
+
+impl GroupsIdx {
+    /// Create the groups from the group id of every row.
+    ///
+    /// * `first` - first row index of every group, the position in this vec is the group id.
+    /// * `rows` - row indexes. If `None` the rows are `0..group_ids.len()`.
+    /// * `group_ids` - group id of every row.
+    ///
+    /// The rows must be in ascending order, so that the indexes within a group are sorted.
+    pub(crate) fn from_group_ids(first: Vec<u32>, rows: Option<&[u32]>, group_ids: &[u32]) -> Self {
+        let n_groups = first.len();
+        let mut offsets = vec![0u32; n_groups + 1];
+        for &gid in group_ids {
+            offsets[gid as usize + 1] += 1;
+        }
+        for i in 0..n_groups {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut cursor = offsets[..n_groups].to_vec();
+        let mut all = vec![0u32; group_ids.len()];
+        for (i, &gid) in group_ids.iter().enumerate() {
+            let row = match rows {
+                Some(rows) => rows[i],
+                None => i as u32,
+            };
+            let pos = &mut cursor[gid as usize];
+            all[*pos as usize] = row;
+            *pos += 1;
+        }
+
+        GroupsIdx {
+            first,
+            offsets,
+            all,
+        }
+    }
+
+    /// Create groups with the given number of rows in parallel.
+    ///
+    /// `fill(i, idx)` writes the row indexes of group `i` in `idx`, a slice of the flat buffer
+    /// of length `lengths[i]`, and returns the first row index of the group. The first row
+    /// index is returned separately, so that empty groups can keep a first row index.
+    pub fn from_lengths<F>(lengths: &[u32], fill: F) -> Self
+    where
+        F: Fn(usize, &mut [u32]) -> u32 + Send + Sync,
+    {
+        let mut offsets = Vec::with_capacity(lengths.len() + 1);
+        offsets.push(0u32);
+        let mut n_rows = 0;
+        for &len in lengths {
+            n_rows += len;
+            offsets.push(n_rows);
+        }
+
+        let mut all = vec![0u32; n_rows as usize];
+        let mut group_slices = Vec::with_capacity(lengths.len());
+        let mut rest = all.as_mut_slice();
+        for &len in lengths {
+            let (group, tail) = std::mem::take(&mut rest).split_at_mut(len as usize);
+            group_slices.push(group);
+            rest = tail;
+        }
+        let first = POOL.install(|| {
+            group_slices
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, idx)| fill(i, idx))
+                .collect()
+        });
+
+        GroupsIdx {
+            first,
+            offsets,
+            all,
+        }
+    }
+
+    /// Concatenate groups that were created on disjoint partitions of the keys.
+    pub(crate) fn concat(parts: Vec<GroupsIdx>) -> Self {
+        let n_groups = parts.iter().map(|p| p.first.len()).sum::<usize>();
+        let n_rows = parts.iter().map(|p| p.all.len()).sum::<usize>();
+        let mut first = Vec::with_capacity(n_groups);
+        let mut offsets = Vec::with_capacity(n_groups + 1);
+        let mut all = Vec::with_capacity(n_rows);
+        offsets.push(0);
+
+        for part in parts {
+            let offset = all.len() as u32;
+            first.extend_from_slice(&part.first);
+            offsets.extend(part.offsets[1..].iter().map(|o| o + offset));
+            all.extend_from_slice(&part.all);
+        }
+        GroupsIdx {
+            first,
+            offsets,
+            all,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.first.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+
+    /// First row index of every group.
+    pub fn first(&self) -> &[u32] {
+        &self.first
+    }
+
+    /// Row indexes of group `i`.
+    pub fn get(&self, i: usize) -> &[u32] {
+        let start = self.offsets[i] as usize;
+        let end = self.offsets[i + 1] as usize;
+        &self.all[start..end]
+    }
+
+    /// Reorder the groups by their first row index.
+    fn sort(&mut self) {
+        let mut order = (0..self.len() as u32).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&i| self.first[i as usize]);
+
+        let mut first = Vec::with_capacity(self.len());
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        let mut all = Vec::with_capacity(self.all.len());
+        offsets.push(0);
+        for i in order {
+            let i = i as usize;
+            first.push(self.first[i]);
+            all.extend_from_slice(self.get(i));
+            offsets.push(all.len() as u32);
+        }
+        *self = GroupsIdx {
+            first,
+            offsets,
+            all,
+        }
+    }
+
+    /// If every group consists of consecutive rows, return the groups as slices.
+    fn to_slices(&self) -> Option<GroupsSlice> {
+        (0..self.len())
+            .map(|i| {
+                let idx = self.get(i);
+                let first = self.first[i];
+                if idx
+                    .iter()
+                    .enumerate()
+                    .all(|(j, &row)| row == first + j as u32)
+                {
+                    Some([first, idx.len() as u32])
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<(u32, Vec<u32>)>> for GroupsIdx {
+    fn from(groups: Vec<(u32, Vec<u32>)>) -> Self {
+        let n_rows = groups.iter().map(|g| g.1.len()).sum::<usize>();
+        let mut first = Vec::with_capacity(groups.len());
+        let mut offsets = Vec::with_capacity(groups.len() + 1);
+        let mut all = Vec::with_capacity(n_rows);
+        offsets.push(0);
+        for (f, idx) in groups {
+            first.push(f);
+            all.extend_from_slice(&idx);
+            offsets.push(all.len() as u32);
+        }
+        GroupsIdx {
+            first,
+            offsets,
+            all,
+        }
+    }
+}
+
+/// Groups of consecutive rows. Every group is stored as `[first, len]`.
+pub type GroupsSlice = Vec<[u32; 2]>;
+
+/// The groups of a groupby operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupTuples {
+    /// Groups stored as row indexes.
+    Idx(GroupsIdx),
+    /// Groups of consecutive rows, e.g. the result of grouping sorted keys.
+    Slice(GroupsSlice),
+}
+
+impl Default for GroupTuples {
+    fn default() -> Self {
+        GroupTuples::Idx(GroupsIdx::default())
+    }
+}
+
+/// A single group of a [GroupTuples](enum.GroupTuples.html).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupsIndicator<'a> {
+    Idx((u32, &'a [u32])),
+    Slice([u32; 2]),
+}
+
+impl<'a> GroupsIndicator<'a> {
+    /// First row index of the group.
+    pub fn first(&self) -> u32 {
+        match self {
+            GroupsIndicator::Idx((first, _)) => *first,
+            GroupsIndicator::Slice([first, _]) => *first,
+        }
+    }
+
+    /// Last row index of the group.
+    pub fn last(&self) -> u32 {
+        match self {
+            GroupsIndicator::Idx((_, idx)) => idx[idx.len() - 1],
+            GroupsIndicator::Slice([first, len]) => first + len - 1,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            GroupsIndicator::Idx((_, idx)) => idx.len(),
+            GroupsIndicator::Slice([_, len]) => *len as usize,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Row index of the `i`-th row of the group.
+    pub fn get(&self, i: usize) -> u32 {
+        match self {
+            GroupsIndicator::Idx((_, idx)) => idx[i],
+            GroupsIndicator::Slice([first, len]) => {
+                assert!(i < *len as usize);
+                first + i as u32
+            }
+        }
+    }
+
+    /// Iterate over the row indexes of the group.
+    pub fn iter(&self) -> GroupIdxIter<'a> {
+        match self {
+            GroupsIndicator::Idx((_, idx)) => GroupIdxIter::Idx(idx.iter()),
+            GroupsIndicator::Slice([first, len]) => GroupIdxIter::Slice(*first..*first + *len),
+        }
+    }
+}
+
+/// Iterator over the row indexes of a single group.
+#[derive(Clone)]
+pub enum GroupIdxIter<'a> {
+    Idx(std::slice::Iter<'a, u32>),
+    Slice(Range<u32>),
+}
+
+impl<'a> Iterator for GroupIdxIter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            GroupIdxIter::Idx(iter) => iter.next().map(|i| *i as usize),
+            GroupIdxIter::Slice(range) => range.next().map(|i| i as usize),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            GroupIdxIter::Idx(iter) => iter.size_hint(),
+            GroupIdxIter::Slice(range) => range.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for GroupIdxIter<'a> {}
+
+impl GroupTuples {
+    pub fn len(&self) -> usize {
+        match self {
+            GroupTuples::Idx(groups) => groups.len(),
+            GroupTuples::Slice(groups) => groups.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get group `i`.
+    pub fn get(&self, i: usize) -> GroupsIndicator {
+        match self {
+            GroupTuples::Idx(groups) => GroupsIndicator::Idx((groups.first[i], groups.get(i))),
+            GroupTuples::Slice(groups) => GroupsIndicator::Slice(groups[i]),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = GroupsIndicator> + '_ {
+        (0..self.len()).map(move |i| self.get(i))
+    }
+
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = GroupsIndicator> + '_ {
+        (0..self.len()).into_par_iter().map(move |i| self.get(i))
+    }
+
+    /// Iterate over the first row index of every group.
+    pub fn iter_first(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.len()).map(move |i| self.get(i).first())
+    }
+
+    /// Total number of rows in all groups.
+    pub fn n_rows(&self) -> usize {
+        match self {
+            GroupTuples::Idx(groups) => groups.all.len(),
+            GroupTuples::Slice(groups) => groups.iter().map(|g| g[1] as usize).sum(),
+        }
+    }
+
+    /// Order the groups by their first row index.
+    pub fn sort(&mut self) {
+        match self {
+            GroupTuples::Idx(groups) => {
+                if !groups.first.windows(2).all(|w| w[0] <= w[1]) {
+                    groups.sort()
+                }
+            }
+            GroupTuples::Slice(groups) => groups.sort_unstable_by_key(|g| g[0]),
+        }
+    }
+
+    /// Convert to the slice representation if every group consists of consecutive rows.
+    pub fn into_slices_if_contiguous(self) -> Self {
+        match &self {
+            GroupTuples::Idx(groups) => match groups.to_slices() {
+                Some(slices) => GroupTuples::Slice(slices),
+                None => self,
+            },
+            GroupTuples::Slice(_) => self,
+        }
+    }
+
+    /// Create slice groups from keys that are sorted, i.e. equal keys are adjacent.
+    pub fn from_sorted_keys(keys: &Series) -> Self {
+        let len = keys.len();
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for i in 1..len {
+            // Safety: both indexes are in bounds.
+            if !unsafe { keys.equal_element(i, start, keys) } {
+                groups.push([start as u32, (i - start) as u32]);
+                start = i;
+            }
+        }
+        if len > 0 {
+            groups.push([start as u32, (len - start) as u32]);
+        }
+        GroupTuples::Slice(groups)
+    }
+
+    /// Create slice groups from sorted values, like [from_sorted_keys](GroupTuples::from_sorted_keys).
+    pub(crate) fn from_sorted_iter<T: PartialEq>(iter: impl Iterator<Item = T>) -> Self {
+        let mut groups = Vec::new();
+        let mut start = 0;
+        let mut current = None;
+        let mut len = 0;
+        for (i, v) in iter.enumerate() {
+            if current.as_ref() != Some(&v) {
+                if i > 0 {
+                    groups.push([start as u32, (i - start) as u32]);
+                }
+                start = i;
+                current = Some(v);
+            }
+            len = i + 1;
+        }
+        if len > 0 {
+            groups.push([start as u32, (len - start) as u32]);
+        }
+        GroupTuples::Slice(groups)
+    }
+
+    /// Row indexes of every group as a `ListChunked`.
+    pub fn to_list(&self) -> ListChunked {
+        self.iter()
+            .map(|g| {
+                let ca: NoNull<UInt32Chunked> = g.iter().map(|v| v as u32).collect();
+                ca.into_inner().into_series()
+            })
+            .collect()
+    }
+
+    /// Convert the groups to a `Vec` of the first row index and the row indexes of every group,
+    /// the representation of `GroupTuples` in earlier versions. This allocates a `Vec` per group.
+    pub fn into_idx(self) -> Vec<(u32, Vec<u32>)> {
+        self.iter()
+            .map(|g| (g.first(), g.iter().map(|i| i as u32).collect()))
+            .collect()
+    }
+}
+
+impl From<GroupsIdx> for GroupTuples {
+    fn from(groups: GroupsIdx) -> Self {
+        GroupTuples::Idx(groups)
+    }
+}
+
+impl From<Vec<(u32, Vec<u32>)>> for GroupTuples {
+    fn from(groups: Vec<(u32, Vec<u32>)>) -> Self {
+        GroupTuples::Idx(groups.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groups_idx() {
+        // rows: 0 -> a, 1 -> b, 2 -> a, 3 -> c, 4 -> b
+        let groups = GroupsIdx::from_group_ids(vec![0, 1, 3], None, &[0, 1, 0, 2, 1]);
+        assert_eq!(groups.get(0), &[0, 2]);
+        assert_eq!(groups.get(1), &[1, 4]);
+        assert_eq!(groups.get(2), &[3]);
+
+        // the first row index of an empty group is kept
+        let groups = GroupsIdx::from_lengths(&[2, 0, 1], |i, idx| {
+            idx.iter_mut()
+                .enumerate()
+                .for_each(|(j, v)| *v = (i + j) as u32);
+            idx.first().copied().unwrap_or(9)
+        });
+        assert_eq!(groups.first(), &[0, 9, 2]);
+        assert_eq!(groups.get(0), &[0, 1]);
+        assert!(groups.get(1).is_empty());
+        assert_eq!(groups.get(2), &[2]);
+
+        let groups: GroupTuples = vec![(3, vec![3, 4]), (0, vec![0, 1, 2])].into();
+        let mut sorted = groups.clone();
+        sorted.sort();
+        assert_eq!(sorted.iter_first().collect::<Vec<_>>(), &[0, 3]);
+        assert_eq!(sorted.get(0).iter().collect::<Vec<_>>(), &[0, 1, 2]);
+        assert_eq!(
+            sorted.into_slices_if_contiguous(),
+            GroupTuples::Slice(vec![[0, 3], [3, 2]])
+        );
+
+        let keys = Series::new("", &[1, 1, 2, 2, 2, 3]);
+        let groups = GroupTuples::from_sorted_keys(&keys);
+        assert_eq!(groups, GroupTuples::Slice(vec![[0, 2], [2, 3], [5, 1]]));
+        assert_eq!(groups.get(1).iter().collect::<Vec<_>>(), &[2, 3, 4]);
+        assert_eq!(groups.get(1).last(), 4);
+        assert_eq!(
+            groups.into_idx(),
+            vec![(0, vec![0, 1]), (2, vec![2, 3, 4]), (5, vec![5])]
+        );
+        let groups: GroupTuples = vec![(3, vec![3, 4]), (0, vec![0, 1, 2])].into();
+        assert_eq!(groups.into_idx(), vec![(3, vec![3, 4]), (0, vec![0, 1, 2])]);
+
+        // keys with a sortedness flag are grouped in contiguous slices
+        let mut keys = Series::new("", &[Some(3), Some(3), Some(1), None, None]);
+        keys.set_sorted(IsSorted::Descending);
+        assert_eq!(
+            keys.group_tuples(false),
+            GroupTuples::Slice(vec![[0, 2], [2, 1], [3, 2]])
+        );
+    }
+}
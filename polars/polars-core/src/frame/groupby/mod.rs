@@ -1,26 +1,29 @@
 use crate::chunked_array::builder::PrimitiveChunkedBuilder;
 use crate::frame::select::Selection;
 use crate::prelude::*;
-use crate::utils::{accumulate_dataframes_vertical, split_ca, split_df, NoNull};
+use crate::utils::{accumulate_dataframes_vertical, split_ca, split_df};
 use crate::vector_hasher::{
     create_hash_and_keys_threaded_vectorized, df_rows_to_hashes, df_rows_to_hashes_threaded,
-    prepare_hashed_relation, this_thread, IdBuildHasher, IdxHash,
+    this_thread, IdBuildHasher, IdxHash,
 };
 use crate::POOL;
 use ahash::RandomState;
 use hashbrown::{hash_map::RawEntryMut, HashMap};
 use itertools::Itertools;
 use rayon::prelude::*;
+use std::cell::Cell;
+use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash, Hasher};
 
 pub mod aggregations;
+mod groups;
 #[cfg(feature = "pivot")]
 pub(crate) mod pivot;
 #[cfg(feature = "downsample")]
 pub mod resample;
 
-pub type GroupTuples = Vec<(u32, Vec<u32>)>;
+pub use groups::{GroupIdxIter, GroupTuples, GroupsIdx, GroupsIndicator, GroupsSlice};
 
 pub trait VecHash {
     /// Compute the hase for all values in the array.
@@ -61,6 +64,37 @@ impl VecHash for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl VecHash for BinaryChunked {
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        let ca: NoNull<UInt64Chunked> = self
+            .iter()
+            .map(|opt_v| {
+                let mut hasher = random_state.build_hasher();
+                opt_v.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        ca.into_inner()
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl VecHash for DecimalChunked {
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        // the unscaled values are hashed, all values of an array have the same scale
+        let ca: NoNull<UInt64Chunked> = self
+            .iter()
+            .map(|opt_v| {
+                let mut hasher = random_state.build_hasher();
+                opt_v.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        ca.into_inner()
+    }
+}
+
 impl VecHash for BooleanChunked {
     fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
         self.branch_apply_cast_numeric_no_null(|opt_v| {
@@ -98,15 +132,46 @@ fn groupby<T>(a: impl Iterator<Item = T>) -> GroupTuples
 where
     T: Hash + Eq,
 {
-    let hash_tbl = prepare_hashed_relation(a);
+    let (lower, _) = a.size_hint();
+    let mut hash_tbl: HashMap<T, u32, RandomState> =
+        HashMap::with_capacity_and_hasher(lower, RandomState::default());
+    let mut first = Vec::new();
+    let mut group_ids = Vec::with_capacity(lower);
+
+    for (idx, k) in a.enumerate() {
+        let n_groups = first.len() as u32;
+        let group_id = *hash_tbl.entry(k).or_insert_with(|| {
+            first.push(idx as u32);
+            n_groups
+        });
+        group_ids.push(group_id);
+    }
+    GroupsIdx::from_group_ids(first, None, &group_ids).into()
+}
 
-    hash_tbl
-        .into_iter()
-        .map(|(_, indexes)| {
-            let first = unsafe { *indexes.get_unchecked(0) };
-            (first, indexes)
-        })
-        .collect()
+/// Group keys with few distinct values, like booleans and 8 bit integers, by looking up the group
+/// of a key in a table instead of hashing it. `slot` maps a key to its index in a table of
+/// `n_slots` entries.
+fn groupby_small_keys<T>(
+    a: impl Iterator<Item = Option<T>>,
+    n_slots: usize,
+    slot: impl Fn(T) -> usize,
+) -> GroupTuples {
+    let (lower, _) = a.size_hint();
+    // the last entry is the group of the null values
+    let mut table = vec![u32::MAX; n_slots + 1];
+    let mut first = Vec::new();
+    let mut group_ids = Vec::with_capacity(lower);
+
+    for (idx, opt_k) in a.enumerate() {
+        let entry = &mut table[opt_k.map(&slot).unwrap_or(n_slots)];
+        if *entry == u32::MAX {
+            *entry = first.len() as u32;
+            first.push(idx as u32);
+        }
+        group_ids.push(*entry);
+    }
+    GroupsIdx::from_group_ids(first, None, &group_ids).into()
 }
 
 fn groupby_threaded_flat<I, T>(iters: Vec<I>, group_size_hint: usize) -> GroupTuples
@@ -114,15 +179,13 @@ where
     I: IntoIterator<Item = T> + Send,
     T: Send + Hash + Eq + Sync + Copy,
 {
-    groupby_threaded(iters, group_size_hint)
-        .into_iter()
-        .flatten()
-        .collect()
+    GroupsIdx::concat(groupby_threaded(iters, group_size_hint)).into()
 }
 
-/// Determine groupby tuples from an iterator. The group_size_hint is used to pre-allocate the group vectors.
+/// Determine the groups from an iterator. Every thread returns the groups of its partition of the keys.
+/// The group_size_hint is used to pre-allocate the hash tables.
 /// When the grouping column is a categorical type we already have a good indication of the avg size of the groups.
-fn groupby_threaded<I, T>(iters: Vec<I>, group_size_hint: usize) -> Vec<GroupTuples>
+fn groupby_threaded<I, T>(iters: Vec<I>, group_size_hint: usize) -> Vec<GroupsIdx>
 where
     I: IntoIterator<Item = T> + Send,
     T: Send + Hash + Eq + Sync + Copy,
@@ -130,18 +193,27 @@ where
     let n_threads = iters.len();
     let (hashes_and_keys, random_state) = create_hash_and_keys_threaded_vectorized(iters, None);
     let size = hashes_and_keys.iter().fold(0, |acc, v| acc + v.len());
+    let tbl_size = if group_size_hint > 0 {
+        size / group_size_hint / n_threads
+    } else {
+        size / n_threads
+    };
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
+    // The hashtable maps a key to its group id. The row indexes are gathered in flat buffers.
     POOL.install(|| {
         (0..n_threads).into_par_iter().map(|thread_no| {
             let random_state = random_state.clone();
             let hashes_and_keys = &hashes_and_keys;
             let thread_no = thread_no as u64;
 
-            let mut hash_tbl: HashMap<T, (u32, Vec<u32>), RandomState> =
-                HashMap::with_capacity_and_hasher(size / n_threads, random_state);
+            let mut hash_tbl: HashMap<T, u32, RandomState> =
+                HashMap::with_capacity_and_hasher(tbl_size, random_state);
+            let mut first = Vec::new();
+            let mut rows = Vec::with_capacity(size / n_threads);
+            let mut group_ids = Vec::with_capacity(size / n_threads);
 
             let n_threads = n_threads as u64;
             let mut offset = 0;
@@ -161,23 +233,23 @@ where
                                 // uses the key to check equality to find and entry
                                 .from_key_hashed_nocheck(*h, &k);
 
-                            match entry {
+                            let group_id = match entry {
                                 RawEntryMut::Vacant(entry) => {
-                                    let mut tuples = Vec::with_capacity(group_size_hint);
-                                    tuples.push(idx);
-                                    entry.insert_hashed_nocheck(*h, *k, (idx, tuples));
+                                    let group_id = first.len() as u32;
+                                    first.push(idx);
+                                    entry.insert_hashed_nocheck(*h, *k, group_id);
+                                    group_id
                                 }
-                                RawEntryMut::Occupied(mut entry) => {
-                                    let (_k, v) = entry.get_key_value_mut();
-                                    v.1.push(idx);
-                                }
-                            }
+                                RawEntryMut::Occupied(entry) => *entry.get(),
+                            };
+                            rows.push(idx);
+                            group_ids.push(group_id);
                         }
                     });
 
                 offset += len;
             }
-            hash_tbl.into_iter().map(|(_k, v)| v).collect::<Vec<_>>()
+            GroupsIdx::from_group_ids(first, Some(&rows), &group_ids)
         })
     })
     .collect()
@@ -244,29 +316,44 @@ pub(crate) fn populate_multiple_key_hashmap<V, H, F, G>(
     }
 }
 
+/// Insert a row in a multiple key hashmap that maps the keys to a group id.
+/// The group id of the row is returned. If the row starts a new group, its index is pushed to `first`.
+fn insert_multiple_key_group(
+    hash_tbl: &mut HashMap<IdxHash, u32, IdBuildHasher>,
+    idx: u32,
+    h: u64,
+    keys: &DataFrame,
+    first: &mut Vec<u32>,
+) -> u32 {
+    let new_group_id = first.len() as u32;
+    let found = Cell::new(new_group_id);
+    populate_multiple_key_hashmap(hash_tbl, idx, h, keys, || new_group_id, |v| found.set(*v));
+    let group_id = found.get();
+    if group_id == new_group_id {
+        first.push(idx);
+    }
+    group_id
+}
+
 fn groupby_multiple_keys(keys: DataFrame) -> GroupTuples {
     let (hashes, _) = df_rows_to_hashes(&keys, None);
     let size = hashes.len();
     // rather over allocate because rehashing is expensive
-    let mut hash_tbl: HashMap<IdxHash, (u32, Vec<u32>), IdBuildHasher> =
+    let mut hash_tbl: HashMap<IdxHash, u32, IdBuildHasher> =
         HashMap::with_capacity_and_hasher(size, IdBuildHasher::default());
+    let mut first = Vec::new();
+    let mut group_ids = Vec::with_capacity(size);
 
     // hashes has no nulls
     let mut idx = 0;
     for hashes_chunk in hashes.data_views() {
         for &h in hashes_chunk {
-            populate_multiple_key_hashmap(
-                &mut hash_tbl,
-                idx,
-                h,
-                &keys,
-                || (idx, vec![idx]),
-                |v| v.1.push(idx),
-            );
+            let group_id = insert_multiple_key_group(&mut hash_tbl, idx, h, &keys, &mut first);
+            group_ids.push(group_id);
             idx += 1;
         }
     }
-    hash_tbl.into_iter().map(|(_k, v)| v).collect::<Vec<_>>()
+    GroupsIdx::from_group_ids(first, None, &group_ids).into()
 }
 
 fn groupby_threaded_multiple_keys_flat(keys: DataFrame, n_threads: usize) -> GroupTuples {
@@ -280,49 +367,55 @@ fn groupby_threaded_multiple_keys_flat(keys: DataFrame, n_threads: usize) -> Gro
 
     // We use a combination of a custom IdentityHasher and a utility key IdxHash that stores
     // the index of the row and and the hash. The Hash function of this key just returns the hash it stores.
-    POOL.install(|| {
-        (0..n_threads).into_par_iter().map(|thread_no| {
-            let hashes = &hashes;
-            let thread_no = thread_no as u64;
-
-            let keys = &keys;
-
-            // rather over allocate because rehashing is expensive
-            let mut hash_tbl: HashMap<IdxHash, (u32, Vec<u32>), IdBuildHasher> =
-                HashMap::with_capacity_and_hasher(size / n_threads, IdBuildHasher::default());
-
-            let n_threads = n_threads as u64;
-            let mut offset = 0;
-            for hashes in hashes {
-                let len = hashes.len() as u32;
-
-                let mut idx = 0;
-                for hashes_chunk in hashes.data_views() {
-                    for &h in hashes_chunk {
-                        // partition hashes by thread no.
-                        // So only a part of the hashes go to this hashmap
-                        if this_thread(h, thread_no, n_threads) {
-                            let idx = idx + offset;
-                            populate_multiple_key_hashmap(
-                                &mut hash_tbl,
-                                idx,
-                                h,
-                                &keys,
-                                || (idx, vec![idx]),
-                                |v| v.1.push(idx),
-                            );
+    let parts = POOL.install(|| {
+        (0..n_threads)
+            .into_par_iter()
+            .map(|thread_no| {
+                let hashes = &hashes;
+                let thread_no = thread_no as u64;
+
+                let keys = &keys;
+
+                // rather over allocate because rehashing is expensive
+                let mut hash_tbl: HashMap<IdxHash, u32, IdBuildHasher> =
+                    HashMap::with_capacity_and_hasher(size / n_threads, IdBuildHasher::default());
+                let mut first = Vec::new();
+                let mut rows = Vec::with_capacity(size / n_threads);
+                let mut group_ids = Vec::with_capacity(size / n_threads);
+
+                let n_threads = n_threads as u64;
+                let mut offset = 0;
+                for hashes in hashes {
+                    let len = hashes.len() as u32;
+
+                    let mut idx = 0;
+                    for hashes_chunk in hashes.data_views() {
+                        for &h in hashes_chunk {
+                            // partition hashes by thread no.
+                            // So only a part of the hashes go to this hashmap
+                            if this_thread(h, thread_no, n_threads) {
+                                let idx = idx + offset;
+                                let group_id = insert_multiple_key_group(
+                                    &mut hash_tbl,
+                                    idx,
+                                    h,
+                                    keys,
+                                    &mut first,
+                                );
+                                rows.push(idx);
+                                group_ids.push(group_id);
+                            }
+                            idx += 1;
                         }
-                        idx += 1;
                     }
-                }
 
-                offset += len;
-            }
-            hash_tbl.into_iter().map(|(_k, v)| v).collect::<Vec<_>>()
-        })
-    })
-    .flatten()
-    .collect()
+                    offset += len;
+                }
+                GroupsIdx::from_group_ids(first, Some(&rows), &group_ids)
+            })
+            .collect::<Vec<_>>()
+    });
+    GroupsIdx::concat(parts).into()
 }
 
 /// Used to create the tuples for a groupby operation.
@@ -373,6 +466,10 @@ where
     T::Native: Eq + Hash + Send,
 {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        // equal keys are adjacent, so the groups are contiguous slices
+        if self.is_sorted() != IsSorted::Not {
+            return GroupTuples::from_sorted_iter(self.into_iter());
+        }
         let group_size_hint = if let Some(m) = &self.categorical_map {
             self.len() / m.len()
         } else {
@@ -429,6 +526,20 @@ impl IntoGroupTuples for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl IntoGroupTuples for BinaryChunked {
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        group_tuples!(self, multithreaded)
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl IntoGroupTuples for DecimalChunked {
+    fn group_tuples(&self, _multithreaded: bool) -> GroupTuples {
+        groupby(self.iter())
+    }
+}
+
 impl IntoGroupTuples for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         self.cast::<UInt32Type>()
@@ -502,7 +613,21 @@ impl DataFrame {
         let groups = match by.len() {
             1 => {
                 let series = &by[0];
-                series.group_tuples(multithreaded)
+                // a single pass over a small table is faster than hashing on multiple threads
+                match series.dtype() {
+                    DataType::Boolean => {
+                        groupby_small_keys(series.bool().unwrap().into_iter(), 2, |v| v as usize)
+                    }
+                    DataType::UInt8 => {
+                        groupby_small_keys(series.u8().unwrap().into_iter(), 256, |v| v as usize)
+                    }
+                    DataType::Int8 => {
+                        groupby_small_keys(series.i8().unwrap().into_iter(), 256, |v| {
+                            (v as i16 + 128) as usize
+                        })
+                    }
+                    _ => series.group_tuples(multithreaded),
+                }
             }
             _ => {
                 if multithreaded {
@@ -513,6 +638,8 @@ impl DataFrame {
                 }
             }
         };
+        // groups of consecutive rows, e.g. sorted keys, don't need the index buffers
+        let groups = groups.into_slices_if_contiguous();
 
         Ok(GroupBy {
             df: self,
@@ -601,7 +728,7 @@ impl DataFrame {
 pub struct GroupBy<'df, 'selection_str> {
     df: &'df DataFrame,
     pub(crate) selected_keys: Vec<Series>,
-    // [first idx, [other idx]]
+    // first idx and all idx of every group
     pub(crate) groups: GroupTuples,
     // columns selected for aggregation
     pub(crate) selected_agg: Option<Vec<&'selection_str str>>,
@@ -637,9 +764,8 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     }
 
     /// Get the internal representation of the GroupBy operation.
-    /// The Vec returned contains:
-    ///     (first_idx, Vec<indexes>)
-    ///     Where second value in the tuple is a vector with all matching indexes.
+    /// Every group holds the first index of the group and all matching indexes,
+    /// either as flat index buffers or as slices of consecutive rows.
     pub fn get_groups(&self) -> &GroupTuples {
         &self.groups
     }
@@ -656,7 +782,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         unsafe {
             self.selected_keys.iter().for_each(|s| {
                 let key =
-                    s.take_iter_unchecked(&mut self.groups.iter().map(|(idx, _)| *idx as usize));
+                    s.take_iter_unchecked(&mut self.groups.iter_first().map(|idx| idx as usize));
                 keys.push(key)
             });
         }
@@ -711,7 +837,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
         for agg_col in agg_cols {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Mean);
-            let opt_agg = agg_col.agg_mean(&self.groups);
+            let opt_agg = agg_col.try_agg_mean(&self.groups)?;
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -750,7 +876,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
         for agg_col in agg_cols {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Sum);
-            let opt_agg = agg_col.agg_sum(&self.groups);
+            let opt_agg = agg_col.try_agg_sum(&self.groups)?;
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -1055,8 +1181,8 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Count);
             let mut builder =
                 PrimitiveChunkedBuilder::<UInt32Type>::new(&new_name, self.groups.len());
-            for (_first, idx) in &self.groups {
-                builder.append_value(idx.len() as u32);
+            for g in self.groups.iter() {
+                builder.append_value(g.len() as u32);
             }
             let ca = builder.finish();
             cols.push(ca.into_series())
@@ -1092,14 +1218,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     pub fn groups(&self) -> Result<DataFrame> {
         let mut cols = self.keys();
 
-        let mut column: ListChunked = self
-            .groups
-            .iter()
-            .map(|(_first, idx)| {
-                let ca: NoNull<UInt32Chunked> = idx.iter().map(|&v| v as u32).collect();
-                ca.into_inner().into_series()
-            })
-            .collect();
+        let mut column = self.groups.to_list();
         let new_name = fmt_groupby_column("", GroupByMethod::Groups);
         column.rename(&new_name);
         cols.push(column.into_series());
@@ -1199,8 +1318,8 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
                                 &new_name,
                                 self.groups.len(),
                             );
-                            for (_first, idx) in &self.groups {
-                                builder.append_value(idx.len() as u32);
+                            for g in self.groups.iter() {
+                                builder.append_value(g.len() as u32);
                             }
                             let ca = builder.finish();
                             cols.push(ca.into_series());
@@ -1251,30 +1370,35 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Apply a closure over the groups as a new DataFrame.
-    pub fn apply<F>(&self, f: F) -> Result<DataFrame>
-    where
-        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
-    {
-        let df = if let Some(agg) = &self.selected_agg {
+    /// The DataFrame of which every group is passed to an applied closure.
+    fn prepare_apply(&self) -> Result<DataFrame> {
+        if let Some(agg) = &self.selected_agg {
             if agg.is_empty() {
-                self.df.clone()
+                Ok(self.df.clone())
             } else {
                 let mut new_cols = Vec::with_capacity(self.selected_keys.len() + agg.len());
                 new_cols.extend_from_slice(&self.selected_keys);
                 let cols = self.df.select_series(agg)?;
                 new_cols.extend(cols.into_iter());
-                DataFrame::new_no_checks(new_cols)
+                Ok(DataFrame::new_no_checks(new_cols))
             }
         } else {
-            self.df.clone()
-        };
+            Ok(self.df.clone())
+        }
+    }
+
+    /// Apply a closure over the groups as a new DataFrame.
+    pub fn apply<F>(&self, f: F) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let df = self.prepare_apply()?;
 
         let dfs = self
             .get_groups()
             .par_iter()
-            .map(|t| {
-                let sub_df = unsafe { df.take_iter_unchecked(t.1.iter().map(|i| *i as usize)) };
+            .map(|g| {
+                let sub_df = unsafe { df.take_iter_unchecked(g.iter()) };
                 f(sub_df)
             })
             .collect::<Result<Vec<_>>>()?;
@@ -1283,6 +1407,127 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         df.as_single_chunk();
         Ok(df)
     }
+
+    /// Apply a closure that returns a DataFrame with the given `schema` over the groups. The groups
+    /// are processed in parallel and the key columns are added to the output, repeated for every
+    /// row the closure returns for a group.
+    ///
+    /// The columns of the schema are selected from the output of the closure and cast to the
+    /// declared data types. Key columns in the schema are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     let schema = Schema::new(vec![Field::new("temp", DataType::Int32)]);
+    ///     // the two highest temperatures of every date
+    ///     df.groupby("date")?
+    ///         .apply_with_schema(|df| df.sort("temp", true).map(|df| df.head(Some(2))), &schema)
+    /// }
+    /// ```
+    pub fn apply_with_schema<F>(&self, f: F, schema: &Schema) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        self.apply_with_schema_and_aggs(f, schema, vec![])
+    }
+
+    /// Like [apply_with_schema](GroupBy::apply_with_schema), but also add aggregations that have a
+    /// single value for every group, in the order of the groups. These are repeated like the key
+    /// columns and placed between the keys and the output of the closure. An aggregation can't
+    /// have the name of a column in the schema.
+    pub fn apply_with_schema_and_aggs<F>(
+        &self,
+        f: F,
+        schema: &Schema,
+        aggs: Vec<Series>,
+    ) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let n_groups = self.groups.len();
+        if let Some(agg) = aggs.iter().find(|s| s.len() != n_groups) {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "aggregation {} has length {} while there are {} groups",
+                    agg.name(),
+                    agg.len(),
+                    n_groups
+                )
+                .into(),
+            ));
+        }
+        let key_names: Vec<_> = self.selected_keys.iter().map(|s| s.name()).collect();
+        let fields: Vec<_> = schema
+            .fields()
+            .iter()
+            .filter(|fld| !key_names.contains(&fld.name().as_str()))
+            .collect();
+        if let Some(agg) = aggs
+            .iter()
+            .find(|s| fields.iter().any(|fld| fld.name() == s.name()))
+        {
+            return Err(PolarsError::Duplicate(
+                format!(
+                    "aggregation {} has the same name as a column of the declared schema",
+                    agg.name()
+                )
+                .into(),
+            ));
+        }
+        let df = self.prepare_apply()?;
+
+        let outputs = POOL.install(|| {
+            self.groups
+                .par_iter()
+                .map(|g| {
+                    let sub_df = unsafe { df.take_iter_unchecked(g.iter()) };
+                    let out = f(sub_df)?;
+                    fields
+                        .iter()
+                        .map(|fld| out.column(fld.name())?.cast_with_datatype(fld.data_type()))
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        // every row of the output gets the values of the group it was computed from
+        let group_idx: Vec<usize> = outputs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, columns)| {
+                let height = columns.first().map(|s| s.len()).unwrap_or(0);
+                std::iter::repeat(i).take(height)
+            })
+            .collect();
+
+        let mut columns = self.keys();
+        columns.extend(aggs);
+        let mut columns: Vec<_> = columns
+            .iter()
+            .map(|s| unsafe { s.take_iter_unchecked(&mut group_idx.iter().copied()) })
+            .collect();
+
+        for (i, fld) in fields.iter().enumerate() {
+            let mut iter = outputs.iter().map(|columns| &columns[i]);
+            let s = match iter.next() {
+                Some(first) => {
+                    let mut s = first.clone();
+                    for other in iter {
+                        s.append(other)?;
+                    }
+                    s.rechunk()
+                }
+                None => Series::try_from((
+                    fld.name().as_str(),
+                    arrow::array::new_empty_array(&fld.data_type().to_arrow()),
+                ))?,
+            };
+            columns.push(s);
+        }
+        DataFrame::new(columns)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -1301,6 +1546,13 @@ pub enum GroupByMethod {
     List,
     Std,
     Var,
+    ArgMin,
+    ArgMax,
+    Mode,
+    Product,
+    Any,
+    All,
+    NNulls,
 }
 
 // Formatting functions used in eager and lazy code for renaming grouped columns
@@ -1321,14 +1573,19 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Quantile(quantile) => format!["{}_quantile_{:.2}", name, quantile],
         Std => format!["{}_agg_std", name],
         Var => format!["{}_agg_var", name],
+        ArgMin => format!["{}_arg_min", name],
+        ArgMax => format!["{}_arg_max", name],
+        Mode => format!["{}_mode", name],
+        Product => format!["{}_product", name],
+        Any => format!["{}_any", name],
+        All => format!["{}_all", name],
+        NNulls => format!["{}_n_nulls", name],
     }
 }
 
 #[cfg(test)]
 mod test {
-    use itertools::Itertools;
-
-    use crate::frame::groupby::{groupby, groupby_threaded_flat};
+    use crate::frame::groupby::{groupby, groupby_threaded_flat, GroupTuples};
     use crate::prelude::*;
     use crate::utils::split_ca;
 
@@ -1578,6 +1835,178 @@ mod test {
         assert!(out.sort("b", false).unwrap().frame_equal(&df));
     }
 
+    #[test]
+    fn test_agg_extended() {
+        let groups: GroupTuples = vec![(0, vec![0, 1, 2]), (3, vec![3, 4]), (5, vec![5])].into();
+        let s = Series::new("a", &[Some(2), Some(1), Some(2), None, Some(3), None]);
+        assert_eq!(
+            Vec::from(s.agg_arg_min(&groups).unwrap().u32().unwrap()),
+            &[Some(1), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(s.agg_arg_max(&groups).unwrap().u32().unwrap()),
+            &[Some(0), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(s.agg_product(&groups).unwrap().i64().unwrap()),
+            &[Some(4), Some(3), None]
+        );
+        assert_eq!(
+            Vec::from(s.agg_mode(&groups).unwrap().i32().unwrap()),
+            &[Some(2), Some(3), None]
+        );
+        assert_eq!(
+            Vec::from(&s.agg_n_nulls(&groups)),
+            &[Some(0), Some(1), Some(1)]
+        );
+
+        let b = Series::new(
+            "b",
+            &[Some(true), Some(false), None, None, Some(true), None],
+        );
+        assert_eq!(
+            Vec::from(b.agg_any(&groups).unwrap().bool().unwrap()),
+            &[Some(true), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(b.agg_all(&groups).unwrap().bool().unwrap()),
+            &[Some(false), Some(true), Some(true)]
+        );
+        assert!(s.agg_any(&groups).is_err());
+        let utf8 = Series::new("c", &["a", "b", "c", "d", "e", "f"]);
+        assert!(matches!(
+            utf8.agg_product(&groups),
+            Err(PolarsError::InvalidOperation(_))
+        ));
+        assert!(utf8.agg_arg_min(&groups).is_err());
+        assert!(utf8.agg_all(&groups).is_err());
+
+        // a product that overflows is null
+        let groups: GroupTuples = vec![(0, vec![0, 1]), (2, vec![2, 3])].into();
+        let s = Series::new("a", &[i64::MAX, 2, i64::MAX, 1]);
+        assert_eq!(
+            Vec::from(s.agg_product(&groups).unwrap().i64().unwrap()),
+            &[None, Some(i64::MAX)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-u64")]
+    fn test_agg_product_u64() {
+        let groups: GroupTuples = vec![(0, vec![0, 1]), (2, vec![2, 3])].into();
+        // the values don't fit in an i64
+        let s = Series::new("a", &[u64::MAX, 1, u64::MAX / 2, 4]);
+        assert_eq!(
+            Vec::from(s.agg_product(&groups).unwrap().u64().unwrap()),
+            &[Some(u64::MAX), None]
+        );
+    }
+
+    #[test]
+    fn test_groupby_apply_with_schema() {
+        let df = df! {
+            "a" => [1, 1, 2, 2, 2],
+            "b" => [1, 2, 3, 4, 5]
+        }
+        .unwrap();
+        let schema = Schema::new(vec![Field::new("b", DataType::Float64)]);
+        let gb = df.groupby("a").unwrap();
+
+        // the keys are not part of the closure output and columns outside the schema are dropped
+        let out = gb
+            .apply_with_schema(
+                |df| {
+                    let b = df.column("b")?.head(Some(1));
+                    DataFrame::new(vec![b, Series::new("c", &[0])])
+                },
+                &schema,
+            )
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(out.column("b").unwrap().dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.column("b").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(3.0)]
+        );
+
+        let sum = gb.clone().select("b").sum().unwrap();
+        let out = gb
+            .apply_with_schema_and_aggs(Ok, &schema, vec![sum.column("b_sum").unwrap().clone()])
+            .unwrap()
+            .sort("b", false)
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["a", "b_sum", "b"]);
+        assert_eq!(
+            Vec::from(out.column("b_sum").unwrap().i32().unwrap()),
+            &[Some(3), Some(3), Some(12), Some(12), Some(12)]
+        );
+
+        // an aggregation can't replace a column of the declared schema
+        let b_sum = sum.column("b_sum").unwrap().clone();
+        let schema = Schema::new(vec![Field::new("b_sum", DataType::Int32)]);
+        assert!(gb
+            .apply_with_schema_and_aggs(Ok, &schema, vec![b_sum])
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-u8",
+        feature = "dtype-u16",
+        feature = "dtype-i8",
+        feature = "dtype-i16",
+        feature = "dtype-u64",
+        feature = "dtype-date32"
+    ))]
+    fn test_groupby_key_dtypes() {
+        let df = df! {
+            "k" => [Some(0), Some(1), None, Some(0), Some(1), None],
+            "w" => [1, 1, 1, 1, 1, 1],
+            "v" => [1, 2, 3, 4, 5, 6]
+        }
+        .unwrap();
+
+        use DataType::*;
+        for dtype in &[UInt8, UInt16, Int8, Int16, UInt64, Boolean, Date32] {
+            let mut df = df.clone();
+            df.may_apply("k", |s| s.cast_with_datatype(dtype)).unwrap();
+            // a single key and multiple keys are grouped by different code paths
+            let single = df.groupby_stable("k").unwrap().select("v").sum().unwrap();
+            let multiple = df
+                .groupby_stable(&["k", "w"])
+                .unwrap()
+                .select("v")
+                .sum()
+                .unwrap();
+            for out in &[single, multiple] {
+                assert_eq!(out.column("k").unwrap().dtype(), dtype);
+                assert_eq!(out.column("k").unwrap().null_count(), 1);
+                assert_eq!(
+                    Vec::from(out.column("v_sum").unwrap().i32().unwrap()),
+                    &[Some(5), Some(7), Some(9)]
+                );
+            }
+        }
+
+        // the extremes of a signed 8 bit key get their own group
+        let df = df! {
+            "k" => [-128i8, 127, -1, -128, 0, 127],
+            "v" => [1, 2, 3, 4, 5, 6]
+        }
+        .unwrap();
+        let out = df.groupby_stable("k").unwrap().select("v").sum().unwrap();
+        assert_eq!(
+            Vec::from(out.column("k").unwrap().i8().unwrap()),
+            &[Some(-128), Some(127), Some(-1), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_sum").unwrap().i32().unwrap()),
+            &[Some(5), Some(8), Some(3), Some(5)]
+        );
+    }
+
     #[test]
     fn test_groupby_threaded() {
         for slice in &[
@@ -1588,11 +2017,11 @@ mod test {
             let ca = UInt8Chunked::new_from_slice("", &slice);
             let splitted = split_ca(&ca, 4).unwrap();
 
-            let a = groupby(ca.into_iter()).into_iter().sorted().collect_vec();
-            let b = groupby_threaded_flat(splitted.iter().map(|ca| ca.into_iter()).collect(), 0)
-                .into_iter()
-                .sorted()
-                .collect_vec();
+            let mut a = groupby(ca.into_iter());
+            let mut b =
+                groupby_threaded_flat(splitted.iter().map(|ca| ca.into_iter()).collect(), 0);
+            a.sort();
+            b.sort();
 
             assert_eq!(a, b);
         }
@@ -1,4 +1,4 @@
-use super::GroupBy;
+use super::{GroupBy, GroupTuples};
 use crate::chunked_array::float::IntegerDecode;
 use crate::prelude::*;
 use hashbrown::HashMap;
@@ -21,6 +21,8 @@ pub(crate) enum Groupable<'a> {
     Int16(i16),
     Int32(i32),
     Int64(i64),
+    Date32(i32),
+    Date64(i64),
     // mantissa, exponent, sign.
     Float32(u64, i16, i8),
     Float64(u64, i16, i8),
@@ -40,6 +42,11 @@ impl<'a> Debug for Groupable<'a> {
             Int16(v) => write!(f, "{}", v),
             Int32(v) => write!(f, "{}", v),
             Int64(v) => write!(f, "{}", v),
+            Date32(v) => write!(f, "{}", AnyValue::Date32(*v)),
+            #[cfg(feature = "temporal")]
+            Date64(v) => write!(f, "{}", AnyValue::Date64(*v)),
+            #[cfg(not(feature = "temporal"))]
+            Date64(v) => write!(f, "{}", v),
             Float32(m, e, s) => write!(f, "float32 mantissa: {} exponent: {} sign: {}", m, e, s),
             Float64(m, e, s) => write!(f, "float64 mantissa: {} exponent: {} sign: {}", m, e, s),
         }
@@ -91,12 +98,8 @@ impl<'b> (dyn SeriesTrait + 'b) {
             DataType::Int16 => as_groupable_iter!(self.i16().unwrap(), Int16),
             DataType::Int32 => as_groupable_iter!(self.i32().unwrap(), Int32),
             DataType::Int64 => as_groupable_iter!(self.i64().unwrap(), Int64),
-            DataType::Date32 => {
-                as_groupable_iter!(self.date32().unwrap(), Int32)
-            }
-            DataType::Date64 => {
-                as_groupable_iter!(self.date64().unwrap(), Int64)
-            }
+            DataType::Date32 => as_groupable_iter!(self.date32().unwrap(), Date32),
+            DataType::Date64 => as_groupable_iter!(self.date64().unwrap(), Date64),
             DataType::Time64(TimeUnit::Nanosecond) => {
                 as_groupable_iter!(self.time64_nanosecond().unwrap(), Int64)
             }
@@ -215,7 +218,7 @@ pub(crate) trait ChunkPivot {
         &self,
         _pivot_series: &'a (dyn SeriesTrait + 'a),
         _keys: Vec<Series>,
-        _groups: &[(u32, Vec<u32>)],
+        _groups: &GroupTuples,
         _agg_type: PivotAgg,
     ) -> Result<DataFrame> {
         Err(PolarsError::InvalidOperation(
@@ -227,7 +230,7 @@ pub(crate) trait ChunkPivot {
         &self,
         _pivot_series: &'a (dyn SeriesTrait + 'a),
         _keys: Vec<Series>,
-        _groups: &[(u32, Vec<u32>)],
+        _groups: &GroupTuples,
     ) -> Result<DataFrame> {
         Err(PolarsError::InvalidOperation(
             "Pivot count operation not implemented for this type".into(),
@@ -252,7 +255,7 @@ fn create_column_values_map<'a, T>(
 /// Create a hashmap that maps columns/keys to the result of the aggregation.
 fn create_new_column_builder_map<'a, T>(
     pivot_vec: &'a [Option<Groupable>],
-    groups: &[(u32, Vec<u32>)],
+    groups: &GroupTuples,
 ) -> HashMap<&'a Groupable<'a>, PrimitiveChunkedBuilder<T>, RandomState>
 where
     T: PolarsNumericType,
@@ -278,7 +281,7 @@ where
         &self,
         pivot_series: &'a (dyn SeriesTrait + 'a),
         keys: Vec<Series>,
-        groups: &[(u32, Vec<u32>)],
+        groups: &GroupTuples,
         agg_type: PivotAgg,
     ) -> Result<DataFrame> {
         // TODO: save an allocation by creating a random access struct for the Groupable utility type.
@@ -292,13 +295,12 @@ where
 
         // iterate over the groups that need to be aggregated
         // idxes are the indexes of the groups in the keys, pivot, and values columns
-        for (_first, idx) in groups {
+        for g in groups.iter() {
             // for every group do the aggregation by adding them to the vector belonging by that column
             // the columns are hashed with the pivot values
             let mut columns_agg_map_group =
-                create_column_values_map::<T::Native>(&pivot_vec_unique, idx.len());
-            for &i in idx {
-                let i = i as usize;
+                create_column_values_map::<T::Native>(&pivot_vec_unique, g.len());
+            for i in g.iter() {
                 let opt_pivot_val = unsafe { pivot_vec.get_unchecked(i) };
 
                 if let Some(pivot_val) = opt_pivot_val {
@@ -344,7 +346,7 @@ where
         &self,
         pivot_series: &'a (dyn SeriesTrait + 'a),
         keys: Vec<Series>,
-        groups: &[(u32, Vec<u32>)],
+        groups: &GroupTuples,
     ) -> Result<DataFrame> {
         pivot_count_impl(self, pivot_series, keys, groups)
     }
@@ -354,7 +356,7 @@ fn pivot_count_impl<'a, CA: TakeRandom>(
     ca: &CA,
     pivot_series: &'a (dyn SeriesTrait + 'a),
     keys: Vec<Series>,
-    groups: &[(u32, Vec<u32>)],
+    groups: &GroupTuples,
 ) -> Result<DataFrame> {
     let pivot_vec: Vec<_> = pivot_series.as_groupable_iter()?.collect();
     // create a hash map that will be filled with the results of the aggregation.
@@ -362,12 +364,11 @@ fn pivot_count_impl<'a, CA: TakeRandom>(
 
     // iterate over the groups that need to be aggregated
     // idxes are the indexes of the groups in the keys, pivot, and values columns
-    for (_first, idx) in groups {
+    for g in groups.iter() {
         // for every group do the aggregation by adding them to the vector belonging by that column
         // the columns are hashed with the pivot values
-        let mut columns_agg_map_group = create_column_values_map::<CA::Item>(&pivot_vec, idx.len());
-        for &i in idx {
-            let i = i as usize;
+        let mut columns_agg_map_group = create_column_values_map::<CA::Item>(&pivot_vec, g.len());
+        for i in g.iter() {
             let opt_pivot_val = unsafe { pivot_vec.get_unchecked(i) };
 
             if let Some(pivot_val) = opt_pivot_val {
@@ -402,7 +403,7 @@ impl ChunkPivot for BooleanChunked {
         &self,
         pivot_series: &'a (dyn SeriesTrait + 'a),
         keys: Vec<Series>,
-        groups: &[(u32, Vec<u32>)],
+        groups: &GroupTuples,
     ) -> Result<DataFrame> {
         pivot_count_impl(self, pivot_series, keys, groups)
     }
@@ -412,7 +413,7 @@ impl ChunkPivot for Utf8Chunked {
         &self,
         pivot_series: &'a (dyn SeriesTrait + 'a),
         keys: Vec<Series>,
-        groups: &[(u32, Vec<u32>)],
+        groups: &GroupTuples,
     ) -> Result<DataFrame> {
         pivot_count_impl(&self, pivot_series, keys, groups)
     }
@@ -423,7 +424,7 @@ impl ChunkPivot for CategoricalChunked {
         &self,
         pivot_series: &'a (dyn SeriesTrait + 'a),
         keys: Vec<Series>,
-        groups: &[(u32, Vec<u32>)],
+        groups: &GroupTuples,
     ) -> Result<DataFrame> {
         self.cast::<UInt32Type>()
             .unwrap()
@@ -643,4 +644,51 @@ mod test {
             &[Some(0), Some(0), Some(2)]
         );
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-u8",
+        feature = "dtype-u16",
+        feature = "dtype-i8",
+        feature = "dtype-i16",
+        feature = "dtype-u64",
+        feature = "dtype-date32"
+    ))]
+    fn test_pivot_key_dtypes() {
+        let df = df! {
+            "k" => [0, 1, 0, 1, 0],
+            "p" => [0, 0, 1, 1, 1],
+            "v" => [1, 2, 3, 4, 5]
+        }
+        .unwrap();
+
+        use DataType::*;
+        for dtype in &[UInt8, UInt16, Int8, Int16, UInt64, Boolean, Date32] {
+            let mut df = df.clone();
+            df.may_apply("k", |s| s.cast_with_datatype(dtype)).unwrap();
+            df.may_apply("p", |s| s.cast_with_datatype(dtype)).unwrap();
+            let pvt = df
+                .groupby_stable("k")
+                .unwrap()
+                .pivot("p", "v")
+                .sum()
+                .unwrap();
+            assert_eq!(pvt.column("k").unwrap().dtype(), dtype);
+
+            // the pivot values are formatted as the column names
+            let (zero, one) = match dtype {
+                Boolean => ("false", "true"),
+                Date32 => ("1970-01-01", "1970-01-02"),
+                _ => ("0", "1"),
+            };
+            assert_eq!(
+                Vec::from(pvt.column(zero).unwrap().i32().unwrap()),
+                &[Some(1), Some(2)]
+            );
+            assert_eq!(
+                Vec::from(pvt.column(one).unwrap().i32().unwrap()),
+                &[Some(8), Some(4)]
+            );
+        }
+    }
 }
@@ -0,0 +1,260 @@
+//! Sort-merge joins on keys that are sorted in ascending order. These don't need a hash table
+//! and the join tuples are produced in the order of the keys.
+use crate::prelude::*;
+use std::borrow::Cow;
+
+macro_rules! dispatch_numeric {
+    ($s:expr, $f:ident $(, $args:expr)*) => {{
+        match $s.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            DataType::UInt8 => $f($s.u8().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-u16")]
+            DataType::UInt16 => $f($s.u16().unwrap() $(, $args)*),
+            DataType::UInt32 => $f($s.u32().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-u64")]
+            DataType::UInt64 => $f($s.u64().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-i8")]
+            DataType::Int8 => $f($s.i8().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-i16")]
+            DataType::Int16 => $f($s.i16().unwrap() $(, $args)*),
+            DataType::Int32 => $f($s.i32().unwrap() $(, $args)*),
+            DataType::Int64 => $f($s.i64().unwrap() $(, $args)*),
+            DataType::Float32 => $f($s.f32().unwrap() $(, $args)*),
+            DataType::Float64 => $f($s.f64().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => $f($s.date32().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => $f($s.date64().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-time64-ns")]
+            DataType::Time64(TimeUnit::Nanosecond) => $f($s.time64_nanosecond().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-duration-ns")]
+            DataType::Duration(TimeUnit::Nanosecond) => $f($s.duration_nanosecond().unwrap() $(, $args)*),
+            #[cfg(feature = "dtype-duration-ms")]
+            DataType::Duration(TimeUnit::Millisecond) => $f($s.duration_millisecond().unwrap() $(, $args)*),
+            _ => None,
+        }
+    }};
+}
+
+/// Get the values of a join key if it has no null values and is sorted in ascending order.
+/// If `detect` is false, only keys with a sortedness flag are regarded as sorted.
+fn sorted_values<T>(ca: &ChunkedArray<T>, detect: bool) -> Option<Cow<[T::Native]>>
+where
+    T: PolarsNumericType,
+{
+    let sorted = if detect {
+        ca.detect_sorted()
+    } else {
+        ca.is_sorted()
+    };
+    if ca.null_count() > 0 || sorted != IsSorted::Ascending {
+        return None;
+    }
+    match ca.cont_slice() {
+        Ok(values) => Some(Cow::Borrowed(values)),
+        Err(_) => Some(Cow::Owned(ca.into_no_null_iter().collect())),
+    }
+}
+
+/// Get the end of the run of values equal to `values[start]`.
+fn run_end<T: PartialOrd>(values: &[T], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < values.len() && values[end] == values[start] {
+        end += 1
+    }
+    end
+}
+
+fn merge_join_tuples_inner<T: PartialOrd>(left: &[T], right: &[T]) -> Vec<(u32, u32)> {
+    let mut join_tuples = Vec::with_capacity(std::cmp::min(left.len(), right.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] < right[j] {
+            i += 1
+        } else if left[i] > right[j] {
+            j += 1
+        } else {
+            let i_end = run_end(left, i);
+            let j_end = run_end(right, j);
+            for idx_left in i..i_end {
+                join_tuples.extend((j..j_end).map(|idx_right| (idx_left as u32, idx_right as u32)))
+            }
+            i = i_end;
+            j = j_end;
+        }
+    }
+    join_tuples
+}
+
+fn merge_join_tuples_left<T: PartialOrd>(left: &[T], right: &[T]) -> Vec<(u32, Option<u32>)> {
+    let mut join_tuples = Vec::with_capacity(left.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() {
+        while j < right.len() && right[j] < left[i] {
+            j += 1
+        }
+        if j < right.len() && right[j] == left[i] {
+            let i_end = run_end(left, i);
+            let j_end = run_end(right, j);
+            for idx_left in i..i_end {
+                join_tuples
+                    .extend((j..j_end).map(|idx_right| (idx_left as u32, Some(idx_right as u32))))
+            }
+            i = i_end;
+            j = j_end;
+        } else {
+            join_tuples.push((i as u32, None));
+            i += 1;
+        }
+    }
+    join_tuples
+}
+
+fn merge_join_tuples_outer<T: PartialOrd>(
+    left: &[T],
+    right: &[T],
+) -> Vec<(Option<u32>, Option<u32>)> {
+    let mut join_tuples = Vec::with_capacity(std::cmp::max(left.len(), right.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        if j == right.len() || (i < left.len() && left[i] < right[j]) {
+            join_tuples.push((Some(i as u32), None));
+            i += 1;
+        } else if i == left.len() || right[j] < left[i] {
+            join_tuples.push((None, Some(j as u32)));
+            j += 1;
+        } else {
+            let i_end = run_end(left, i);
+            let j_end = run_end(right, j);
+            for idx_left in i..i_end {
+                join_tuples.extend(
+                    (j..j_end).map(|idx_right| (Some(idx_left as u32), Some(idx_right as u32))),
+                )
+            }
+            i = i_end;
+            j = j_end;
+        }
+    }
+    join_tuples
+}
+
+fn merge_join_inner_ca<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &Series,
+    detect: bool,
+) -> Option<Vec<(u32, u32)>> {
+    let right = right.unpack::<T>().ok()?;
+    Some(merge_join_tuples_inner(
+        &sorted_values(left, detect)?,
+        &sorted_values(right, detect)?,
+    ))
+}
+
+fn merge_join_left_ca<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &Series,
+    detect: bool,
+) -> Option<Vec<(u32, Option<u32>)>> {
+    let right = right.unpack::<T>().ok()?;
+    Some(merge_join_tuples_left(
+        &sorted_values(left, detect)?,
+        &sorted_values(right, detect)?,
+    ))
+}
+
+fn merge_join_outer_ca<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &Series,
+    detect: bool,
+) -> Option<Vec<(Option<u32>, Option<u32>)>> {
+    let right = right.unpack::<T>().ok()?;
+    Some(merge_join_tuples_outer(
+        &sorted_values(left, detect)?,
+        &sorted_values(right, detect)?,
+    ))
+}
+
+/// Get the inner join tuples with a sort-merge join. Returns `None` if the keys are not numeric
+/// keys of the same type that are sorted in ascending order without null values. If `detect` is
+/// false, the keys should have a sortedness flag, otherwise they are scanned.
+pub(crate) fn merge_join_inner(
+    s_left: &Series,
+    s_right: &Series,
+    detect: bool,
+) -> Option<Vec<(u32, u32)>> {
+    dispatch_numeric!(s_left, merge_join_inner_ca, s_right, detect)
+}
+
+/// Get the left join tuples with a sort-merge join. See [merge_join_inner].
+pub(crate) fn merge_join_left(
+    s_left: &Series,
+    s_right: &Series,
+    detect: bool,
+) -> Option<Vec<(u32, Option<u32>)>> {
+    dispatch_numeric!(s_left, merge_join_left_ca, s_right, detect)
+}
+
+/// Get the outer join tuples with a sort-merge join. See [merge_join_inner].
+pub(crate) fn merge_join_outer(
+    s_left: &Series,
+    s_right: &Series,
+    detect: bool,
+) -> Option<Vec<(Option<u32>, Option<u32>)>> {
+    dispatch_numeric!(s_left, merge_join_outer_ca, s_right, detect)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_join_tuples() {
+        let left = [1, 2, 2, 4, 6];
+        let right = [0, 2, 2, 4, 5];
+        assert_eq!(
+            merge_join_tuples_inner(&left, &right),
+            &[(1, 1), (1, 2), (2, 1), (2, 2), (3, 3)]
+        );
+        assert_eq!(
+            merge_join_tuples_left(&left, &right),
+            &[
+                (0, None),
+                (1, Some(1)),
+                (1, Some(2)),
+                (2, Some(1)),
+                (2, Some(2)),
+                (3, Some(3)),
+                (4, None)
+            ]
+        );
+        assert_eq!(
+            merge_join_tuples_outer(&left, &right),
+            &[
+                (None, Some(0)),
+                (Some(0), None),
+                (Some(1), Some(1)),
+                (Some(1), Some(2)),
+                (Some(2), Some(1)),
+                (Some(2), Some(2)),
+                (Some(3), Some(3)),
+                (None, Some(4)),
+                (Some(4), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_join_requires_sorted_keys() {
+        let sorted = Series::new("a", &[1, 2, 3]);
+        let unsorted = Series::new("a", &[1, 3, 2]);
+        let with_nulls = Series::new("a", &[None, Some(1), Some(2)]);
+        let floats = Series::new("a", &[1.0, 2.0, 3.0]);
+        assert!(merge_join_inner(&sorted, &sorted, true).is_some());
+        // without a flag the keys are only sorted if they are scanned
+        assert!(merge_join_inner(&sorted, &sorted, false).is_none());
+        assert!(merge_join_inner(&sorted, &unsorted, true).is_none());
+        assert!(merge_join_inner(&with_nulls, &sorted, true).is_none());
+        assert!(merge_join_inner(&sorted, &floats, true).is_none());
+        assert!(merge_join_inner(&floats, &Series::new("a", &[1.0, f64::NAN]), true).is_none());
+    }
+}
@@ -1,5 +1,7 @@
+mod merge_join;
 mod multiple_keys;
 
+use crate::frame::hash_join::merge_join::{merge_join_inner, merge_join_left, merge_join_outer};
 use crate::frame::hash_join::multiple_keys::{
     inner_join_multiple_keys, left_join_multiple_keys, outer_join_multiple_keys,
 };
@@ -16,7 +18,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashSet;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 use std::ops::Deref;
 use unsafe_unwrap::UnsafeUnwrap;
@@ -46,6 +48,99 @@ pub enum JoinType {
     Outer,
 }
 
+/// The uniqueness of the join keys that is checked before joining. A failed check returns an
+/// error instead of silently multiplying the rows of the result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinValidation {
+    /// No check is done.
+    ManyToMany,
+    /// The join keys of the right DataFrame should be unique.
+    ManyToOne,
+    /// The join keys of the left DataFrame should be unique.
+    OneToMany,
+    /// The join keys of both DataFrames should be unique.
+    OneToOne,
+}
+
+impl JoinValidation {
+    fn unique_left(&self) -> bool {
+        matches!(self, JoinValidation::OneToMany | JoinValidation::OneToOne)
+    }
+
+    fn unique_right(&self) -> bool {
+        matches!(self, JoinValidation::ManyToOne | JoinValidation::OneToOne)
+    }
+}
+
+impl Default for JoinValidation {
+    fn default() -> Self {
+        JoinValidation::ManyToMany
+    }
+}
+
+impl Display for JoinValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            JoinValidation::ManyToMany => "m:m",
+            JoinValidation::ManyToOne => "m:1",
+            JoinValidation::OneToMany => "1:m",
+            JoinValidation::OneToOne => "1:1",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// The algorithm that is used to find the matching rows of a join.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinStrategy {
+    /// Use a sort-merge join if the keys of both DataFrames are known to be sorted in ascending
+    /// order (see [IsSorted](crate::chunked_array::IsSorted)), otherwise use a hash join.
+    Auto,
+    /// Always use a hash join.
+    Hash,
+    /// Use a sort-merge join. This requires a single numeric or date key that is sorted in
+    /// ascending order and has no null values in both DataFrames, otherwise an error is returned.
+    /// Null keys are allowed if `join_nulls` is false, because they are removed before joining.
+    SortMerge,
+}
+
+impl Default for JoinStrategy {
+    fn default() -> Self {
+        JoinStrategy::Auto
+    }
+}
+
+/// Options of a join.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JoinOptions {
+    /// Allow the lazy engine to compute the two inputs of the join in parallel.
+    pub allow_parallel: bool,
+    /// Compute the two inputs in parallel, even if they come from the same source.
+    /// Only used by the lazy engine.
+    pub force_parallel: bool,
+    /// Appended to the names of the right columns that also occur in the left DataFrame.
+    pub suffix: String,
+    /// The uniqueness of the join keys that is checked before joining.
+    pub validate: JoinValidation,
+    /// Whether null keys match each other.
+    pub join_nulls: bool,
+    /// The algorithm that is used to join.
+    pub strategy: JoinStrategy,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        JoinOptions {
+            allow_parallel: true,
+            force_parallel: false,
+            suffix: "_right".into(),
+            validate: JoinValidation::ManyToMany,
+            join_nulls: true,
+            strategy: JoinStrategy::Auto,
+        }
+    }
+}
+
 unsafe fn get_hash_tbl_threaded_join<T, H>(
     h: u64,
     hash_tables: &[HashMap<T, Vec<u32>, H>],
@@ -458,14 +553,18 @@ impl_float_hash_join!(Float64Type, Float64Chunked);
 
 impl HashJoin<ListType> for ListChunked {}
 impl HashJoin<CategoricalType> for CategoricalChunked {
+    // the codes of `other` are aligned to the categories of `self` before they are compared
     fn hash_join_inner(&self, other: &CategoricalChunked) -> Vec<(u32, u32)> {
-        self.deref().hash_join_inner(&other.cast().unwrap())
+        let (other, _) = self.align_categories(other);
+        self.deref().hash_join_inner(other.deref())
     }
     fn hash_join_left(&self, other: &CategoricalChunked) -> Vec<(u32, Option<u32>)> {
-        self.deref().hash_join_left(&other.cast().unwrap())
+        let (other, _) = self.align_categories(other);
+        self.deref().hash_join_left(other.deref())
     }
     fn hash_join_outer(&self, other: &CategoricalChunked) -> Vec<(Option<u32>, Option<u32>)> {
-        self.deref().hash_join_outer(&other.cast().unwrap())
+        let (other, _) = self.align_categories(other);
+        self.deref().hash_join_outer(other.deref())
     }
 }
 
@@ -648,90 +747,99 @@ impl HashJoin<BooleanType> for BooleanChunked {
     }
 }
 
-impl HashJoin<Utf8Type> for Utf8Chunked {
-    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(u32, u32)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+// Hash joins on the (optional) values of arrays that can be iterated by reference.
+macro_rules! impl_hash_join_iter {
+    ($ca:ty, $type:ty) => {
+        impl HashJoin<$type> for $ca {
+            fn hash_join_inner(&self, other: &$ca) -> Vec<(u32, u32)> {
+                let (a, b, swap) = det_hash_prone_order!(self, other);
 
-        let n_threads = n_join_threads();
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+                let n_threads = n_join_threads();
+                let splitted_a = split_ca(a, n_threads).unwrap();
+                let splitted_b = split_ca(b, n_threads).unwrap();
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => {
+                        let iters_a = splitted_a
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        let iters_b = splitted_b
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                    }
+                    _ => {
+                        let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                        let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                        hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
+                    }
+                }
             }
-        }
-    }
 
-    fn hash_join_left(&self, other: &Utf8Chunked) -> Vec<(u32, Option<u32>)> {
-        let n_threads = n_join_threads();
+            fn hash_join_left(&self, other: &$ca) -> Vec<(u32, Option<u32>)> {
+                let n_threads = n_join_threads();
 
-        let a = self;
-        let b = other;
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+                let a = self;
+                let b = other;
+                let splitted_a = split_ca(a, n_threads).unwrap();
+                let splitted_b = split_ca(b, n_threads).unwrap();
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => {
+                        let iters_a = splitted_a
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        let iters_b = splitted_b
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        hash_join_tuples_left_threaded(iters_a, iters_b)
+                    }
+                    _ => {
+                        let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                        let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                        hash_join_tuples_left_threaded(iters_a, iters_b)
+                    }
+                }
             }
-        }
-    }
 
-    fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<u32>, Option<u32>)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+            fn hash_join_outer(&self, other: &$ca) -> Vec<(Option<u32>, Option<u32>)> {
+                let (a, b, swap) = det_hash_prone_order!(self, other);
 
-        let n_threads = n_join_threads();
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+                let n_threads = n_join_threads();
+                let splitted_a = split_ca(a, n_threads).unwrap();
+                let splitted_b = split_ca(b, n_threads).unwrap();
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                match (a.null_count(), b.null_count()) {
+                    (0, 0) => {
+                        let iters_a = splitted_a
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        let iters_b = splitted_b
+                            .iter()
+                            .map(|ca| ca.into_no_null_iter())
+                            .collect_vec();
+                        hash_join_tuples_outer(iters_a, iters_b, swap)
+                    }
+                    _ => {
+                        let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
+                        let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
+                        hash_join_tuples_outer(iters_a, iters_b, swap)
+                    }
+                }
             }
         }
-    }
+    };
 }
 
+impl_hash_join_iter!(Utf8Chunked, Utf8Type);
+#[cfg(feature = "dtype-binary")]
+impl_hash_join_iter!(BinaryChunked, BinaryType);
+
 pub trait ZipOuterJoinColumn {
     fn zip_outer_join_column(
         &self,
@@ -816,9 +924,284 @@ macro_rules! impl_zip_outer_join {
 impl_zip_outer_join!(BooleanChunked);
 impl_zip_outer_join!(Utf8Chunked);
 
+#[cfg(feature = "dtype-binary")]
+impl ZipOuterJoinColumn for BinaryChunked {
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+    ) -> Series {
+        let right_ca = self.unpack_series_matching_type(right_column).unwrap();
+
+        opt_join_tuples
+            .iter()
+            .map(|(opt_left_idx, opt_right_idx)| match opt_left_idx {
+                Some(left_idx) => self.get(*left_idx as usize),
+                None => unsafe {
+                    let right_idx = opt_right_idx.unsafe_unwrap();
+                    right_ca.get(right_idx as usize)
+                },
+            })
+            .collect::<BinaryChunked>()
+            .into_series()
+    }
+}
+
+/// Get a mask of the rows that have a null value in any of the join keys, or `None` if none
+/// of the keys contain null values.
+fn null_keys(keys: &[Series]) -> Option<Vec<bool>> {
+    if keys.iter().all(|s| s.null_count() == 0) {
+        return None;
+    }
+    let mut mask = vec![false; keys[0].len()];
+    for s in keys {
+        for (m, is_null) in mask.iter_mut().zip(s.is_null().into_no_null_iter()) {
+            *m |= is_null
+        }
+    }
+    Some(mask)
+}
+
+/// Remove the rows that have a null value in any of the join keys. Returns the remaining keys
+/// and, if any rows were removed, the original row index of every remaining row.
+fn drop_null_keys(keys: &[Series]) -> Result<(Vec<Series>, Option<Vec<u32>>)> {
+    match null_keys(keys) {
+        None => Ok((keys.to_vec(), None)),
+        Some(mask) => {
+            let valid: BooleanChunked = mask.iter().map(|is_null| !*is_null).collect();
+            let keys = keys
+                .iter()
+                .map(|s| s.filter(&valid))
+                .collect::<Result<Vec<_>>>()?;
+            let idx = mask
+                .iter()
+                .enumerate()
+                .filter(|(_, is_null)| !**is_null)
+                .map(|(idx, _)| idx as u32)
+                .collect();
+            Ok((keys, Some(idx)))
+        }
+    }
+}
+
+/// Map a row index of the keys without nulls back to the row index of the original keys.
+#[inline]
+fn original_idx(idx: &Option<Vec<u32>>, i: u32) -> u32 {
+    match idx {
+        Some(idx) => idx[i as usize],
+        None => i,
+    }
+}
+
+/// Compute the inner join tuples with `join`. If null keys don't match, the rows with null keys
+/// are removed before the keys are hashed.
+fn inner_join_tuples<F>(
+    left_keys: &[Series],
+    right_keys: &[Series],
+    options: &JoinOptions,
+    join: F,
+) -> Result<Vec<(u32, u32)>>
+where
+    F: FnOnce(&[Series], &[Series]) -> Result<Vec<(u32, u32)>>,
+{
+    if options.join_nulls {
+        return join(left_keys, right_keys);
+    }
+    let (left_keys, left_idx) = drop_null_keys(left_keys)?;
+    let (right_keys, right_idx) = drop_null_keys(right_keys)?;
+    let mut join_tuples = join(&left_keys, &right_keys)?;
+    if left_idx.is_some() || right_idx.is_some() {
+        join_tuples.iter_mut().for_each(|(left, right)| {
+            *left = original_idx(&left_idx, *left);
+            *right = original_idx(&right_idx, *right);
+        });
+    }
+    Ok(join_tuples)
+}
+
+/// Compute the left join tuples with `join`. If null keys don't match, the rows with null keys
+/// are removed before the keys are hashed and the left rows with a null key are added without a
+/// match.
+fn left_join_tuples<F>(
+    left_keys: &[Series],
+    right_keys: &[Series],
+    options: &JoinOptions,
+    join: F,
+) -> Result<Vec<(u32, Option<u32>)>>
+where
+    F: FnOnce(&[Series], &[Series]) -> Result<Vec<(u32, Option<u32>)>>,
+{
+    if options.join_nulls {
+        return join(left_keys, right_keys);
+    }
+    let height = left_keys[0].len();
+    let (left_keys, left_idx) = drop_null_keys(left_keys)?;
+    let (right_keys, right_idx) = drop_null_keys(right_keys)?;
+    let join_tuples = join(&left_keys, &right_keys)?;
+
+    let right = |right: Option<u32>| right.map(|right| original_idx(&right_idx, right));
+    match left_idx {
+        None => Ok(join_tuples
+            .into_iter()
+            .map(|(left, r)| (left, right(r)))
+            .collect()),
+        Some(left_idx) => {
+            // The tuples are ordered by the left rows and every left row without a null key has
+            // at least one tuple, so the gaps between the left rows are the rows with a null key.
+            let mut out = Vec::with_capacity(join_tuples.len() + height - left_idx.len());
+            let mut next = 0;
+            for (left, r) in join_tuples {
+                let left = left_idx[left as usize];
+                if left >= next {
+                    out.extend((next..left).map(|idx| (idx, None)));
+                    next = left + 1;
+                }
+                out.push((left, right(r)));
+            }
+            out.extend((next..height as u32).map(|idx| (idx, None)));
+            Ok(out)
+        }
+    }
+}
+
+/// Compute the outer join tuples with `join`. If null keys don't match, the rows with null keys
+/// are removed before the keys are hashed and every row with a null key is added once without a
+/// match.
+fn outer_join_tuples<F>(
+    left_keys: &[Series],
+    right_keys: &[Series],
+    options: &JoinOptions,
+    join: F,
+) -> Result<Vec<(Option<u32>, Option<u32>)>>
+where
+    F: FnOnce(&[Series], &[Series]) -> Result<Vec<(Option<u32>, Option<u32>)>>,
+{
+    if options.join_nulls {
+        return join(left_keys, right_keys);
+    }
+    let left_height = left_keys[0].len() as u32;
+    let right_height = right_keys[0].len() as u32;
+    let (left_keys, left_idx) = drop_null_keys(left_keys)?;
+    let (right_keys, right_idx) = drop_null_keys(right_keys)?;
+    let mut join_tuples = join(&left_keys, &right_keys)?;
+    if left_idx.is_none() && right_idx.is_none() {
+        return Ok(join_tuples);
+    }
+    join_tuples.iter_mut().for_each(|(left, right)| {
+        *left = left.map(|left| original_idx(&left_idx, left));
+        *right = right.map(|right| original_idx(&right_idx, right));
+    });
+
+    // the indexes that are not in `idx`, i.e. the rows with a null key
+    let null_rows = |idx: &[u32], height: u32| {
+        let mut is_null = vec![true; height as usize];
+        idx.iter().for_each(|i| is_null[*i as usize] = false);
+        (0..height).filter(move |i| is_null[*i as usize])
+    };
+    if let Some(idx) = &left_idx {
+        join_tuples.extend(null_rows(idx, left_height).map(|idx| (Some(idx), None)));
+    }
+    if let Some(idx) = &right_idx {
+        join_tuples.extend(null_rows(idx, right_height).map(|idx| (None, Some(idx))));
+    }
+    Ok(join_tuples)
+}
+
+/// The keys are hashed and compared by their own type, so both sides must have the same type.
+fn check_join_key_dtypes(left: &Series, right: &Series) -> Result<()> {
+    if left.dtype() == right.dtype() {
+        Ok(())
+    } else {
+        Err(PolarsError::DataTypeMisMatch(
+            format!(
+                "cannot join on key {} of dtype {:?} and key {} of dtype {:?}",
+                left.name(),
+                left.dtype(),
+                right.name(),
+                right.dtype()
+            )
+            .into(),
+        ))
+    }
+}
+
+/// Check that the join keys fulfill the cardinality required by `options.validate`.
+fn validate_join_keys(
+    left_keys: &[Series],
+    right_keys: &[Series],
+    options: &JoinOptions,
+) -> Result<()> {
+    let check = |keys: &[Series], side: &str| -> Result<()> {
+        let mut keys = DataFrame::new_no_checks(keys.to_vec());
+        if !options.join_nulls {
+            // null keys don't match, so they may occur multiple times
+            if let Some(mask) = null_keys(keys.get_columns()) {
+                let mask: BooleanChunked = mask.into_iter().map(|is_null| !is_null).collect();
+                keys = keys.filter(&mask)?;
+            }
+        }
+        if keys
+            .is_duplicated()?
+            .into_iter()
+            .any(|dup| dup == Some(true))
+        {
+            Err(PolarsError::ValueError(
+                format!(
+                    "join validation '{}' failed: the join keys of the {} DataFrame are not unique",
+                    options.validate, side
+                )
+                .into(),
+            ))
+        } else {
+            Ok(())
+        }
+    };
+
+    if options.validate.unique_left() {
+        check(left_keys, "left")?;
+    }
+    if options.validate.unique_right() {
+        check(right_keys, "right")?;
+    }
+    Ok(())
+}
+
+/// Find the join tuples with a sort-merge join if `options.strategy` allows it and the keys
+/// are eligible. Returns `None` if a hash join should be used instead.
+fn try_merge_join<T, F>(
+    s_left: &Series,
+    s_right: &Series,
+    options: &JoinOptions,
+    merge_join: F,
+) -> Result<Option<T>>
+where
+    F: Fn(&Series, &Series, bool) -> Option<T>,
+{
+    match options.strategy {
+        JoinStrategy::Hash => Ok(None),
+        JoinStrategy::Auto => Ok(merge_join(s_left, s_right, false)),
+        JoinStrategy::SortMerge => merge_join(s_left, s_right, true).map(Some).ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!(
+                    "cannot sort-merge join on keys '{}' and '{}': the keys should be numeric or date \
+                     keys of the same type, sorted in ascending order and without null values",
+                    s_left.name(),
+                    s_right.name()
+                )
+                .into(),
+            )
+        }),
+    }
+}
+
 impl DataFrame {
     /// Utility method to finish a join.
-    fn finish_join(&self, mut df_left: DataFrame, mut df_right: DataFrame) -> Result<DataFrame> {
+    fn finish_join(
+        &self,
+        mut df_left: DataFrame,
+        mut df_right: DataFrame,
+        suffix: &str,
+    ) -> Result<DataFrame> {
         let mut left_names = HashSet::with_capacity_and_hasher(df_left.width(), RandomState::new());
 
         df_left.columns.iter().for_each(|series| {
@@ -834,7 +1217,7 @@ impl DataFrame {
         });
 
         for name in rename_strs {
-            df_right.rename(&name, &format!("{}_right", name))?;
+            df_right.rename(&name, &format!("{}{}", name, suffix))?;
         }
 
         df_left.hstack_mut(&df_right.columns)?;
@@ -852,28 +1235,72 @@ impl DataFrame {
     }
 
     /// Generic join method. Can be used to join on multiple columns.
+    ///
+    /// Uses the [default JoinOptions](JoinOptions::default), see
+    /// [join_with_options](DataFrame::join_with_options) to set them.
     pub fn join<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
         &self,
         other: &DataFrame,
         left_on: S1,
         right_on: S2,
         how: JoinType,
+    ) -> Result<DataFrame> {
+        self.join_with_options(other, left_on, right_on, how, JoinOptions::default())
+    }
+
+    /// Generic join method with the given [JoinOptions]. Can be used to join on multiple columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn join_lookup(df: &DataFrame, lookup: &DataFrame) -> Result<DataFrame> {
+    ///     let options = JoinOptions {
+    ///         suffix: "_lookup".into(),
+    ///         validate: JoinValidation::ManyToOne,
+    ///         ..Default::default()
+    ///     };
+    ///     df.join_with_options(lookup, "key", "key", JoinType::Left, options)
+    /// }
+    /// ```
+    pub fn join_with_options<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        how: JoinType,
+        options: JoinOptions,
     ) -> Result<DataFrame> {
         let selected_left = self.select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
         assert_eq!(selected_right.len(), selected_left.len());
+        // categorical keys are compared by their codes, so they must share the categories
+        let selected_right = selected_left
+            .iter()
+            .zip(selected_right)
+            .map(|(left, right)| match (left.dtype(), right.dtype()) {
+                (DataType::Categorical, DataType::Categorical) => {
+                    let (right, _) = left
+                        .categorical()
+                        .unwrap()
+                        .align_categories(right.categorical().unwrap());
+                    right.into_series()
+                }
+                _ => right,
+            })
+            .collect::<Vec<_>>();
+        for (left, right) in selected_left.iter().zip(&selected_right) {
+            check_join_key_dtypes(left, right)?;
+        }
+        validate_join_keys(&selected_left, &selected_right, &options)?;
 
         if selected_left.len() == 1 {
+            let s_left = &selected_left[0];
+            let s_right = &selected_right[0];
             return match how {
-                JoinType::Inner => {
-                    self.inner_join(other, selected_left[0].name(), selected_right[0].name())
-                }
-                JoinType::Left => {
-                    self.left_join(other, selected_left[0].name(), selected_right[0].name())
-                }
-                JoinType::Outer => {
-                    self.outer_join(other, selected_left[0].name(), selected_right[0].name())
-                }
+                JoinType::Inner => self.inner_join_from_series(other, s_left, s_right, &options),
+                JoinType::Left => self.left_join_from_series(other, s_left, s_right, &options),
+                JoinType::Outer => self.outer_join_from_series(other, s_left, s_right, &options),
             };
         }
 
@@ -894,14 +1321,23 @@ impl DataFrame {
             }
         }
 
+        if let JoinStrategy::SortMerge = options.strategy {
+            return Err(PolarsError::InvalidOperation(
+                "a sort-merge join is only supported on a single key column".into(),
+            ));
+        }
+
         // This is still single threaded and can create very large keys that are inserted in the
         // hashmap. TODO: implement same hashing technique as in grouping.
         match how {
             JoinType::Inner => {
-                let left = DataFrame::new_no_checks(selected_left);
-                let right = DataFrame::new_no_checks(selected_right.clone());
-                let (left, right, swap) = det_hash_prone_order!(left, right);
-                let join_tuples = inner_join_multiple_keys(&left, &right, swap);
+                let join_tuples =
+                    inner_join_tuples(&selected_left, &selected_right, &options, |left, right| {
+                        let left = DataFrame::new_no_checks(left.to_vec());
+                        let right = DataFrame::new_no_checks(right.to_vec());
+                        let (left, right, swap) = det_hash_prone_order!(left, right);
+                        Ok(inner_join_multiple_keys(&left, &right, swap))
+                    })?;
 
                 let (df_left, df_right) = POOL.join(
                     || self.create_left_df(&join_tuples, false),
@@ -912,12 +1348,15 @@ impl DataFrame {
                         )
                     },
                 );
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, &options.suffix)
             }
             JoinType::Left => {
-                let left = DataFrame::new_no_checks(selected_left);
-                let right = DataFrame::new_no_checks(selected_right.clone());
-                let join_tuples = left_join_multiple_keys(&left, &right);
+                let join_tuples =
+                    left_join_tuples(&selected_left, &selected_right, &options, |left, right| {
+                        let left = DataFrame::new_no_checks(left.to_vec());
+                        let right = DataFrame::new_no_checks(right.to_vec());
+                        Ok(left_join_multiple_keys(&left, &right))
+                    })?;
 
                 let (df_left, df_right) = POOL.join(
                     || self.create_left_df(&join_tuples, true),
@@ -930,14 +1369,16 @@ impl DataFrame {
                         )
                     },
                 );
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, &options.suffix)
             }
             JoinType::Outer => {
-                let left = DataFrame::new_no_checks(selected_left.clone());
-                let right = DataFrame::new_no_checks(selected_right.clone());
-
-                let (left, right, swap) = det_hash_prone_order!(left, right);
-                let opt_join_tuples = outer_join_multiple_keys(&left, &right, swap);
+                let opt_join_tuples =
+                    outer_join_tuples(&selected_left, &selected_right, &options, |left, right| {
+                        let left = DataFrame::new_no_checks(left.to_vec());
+                        let right = DataFrame::new_no_checks(right.to_vec());
+                        let (left, right, swap) = det_hash_prone_order!(left, right);
+                        Ok(outer_join_multiple_keys(&left, &right, swap))
+                    })?;
 
                 // Take the left and right dataframes by join tuples
                 let (mut df_left, df_right) = POOL.join(
@@ -961,7 +1402,7 @@ impl DataFrame {
                     s.rename(s_left.name());
                     df_left.hstack_mut(&[s])?;
                 }
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, &options.suffix)
             }
         }
     }
@@ -984,7 +1425,8 @@ impl DataFrame {
     ) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.inner_join_from_series(other, s_left, s_right)
+        check_join_key_dtypes(s_left, s_right)?;
+        self.inner_join_from_series(other, s_left, s_right, &JoinOptions::default())
     }
 
     pub(crate) fn inner_join_from_series(
@@ -992,8 +1434,22 @@ impl DataFrame {
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        options: &JoinOptions,
     ) -> Result<DataFrame> {
-        let join_tuples = s_left.hash_join_inner(s_right);
+        let join_tuples = inner_join_tuples(
+            std::slice::from_ref(s_left),
+            std::slice::from_ref(s_right),
+            options,
+            |left, right| {
+                let (s_left, s_right) = (&left[0], &right[0]);
+                Ok(
+                    match try_merge_join(s_left, s_right, options, merge_join_inner)? {
+                        Some(join_tuples) => join_tuples,
+                        None => s_left.hash_join_inner(s_right),
+                    },
+                )
+            },
+        )?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&join_tuples, false),
@@ -1004,7 +1460,7 @@ impl DataFrame {
                     .take_iter_unchecked(join_tuples.iter().map(|(_left, right)| *right as usize))
             },
         );
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, &options.suffix)
     }
 
     /// Perform a left join on two DataFrames
@@ -1019,7 +1475,8 @@ impl DataFrame {
     pub fn left_join(&self, other: &DataFrame, left_on: &str, right_on: &str) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.left_join_from_series(other, s_left, s_right)
+        check_join_key_dtypes(s_left, s_right)?;
+        self.left_join_from_series(other, s_left, s_right, &JoinOptions::default())
     }
 
     pub(crate) fn left_join_from_series(
@@ -1027,8 +1484,22 @@ impl DataFrame {
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        options: &JoinOptions,
     ) -> Result<DataFrame> {
-        let opt_join_tuples = s_left.hash_join_left(s_right);
+        let opt_join_tuples = left_join_tuples(
+            std::slice::from_ref(s_left),
+            std::slice::from_ref(s_right),
+            options,
+            |left, right| {
+                let (s_left, s_right) = (&left[0], &right[0]);
+                Ok(
+                    match try_merge_join(s_left, s_right, options, merge_join_left)? {
+                        Some(join_tuples) => join_tuples,
+                        None => s_left.hash_join_left(s_right),
+                    },
+                )
+            },
+        )?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&opt_join_tuples, true),
@@ -1040,7 +1511,7 @@ impl DataFrame {
                 )
             },
         );
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, &options.suffix)
     }
 
     /// Perform an outer join on two DataFrames
@@ -1060,16 +1531,31 @@ impl DataFrame {
     ) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.outer_join_from_series(other, s_left, s_right)
+        check_join_key_dtypes(s_left, s_right)?;
+        self.outer_join_from_series(other, s_left, s_right, &JoinOptions::default())
     }
     pub(crate) fn outer_join_from_series(
         &self,
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        options: &JoinOptions,
     ) -> Result<DataFrame> {
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let opt_join_tuples = outer_join_tuples(
+            std::slice::from_ref(s_left),
+            std::slice::from_ref(s_right),
+            options,
+            |left, right| {
+                let (s_left, s_right) = (&left[0], &right[0]);
+                Ok(
+                    match try_merge_join(s_left, s_right, options, merge_join_outer)? {
+                        Some(join_tuples) => join_tuples,
+                        None => s_left.hash_join_outer(s_right),
+                    },
+                )
+            },
+        )?;
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
@@ -1091,7 +1577,7 @@ impl DataFrame {
         let mut s = s_left.zip_outer_join_column(s_right, &opt_join_tuples);
         s.rename(s_left.name());
         df_left.hstack_mut(&[s])?;
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, &options.suffix)
     }
 }
 
@@ -1325,6 +1811,240 @@ mod test {
         assert_eq!(Vec::from(ca), correct_ham);
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-u8", feature = "dtype-date32"))]
+    fn test_join_dates_and_small_ints() {
+        let (mut temp, mut rain) = create_frames();
+        temp.may_apply("days", |s| s.cast_with_datatype(&DataType::Date32))
+            .unwrap();
+        rain.may_apply("days", |s| s.cast_with_datatype(&DataType::Date32))
+            .unwrap();
+
+        let joined = temp.inner_join(&rain, "days", "days").unwrap();
+        assert_eq!(joined.height(), 3);
+        let joined = temp.left_join(&rain, "days", "days").unwrap();
+        assert_eq!(joined.column("rain_right").unwrap().null_count(), 1);
+        let joined = temp.outer_join(&rain, "days", "days").unwrap();
+        assert_eq!(joined.height(), 5);
+        assert_eq!(joined.column("days").unwrap().dtype(), &DataType::Date32);
+
+        let (mut temp, mut rain) = create_frames();
+        temp.may_apply("days", |s| s.cast::<UInt8Type>()).unwrap();
+        rain.may_apply("days", |s| s.cast::<UInt8Type>()).unwrap();
+        let joined = temp.outer_join(&rain, "days", "days").unwrap();
+        assert_eq!(joined.height(), 5);
+        assert_eq!(joined.column("days").unwrap().dtype(), &DataType::UInt8);
+
+        // keys of different types are not reinterpreted as each other
+        let (temp, _) = create_frames();
+        assert!(temp.inner_join(&rain, "days", "days").is_err());
+        assert!(temp.left_join(&rain, "days", "days").is_err());
+        assert!(temp.outer_join(&rain, "days", "days").is_err());
+        assert!(temp.join(&rain, "days", "days", JoinType::Inner).is_err());
+    }
+
+    #[test]
+    fn test_join_options() {
+        let left = df! {
+            "k" => [Some(1), Some(2), None, Some(2)],
+            "v" => [1, 2, 3, 4]
+        }
+        .unwrap();
+        let right = df! {
+            "k" => [Some(1), Some(2), None],
+            "v" => [10, 20, 30]
+        }
+        .unwrap();
+
+        let options = JoinOptions {
+            suffix: "_lookup".into(),
+            validate: JoinValidation::ManyToOne,
+            ..Default::default()
+        };
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Left, options.clone())
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("v_lookup").unwrap().i32().unwrap()),
+            &[Some(10), Some(20), Some(30), Some(20)]
+        );
+
+        // null keys don't match
+        let options = JoinOptions {
+            join_nulls: false,
+            ..options
+        };
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Left, options.clone())
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("v_lookup").unwrap().i32().unwrap()),
+            &[Some(10), Some(20), None, Some(20)]
+        );
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Inner, options.clone())
+            .unwrap();
+        assert_eq!(out.height(), 3);
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Outer, options.clone())
+            .unwrap();
+        assert_eq!(out.height(), 5);
+        assert_eq!(out.column("k").unwrap().null_count(), 2);
+
+        // the left keys are not unique
+        let options = JoinOptions {
+            validate: JoinValidation::OneToOne,
+            ..options
+        };
+        let err = left
+            .join_with_options(&right, "k", "k", JoinType::Left, options)
+            .unwrap_err();
+        assert!(format!("{}", err).contains("left"));
+        assert!(right
+            .join_with_options(
+                &left,
+                "k",
+                "k",
+                JoinType::Left,
+                JoinOptions {
+                    validate: JoinValidation::ManyToOne,
+                    ..Default::default()
+                }
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_join_without_null_matches() {
+        // many null keys on both sides; null keys are removed before the join
+        let n = 1000;
+        let left = df! {
+            "k" => (0..n as i32).map(|i| if i % 2 == 0 { None } else { Some(i % 3) }).collect::<Vec<_>>(),
+            "k2" => vec![1; n],
+            "v" => (0..n as i32).collect::<Vec<_>>()
+        }
+        .unwrap();
+        let right = df! {
+            "k" => [None, Some(1), None, Some(4)],
+            "k2" => [1, 1, 1, 1],
+            "w" => [1, 2, 3, 4]
+        }
+        .unwrap();
+        let options = JoinOptions {
+            join_nulls: false,
+            ..Default::default()
+        };
+        // the odd rows have a key of 0, 1 or 2, a third of them match
+        let n_matches = (0..n).filter(|i| i % 2 == 1 && i % 3 == 1).count();
+
+        for keys in [vec!["k"], vec!["k", "k2"]].iter() {
+            let out = left
+                .join_with_options(&right, keys, keys, JoinType::Inner, options.clone())
+                .unwrap();
+            assert_eq!(out.height(), n_matches);
+
+            let out = left
+                .join_with_options(&right, keys, keys, JoinType::Left, options.clone())
+                .unwrap();
+            assert_eq!(out.height(), n);
+            // the left rows keep their order
+            assert!(out
+                .column("v")
+                .unwrap()
+                .series_equal(left.column("v").unwrap()));
+            assert_eq!(out.column("w").unwrap().null_count(), n - n_matches);
+
+            let out = left
+                .join_with_options(&right, keys, keys, JoinType::Outer, options.clone())
+                .unwrap();
+            // the unmatched right rows are the two null keys and the key 4
+            assert_eq!(out.height(), n + 3);
+            assert_eq!(out.column("k").unwrap().null_count(), n / 2 + 2);
+        }
+    }
+
+    #[test]
+    fn test_sort_merge_join() {
+        let left = df! {
+            "k" => [1, 2, 2, 4, 6],
+            "v" => [1, 2, 3, 4, 5]
+        }
+        .unwrap();
+        let right = df! {
+            "k" => [0, 2, 2, 4, 5],
+            "w" => [10, 20, 30, 40, 50]
+        }
+        .unwrap();
+        let merge = JoinOptions {
+            strategy: JoinStrategy::SortMerge,
+            ..Default::default()
+        };
+        let hash = JoinOptions {
+            strategy: JoinStrategy::Hash,
+            ..Default::default()
+        };
+
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Inner, merge.clone())
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("w").unwrap().i32().unwrap()),
+            &[Some(20), Some(30), Some(20), Some(30), Some(40)]
+        );
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Left, merge.clone())
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("w").unwrap().i32().unwrap()),
+            &[None, Some(20), Some(30), Some(20), Some(30), Some(40), None]
+        );
+        let out = left
+            .join_with_options(&right, "k", "k", JoinType::Outer, merge.clone())
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("k").unwrap().i32().unwrap()),
+            &[
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(4),
+                Some(5),
+                Some(6)
+            ]
+        );
+        let hashed = left
+            .join_with_options(&right, "k", "k", JoinType::Outer, hash)
+            .unwrap();
+        assert_eq!(out.height(), hashed.height());
+        assert_eq!(
+            out.column("w").unwrap().sum::<i32>(),
+            hashed.column("w").unwrap().sum::<i32>()
+        );
+
+        // with sortedness flags the default strategy uses a sort-merge join
+        let left = left.sort("k", false).unwrap();
+        let right = right.sort("k", false).unwrap();
+        let out = left.join(&right, "k", "k", JoinType::Inner).unwrap();
+        assert_eq!(
+            Vec::from(out.column("w").unwrap().i32().unwrap()),
+            &[Some(20), Some(30), Some(20), Some(30), Some(40)]
+        );
+
+        // the right keys are not sorted
+        let right = right.sort("w", true).unwrap();
+        assert!(left
+            .join_with_options(&right, "k", "k", JoinType::Inner, merge.clone())
+            .is_err());
+        let out = left.join(&right, "k", "k", JoinType::Inner).unwrap();
+        assert_eq!(out.height(), 5);
+        assert!(left
+            .join_with_options(&right, &["k", "v"], &["k", "w"], JoinType::Inner, merge)
+            .is_err());
+    }
+
     #[test]
     fn empty_df_join() {
         let empty: Vec<String> = vec![];
@@ -14,8 +14,10 @@ use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::frame::select::Selection;
 use crate::prelude::*;
 use crate::utils::{accumulate_dataframes_horizontal, accumulate_dataframes_vertical, NoNull};
+use crate::vector_hasher::{check_hashable, df_rows_to_hashes, seeded_random_state};
 
 mod arithmetic;
+pub mod describe;
 pub mod explode;
 pub mod groupby;
 pub mod hash_join;
@@ -732,19 +734,109 @@ impl DataFrame {
 
     /// Sort DataFrame in place by a column.
     pub fn sort_in_place(&mut self, by_column: &str, reverse: bool) -> Result<&mut Self> {
-        let s = self.column(by_column)?;
-
-        let take = s.argsort(reverse);
-
-        self.columns = self.columns.par_iter().map(|s| s.take(&take)).collect();
+        *self = self.sort(by_column, reverse)?;
         Ok(self)
     }
 
     /// Return a sorted clone of this DataFrame.
+    ///
+    /// The sort is skipped if the column is already known to be sorted. The sorted column gets a
+    /// sortedness flag, see [IsSorted](crate::chunked_array::IsSorted). Categoricals with a
+    /// [Lexical](crate::chunked_array::categorical::CategoricalOrdering::Lexical) ordering don't
+    /// get the flag, because their physical codes are not sorted.
     pub fn sort(&self, by_column: &str, reverse: bool) -> Result<Self> {
-        let s = self.column(by_column)?;
+        let idx = self
+            .find_idx_by_name(by_column)
+            .ok_or_else(|| PolarsError::NotFound(by_column.into()))?;
+        let s = &self.columns[idx];
+        let sorted = if reverse {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        };
+        // the flag describes the physical values
+        let lexical = matches!(
+            s.categorical().map(|ca| ca.get_ordering()),
+            Ok(CategoricalOrdering::Lexical)
+        );
+        if !lexical && s.is_sorted() == sorted {
+            return Ok(self.clone());
+        }
 
         let take = s.argsort(reverse);
+        let mut df = self.take(&take);
+        if !lexical {
+            df.columns[idx].set_sorted(sorted);
+        }
+        Ok(df)
+    }
+
+    /// Return a clone of this DataFrame sorted by multiple columns. The rows are sorted by the
+    /// first column, rows with equal values by the second column, etc. `reverse` has a value for
+    /// every column.
+    pub fn sort_by_columns<S: AsRef<str>>(
+        &self,
+        by_columns: &[S],
+        reverse: &[bool],
+    ) -> Result<Self> {
+        if by_columns.len() != reverse.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the number of sort columns: {} does not match the number of reverse flags: {}",
+                    by_columns.len(),
+                    reverse.len()
+                )
+                .into(),
+            ));
+        }
+        match by_columns {
+            [] => return Err(PolarsError::NoData("no columns to sort by".into())),
+            [by_column] => return self.sort(by_column.as_ref(), reverse[0]),
+            _ => {}
+        }
+
+        // The dense rank of every row in the sort order of a column, so that the rows can be
+        // compared on all columns at once.
+        let ranks = by_columns
+            .iter()
+            .zip(reverse)
+            .map(|(name, &reverse)| {
+                let s = self.column(name.as_ref())?;
+                let mut ranks = vec![0u32; s.len()];
+                let mut rank = 0;
+                let mut prev = None;
+                for idx in s.argsort(reverse).into_no_null_iter() {
+                    let idx = idx as usize;
+                    if let Some(prev) = prev {
+                        if s.get(prev) != s.get(idx) {
+                            rank += 1;
+                        }
+                    }
+                    ranks[idx] = rank;
+                    prev = Some(idx);
+                }
+                Ok(ranks)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut take: Vec<u32> = (0..self.height() as u32).collect();
+        take.sort_by(|&a, &b| {
+            ranks
+                .iter()
+                .map(|ranks| ranks[a as usize].cmp(&ranks[b as usize]))
+                .find(|ord| ord != &std::cmp::Ordering::Equal)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(self.take(&UInt32Chunked::new_from_slice("", &take)))
+    }
+
+    /// Return the first `k` rows of this DataFrame sorted by a column.
+    ///
+    /// This is equal to `df.sort(by_column, reverse)?.head(Some(k))`, but only does a partial sort.
+    pub fn top_k(&self, k: usize, by_column: &str, reverse: bool) -> Result<Self> {
+        let s = self.column(by_column)?;
+
+        let take = s.argsort_top_k(k, reverse);
         Ok(self.take(&take))
     }
 
@@ -1437,15 +1529,11 @@ impl DataFrame {
             None => self.get_column_names(),
         };
         let gb = self.groupby(names)?;
-        let groups = gb.get_groups().iter().map(|v| v.0);
-
-        let df = if maintain_order {
-            let mut groups = groups.collect::<Vec<_>>();
+        let mut groups = gb.get_groups().iter_first().collect::<Vec<_>>();
+        if maintain_order {
             groups.sort_unstable();
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
-        } else {
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
-        };
+        }
+        let df = unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) };
 
         Ok(df)
     }
@@ -1464,6 +1552,28 @@ impl DataFrame {
         Ok(is_unique_helper(groups, self.height() as u32, false, true))
     }
 
+    /// Hash all the rows of the DataFrame to a single `u64` with a hasher seeded by `seed`.
+    ///
+    /// The hashes of the columns are combined in column order, so rows with equal values (and
+    /// nulls at the same positions) get the same hash. Like [Series::hash] the hashes are only
+    /// reproducible within the same build of polars.
+    ///
+    /// Returns an error if a column is of type list, struct or object.
+    pub fn hash_rows(&self, seed: u64) -> Result<UInt64Chunked> {
+        if self.columns.is_empty() {
+            return Err(PolarsError::NoData(
+                "cannot hash the rows of an empty DataFrame".into(),
+            ));
+        }
+        for s in &self.columns {
+            check_hashable(s)?;
+        }
+        let mut df = self.clone();
+        df.rechunk();
+        let (ca, _) = df_rows_to_hashes(&df, Some(seeded_random_state(seed)));
+        Ok(ca)
+    }
+
     /// Create a new DataFrame that shows the null counts per column.
     pub fn null_count(&self) -> Self {
         let cols = self
@@ -1693,6 +1803,44 @@ mod test {
         println!("{:?}", df);
     }
 
+    #[test]
+    fn test_sort_by_columns() {
+        let df = df! {
+            "a" => &[Some(2), Some(1), None, Some(2), Some(1)],
+            "b" => &["x", "y", "z", "z", "x"],
+            "c" => &[1, 2, 3, 4, 5]
+        }
+        .unwrap();
+        let out = df.sort_by_columns(&["a", "b"], &[false, true]).unwrap();
+        assert_eq!(
+            Vec::from(out.column("c").unwrap().i32().unwrap()),
+            &[Some(3), Some(2), Some(5), Some(4), Some(1)]
+        );
+        let out = df.sort_by_columns(&["b", "a"], &[false, true]).unwrap();
+        assert_eq!(
+            Vec::from(out.column("c").unwrap().i32().unwrap()),
+            &[Some(1), Some(5), Some(2), Some(4), Some(3)]
+        );
+        assert!(df.sort_by_columns(&["a", "b"], &[false]).is_err());
+        assert!(df.sort_by_columns::<&str>(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_top_k() {
+        let df = df! {
+            "a" => &[3, 1, 5, 2, 4],
+            "b" => &["c", "a", "e", "b", "d"]
+        }
+        .unwrap();
+        let top = df.top_k(2, "a", false).unwrap();
+        assert!(top.frame_equal(&df.sort("a", false).unwrap().head(Some(2))));
+        let top = df.top_k(3, "a", true).unwrap();
+        assert_eq!(
+            Vec::from(top.column("a").unwrap().i32().unwrap()),
+            &[Some(5), Some(4), Some(3)]
+        );
+    }
+
     #[test]
     fn slice() {
         let df = create_frame();
@@ -1778,6 +1926,68 @@ mod test {
         assert_eq!(df.n_chunks().unwrap(), 2)
     }
 
+    #[test]
+    fn test_sort_sets_sorted_flag() {
+        let df = df! {
+            "a" => [3, 1, 2],
+            "b" => ["c", "a", "b"]
+        }
+        .unwrap();
+        let sorted = df.sort("a", false).unwrap();
+        assert_eq!(sorted.column("a").unwrap().is_sorted(), IsSorted::Ascending);
+        assert_eq!(sorted.column("b").unwrap().is_sorted(), IsSorted::Not);
+        let sorted = sorted.sort("a", true).unwrap();
+        assert_eq!(
+            sorted.column("a").unwrap().is_sorted(),
+            IsSorted::Descending
+        );
+        assert_eq!(
+            Vec::from(sorted.column("b").unwrap().utf8().unwrap()),
+            &[Some("c"), Some("b"), Some("a")]
+        );
+
+        // the codes of a lexically sorted categorical are not sorted
+        let mut cat = Utf8Chunked::new_from_slice("cat", &["b", "c", "a"])
+            .cast::<CategoricalType>()
+            .unwrap();
+        cat.set_ordering(CategoricalOrdering::Lexical);
+        let cat_df = DataFrame::new(vec![cat.into_series()]).unwrap();
+        let sorted = cat_df.sort("cat", false).unwrap();
+        let out = sorted.column("cat").unwrap();
+        assert_eq!(out.is_sorted(), IsSorted::Not);
+        assert_eq!(
+            Vec::from(out.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+
+        let mut s = df.column("b").unwrap().clone();
+        assert_eq!(s.detect_sorted(), IsSorted::Not);
+        let mut s = Series::new("c", &[1.0, 2.0, 2.0]);
+        assert_eq!(s.detect_sorted(), IsSorted::Ascending);
+        assert_eq!(s.is_sorted(), IsSorted::Ascending);
+    }
+
+    #[test]
+    fn test_hash_rows() {
+        let mut df = df! {
+            "int" => [Some(1), Some(1), None, None],
+            "str" => ["a", "a", "b", "b"]
+        }
+        .unwrap();
+        df.vstack_mut(&df.slice(0, 2)).unwrap();
+        let h: Vec<_> = df.hash_rows(42).unwrap().into_no_null_iter().collect();
+        assert_eq!(h.len(), 6);
+        assert_eq!(h[0], h[1]);
+        assert_eq!(h[2], h[3]);
+        assert_eq!(h[0], h[4]);
+        assert_ne!(h[0], h[2]);
+        assert!(DataFrame::new_no_checks(vec![]).hash_rows(42).is_err());
+
+        let list = Series::new("list", &[Series::new("", &[1]), Series::new("", &[2])]);
+        let df = DataFrame::new(vec![Series::new("int", &[1, 2]), list]).unwrap();
+        assert!(df.hash_rows(42).is_err());
+    }
+
     #[test]
     fn test_h_agg() {
         let a = Series::new("a", &[1, 2, 6]);
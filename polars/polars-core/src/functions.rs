@@ -1,5 +1,7 @@
 use crate::prelude::*;
+use crate::utils::get_supertype;
 use num::{Float, NumCast};
+use std::convert::TryFrom;
 use std::ops::Div;
 
 // todo! make numerical stable from catastrophic cancellation
@@ -23,6 +25,147 @@ where
     Some(cov(a, b)? / (a.std()? * b.std()?))
 }
 
+/// How [concat] combines `DataFrame`s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConcatMode {
+    /// Stack the rows. All `DataFrame`s must have the same column names in the same order.
+    Vertical,
+    /// Place the columns side by side. Shorter `DataFrame`s are padded with nulls.
+    Horizontal,
+    /// Stack the rows on the union of the columns. Missing columns are filled with nulls.
+    Diagonal,
+}
+
+/// Determine the schema of the [concat] of `DataFrame`s with the given schemas.
+///
+/// Columns that occur in several inputs get the supertype of their data types.
+pub fn concat_schema(schemas: &[&Schema], mode: ConcatMode) -> Result<Schema> {
+    let mut fields: Vec<Field> = Vec::new();
+    match mode {
+        ConcatMode::Vertical => {
+            if let Some(first) = schemas.first() {
+                fields = first.fields().clone();
+            }
+            for schema in schemas.iter().skip(1) {
+                let names_match = schema.fields().len() == fields.len()
+                    && schema
+                        .fields()
+                        .iter()
+                        .zip(&fields)
+                        .all(|(a, b)| a.name() == b.name());
+                if !names_match {
+                    return Err(PolarsError::ShapeMisMatch(
+                        "cannot vertically concatenate DataFrames with different columns".into(),
+                    ));
+                }
+                for (field, other) in fields.iter_mut().zip(schema.fields()) {
+                    let dtype = get_supertype(field.data_type(), other.data_type())?;
+                    *field = Field::new(field.name(), dtype);
+                }
+            }
+        }
+        ConcatMode::Horizontal => {
+            for field in schemas.iter().flat_map(|schema| schema.fields()) {
+                if fields.iter().any(|f| f.name() == field.name()) {
+                    return Err(PolarsError::Duplicate(
+                        format!("column with name: '{}' occurs more than once", field.name())
+                            .into(),
+                    ));
+                }
+                fields.push(field.clone())
+            }
+        }
+        ConcatMode::Diagonal => {
+            for field in schemas.iter().flat_map(|schema| schema.fields()) {
+                match fields.iter_mut().find(|f| f.name() == field.name()) {
+                    Some(f) => {
+                        let dtype = get_supertype(f.data_type(), field.data_type())?;
+                        *f = Field::new(f.name(), dtype);
+                    }
+                    None => fields.push(field.clone()),
+                }
+            }
+        }
+    }
+    Ok(Schema::new(fields))
+}
+
+fn full_null(name: &str, len: usize, dtype: &DataType) -> Result<Series> {
+    match dtype {
+        // a categorical column needs a (empty) mapping
+        DataType::Categorical => Utf8Chunked::full_null(name, len)
+            .into_series()
+            .cast_with_datatype(dtype),
+        _ => Series::try_from((name, arrow::array::new_null_array(&dtype.to_arrow(), len))),
+    }
+}
+
+/// Concatenate `DataFrame`s vertically, horizontally or diagonally. See [ConcatMode].
+///
+/// The columns are cast to the supertype of their data types in the inputs.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_core::functions::{concat, ConcatMode};
+///
+/// fn example(january: DataFrame, february: DataFrame) -> Result<DataFrame> {
+///     // columns that only exist in one of the months are filled with nulls
+///     concat(&[january, february], ConcatMode::Diagonal)
+/// }
+/// ```
+pub fn concat(dfs: &[DataFrame], mode: ConcatMode) -> Result<DataFrame> {
+    if dfs.is_empty() {
+        return Err(PolarsError::NoData(
+            "cannot concatenate an empty list of DataFrames".into(),
+        ));
+    }
+    let schemas = dfs.iter().map(|df| df.schema()).collect::<Vec<_>>();
+    let schemas = schemas.iter().collect::<Vec<_>>();
+    let schema = concat_schema(&schemas, mode)?;
+
+    match mode {
+        ConcatMode::Horizontal => {
+            let height = dfs.iter().map(|df| df.height()).max().unwrap();
+            let columns = dfs
+                .iter()
+                .flat_map(|df| df.get_columns())
+                .map(|s| {
+                    let mut s = s.clone();
+                    if s.len() < height {
+                        s.append(&full_null(s.name(), height - s.len(), s.dtype())?)?;
+                    }
+                    Ok(s)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            DataFrame::new(columns)
+        }
+        ConcatMode::Vertical | ConcatMode::Diagonal => {
+            let mut out: Option<DataFrame> = None;
+            for df in dfs {
+                let columns = schema
+                    .fields()
+                    .iter()
+                    .map(|field| match df.column(field.name()) {
+                        Ok(s) if s.dtype() == field.data_type() => Ok(s.clone()),
+                        Ok(s) => s.cast_with_datatype(field.data_type()),
+                        Err(_) => full_null(field.name(), df.height(), field.data_type()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let df = DataFrame::new_no_checks(columns);
+                match &mut out {
+                    Some(acc) => {
+                        acc.vstack_mut(&df)?;
+                    }
+                    None => out = Some(df),
+                }
+            }
+            Ok(out.unwrap())
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -34,4 +177,43 @@ mod test {
         assert!((cov(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 0.5).abs() < 0.001);
         assert!((pearson_corr(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_concat() {
+        let a = df! {
+            "x" => &[1, 2],
+            "y" => &["a", "b"]
+        }
+        .unwrap();
+        let b = df! {
+            "x" => &[3.5],
+            "z" => &[true]
+        }
+        .unwrap();
+
+        let out = concat(&[a.clone(), b.clone()], ConcatMode::Diagonal).unwrap();
+        assert_eq!(out.get_column_names(), &["x", "y", "z"]);
+        assert_eq!(
+            Vec::from(out.column("x").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("y").unwrap().utf8().unwrap()),
+            &[Some("a"), Some("b"), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("z").unwrap().bool().unwrap()),
+            &[None, None, Some(true)]
+        );
+
+        let out = concat(&[a.clone(), b.clone()], ConcatMode::Horizontal).unwrap_err();
+        assert!(matches!(out, PolarsError::Duplicate(_)));
+        let out = concat(&[a.clone(), b.select("z").unwrap()], ConcatMode::Horizontal).unwrap();
+        assert_eq!(out.shape(), (2, 3));
+        assert_eq!(out.column("z").unwrap().null_count(), 1);
+
+        assert!(concat(&[a.clone(), b], ConcatMode::Vertical).is_err());
+        let out = concat(&[a.clone(), a], ConcatMode::Vertical).unwrap();
+        assert_eq!(out.shape(), (4, 2));
+    }
 }
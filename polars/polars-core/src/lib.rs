@@ -18,6 +18,7 @@ use ahash::AHashMap;
 use lazy_static::lazy_static;
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::cell::Cell;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Mutex, MutexGuard};
 
 // this is re-exported in utils for polars child crates
@@ -51,19 +52,69 @@ impl Default for StringCache {
 }
 
 thread_local! {pub(crate) static USE_STRING_CACHE: Cell<bool> = Cell::new(false)}
+/// Number of alive [StringCacheHolder]s. This is process-wide, so that a holder taken on the
+/// calling thread is also seen by the threads of the thread pool.
+static STRING_CACHE_HOLDERS: AtomicU32 = AtomicU32::new(0);
 lazy_static! {
     static ref L_STRING_CACHE: StringCache = Default::default();
 }
 
 pub(crate) use L_STRING_CACHE as STRING_CACHE;
 
+/// Turn the global string cache on or off.
+///
+/// Turning it off clears the cache, unless a [StringCacheHolder](struct.StringCacheHolder.html)
+/// is still alive.
 pub fn toggle_string_cache(toggle: bool) {
     USE_STRING_CACHE.with(|val| val.set(toggle));
-    if !toggle {
+    if !toggle && STRING_CACHE_HOLDERS.load(Ordering::SeqCst) == 0 {
         STRING_CACHE.clear()
     }
 }
 
 pub(crate) fn use_string_cache() -> bool {
-    USE_STRING_CACHE.with(|val| val.get())
+    USE_STRING_CACHE.with(|val| val.get()) || STRING_CACHE_HOLDERS.load(Ordering::SeqCst) > 0
+}
+
+/// Enables the global string cache as long as it is alive.
+///
+/// Holders can be nested. When the last holder is dropped, the cache is cleared, unless it
+/// was turned on with [toggle_string_cache](fn.toggle_string_cache.html).
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_core::StringCacheHolder;
+///
+/// let _holder = StringCacheHolder::hold();
+/// let a = Series::new("a", &["a", "b"]).cast::<CategoricalType>().unwrap();
+/// let b = Series::new("b", &["b", "c"]).cast::<CategoricalType>().unwrap();
+/// // "b" has the same code in both Series
+/// ```
+pub struct StringCacheHolder {
+    // don't allow construction without `hold`
+    _private: (),
+}
+
+impl StringCacheHolder {
+    pub fn hold() -> Self {
+        STRING_CACHE_HOLDERS.fetch_add(1, Ordering::SeqCst);
+        StringCacheHolder { _private: () }
+    }
+}
+
+impl Default for StringCacheHolder {
+    fn default() -> Self {
+        Self::hold()
+    }
+}
+
+impl Drop for StringCacheHolder {
+    fn drop(&mut self) {
+        let holders = STRING_CACHE_HOLDERS.fetch_sub(1, Ordering::SeqCst) - 1;
+        if holders == 0 && !USE_STRING_CACHE.with(|val| val.get()) {
+            STRING_CACHE.clear()
+        }
+    }
 }
@@ -8,23 +8,32 @@ pub use crate::{
             ListPrimitiveChunkedBuilder, ListUtf8ChunkedBuilder, NewChunkedArray,
             PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
         },
+        categorical::CategoricalOrdering,
         comparison::NumComp,
+        decimal::DecimalChunkedBuilder,
         iterator::{IntoNoNullIterator, PolarsIterator},
         ops::{
             aggregate::*,
             chunkops::ChunkOps,
             take::{AsTakeIndex, IntoTakeRandom, NumTakeRandomChunked, NumTakeRandomCont},
-            window::InitFold,
+            window::{InitFold, RollingAgg},
             *,
         },
-        ChunkedArray, NoNull,
+        ChunkedArray, IsSorted, NoNull,
     },
     datatypes,
     datatypes::*,
     error::{PolarsError, Result},
-    frame::{groupby::VecHash, hash_join::JoinType, DataFrame},
+    frame::{
+        describe::describe_schema,
+        explode::MeltArgs,
+        groupby::VecHash,
+        hash_join::{JoinOptions, JoinStrategy, JoinType, JoinValidation},
+        DataFrame,
+    },
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
+        ops::RankMethod,
         IntoSeries, NamedFrom, Series, SeriesTrait,
     },
     testing::*,
@@ -34,5 +43,9 @@ pub(crate) use polars_arrow::array::*;
 pub use polars_arrow::vec::AlignedVec;
 pub use std::sync::Arc;
 
+#[cfg(feature = "dtype-binary")]
+pub use crate::chunked_array::binary::BinaryChunkedBuilder;
+#[cfg(feature = "dtype-struct")]
+pub use crate::chunked_array::struct_::StructChunked;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
@@ -35,6 +35,10 @@ macro_rules! impl_compare {
                 .unwrap()
                 .$method($rhs.duration_millisecond().unwrap()),
             DataType::List(_) => $self.list().unwrap().$method($rhs.list().unwrap()),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => $self.decimal().unwrap().$method($rhs.decimal().unwrap()),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => $self.binary().unwrap().$method($rhs.binary().unwrap()),
             _ => unimplemented!(),
         }
     }};
@@ -0,0 +1,255 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::chunked_array::comparison::*;
+use crate::fmt::FmtList;
+use crate::frame::groupby::*;
+use crate::frame::hash_join::{HashJoin, ZipOuterJoinColumn};
+use crate::prelude::*;
+use ahash::RandomState;
+use arrow::array::{ArrayData, ArrayRef};
+use arrow::buffer::Buffer;
+
+impl IntoSeries for BinaryChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl FmtList for BinaryChunked {
+    fn fmt_list(&self) -> String {
+        let values: Vec<_> = (0..self.len())
+            .map(|i| format!("{}", self.get_any_value(i)))
+            .collect();
+        format!("[{}]", values.join(", "))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<BinaryChunked> {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.equal_element(idx_self, idx_other, other)
+    }
+
+    fn is_in_same_type(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
+        IsIn::is_in(&self.0, list_array)
+    }
+
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        self.0.vec_hash(random_state)
+    }
+
+    fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
+        HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref())
+    }
+    fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
+        HashJoin::hash_join_left(&self.0, other.as_ref().as_ref())
+    }
+    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
+        HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
+    }
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+    ) -> Series {
+        ZipOuterJoinColumn::zip_outer_join_column(&self.0, right_column, opt_join_tuples)
+    }
+
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        IntoGroupTuples::group_tuples(&self.0, multithreaded)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<BinaryChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.array_data()
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        self.0.append_array(other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        self.0.append(other.binary()?);
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        let idx = idx.rechunk();
+        Ok(ChunkTake::take_unchecked(&self.0, (&idx).into()).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        ChunkTake::take(&self.0, iter.into()).into_series()
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        let indices = indices.rechunk();
+        ChunkTake::take(&self.0, (&indices).into()).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        ChunkOps::rechunk(&self.0).into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        ChunkTake::take(&self.0, (0..self.0.len()).step_by(n).into()).into_series()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Binary => Ok(self.0.clone().into_series()),
+            dt => Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot cast binary to {:?}; use a hex or base64 encoding instead",
+                    dt
+                )
+                .into(),
+            )),
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        ChunkSort::sort_in_place(&mut self.0, reverse)
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        ChunkSort::sort(&self.0, reverse).into_series()
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        ChunkSort::argsort(&self.0, reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn unique(&self) -> Result<Series> {
+        ChunkUnique::unique(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        ChunkUnique::n_unique(&self.0)
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        ChunkUnique::arg_unique(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_unique(&self.0)
+    }
+
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_duplicated(&self.0)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        self.0.null_bits()
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_n(n, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_frac(frac, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+}
@@ -16,6 +16,7 @@ use crate::fmt::FmtList;
 #[cfg(feature = "pivot")]
 use crate::frame::groupby::pivot::*;
 use crate::frame::groupby::*;
+use crate::frame::hash_join::{HashJoin, ZipOuterJoinColumn};
 use crate::prelude::*;
 use ahash::RandomState;
 use arrow::array::{ArrayData, ArrayRef};
@@ -119,67 +120,75 @@ macro_rules! impl_dyn_series {
             }
 
             fn is_in_same_type(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
-                cast_and_apply!(self, is_in_same_type, list_array)
+                IsIn::is_in(&self.0, list_array)
             }
 
             fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
-                cast_and_apply!(self, vec_hash, random_state)
+                self.0.vec_hash(random_state)
             }
 
-            fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_mean(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_mean, groups)
             }
 
-            fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_min(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_min, groups)
             }
 
-            fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_max(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_max, groups)
             }
 
-            fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_sum(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_sum, groups)
             }
 
-            fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_first(&self, groups: &GroupTuples) -> Series {
                 physical_dispatch!(self, agg_first, groups)
             }
 
-            fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_last(&self, groups: &GroupTuples) -> Series {
                 physical_dispatch!(self, agg_last, groups)
             }
 
-            fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_std(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_std, groups)
             }
 
-            fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_var(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_var, groups)
             }
 
-            fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+            fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
                 cast_and_apply!(self, agg_n_unique, groups)
             }
 
-            fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_list(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_list, groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
+            fn agg_quantile(&self, groups: &GroupTuples, quantile: f64) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_quantile, groups, quantile)
             }
 
-            fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_median(&self, groups: &GroupTuples) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_median, groups)
             }
 
+            fn agg_arg_min(&self, groups: &GroupTuples) -> Result<Series> {
+                cast_and_apply!(self, agg_arg_min, groups)
+            }
+
+            fn agg_arg_max(&self, groups: &GroupTuples) -> Result<Series> {
+                cast_and_apply!(self, agg_arg_max, groups)
+            }
+
             #[cfg(feature = "pivot")]
             fn pivot<'a>(
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupTuples,
                 agg_type: PivotAgg,
             ) -> Result<DataFrame> {
                 self.0.pivot(pivot_series, keys, groups, agg_type)
@@ -190,25 +199,25 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupTuples,
             ) -> Result<DataFrame> {
                 self.0.pivot_count(pivot_series, keys, groups)
             }
             fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
-                cast_and_apply!(self, hash_join_inner, other)
+                HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref())
             }
             fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
-                cast_and_apply!(self, hash_join_left, other)
+                HashJoin::hash_join_left(&self.0, other.as_ref().as_ref())
             }
             fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
-                cast_and_apply!(self, hash_join_outer, other)
+                HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
             }
             fn zip_outer_join_column(
                 &self,
                 right_column: &Series,
                 opt_join_tuples: &[(Option<u32>, Option<u32>)],
             ) -> Series {
-                physical_dispatch!(self, zip_outer_join_column, right_column, opt_join_tuples)
+                ZipOuterJoinColumn::zip_outer_join_column(&self.0, right_column, opt_join_tuples)
             }
             fn subtract(&self, rhs: &Series) -> Result<Series> {
                 try_physical_dispatch!(self, subtract, rhs)
@@ -226,7 +235,7 @@ macro_rules! impl_dyn_series {
                 try_physical_dispatch!(self, remainder, rhs)
             }
             fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
-                cast_and_apply!(self, group_tuples, multithreaded)
+                IntoGroupTuples::group_tuples(&self.0, multithreaded)
             }
         }
 
@@ -247,6 +256,14 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn is_sorted(&self) -> IsSorted {
+                self.0.is_sorted()
+            }
+
+            fn set_sorted(&mut self, sorted: IsSorted) {
+                self.0.set_sorted(sorted)
+            }
+
             fn array_data(&self) -> Vec<&ArrayData> {
                 self.0.array_data()
             }
@@ -376,7 +393,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
-                try_physical_dispatch!(self, filter, filter)
+                ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
             }
 
             fn take(&self, indices: &UInt32Chunked) -> Series {
@@ -415,7 +432,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn rechunk(&self) -> Series {
-                physical_dispatch!(self, rechunk,)
+                ChunkOps::rechunk(&self.0).into_series()
             }
 
             fn head(&self, length: Option<usize>) -> Series {
@@ -472,6 +489,12 @@ macro_rules! impl_dyn_series {
                     Categorical => {
                         ChunkCast::cast::<CategoricalType>(&self.0).map(|ca| ca.into_series())
                     }
+                    #[cfg(feature = "dtype-decimal")]
+                    Decimal(precision, scale) => crate::chunked_array::decimal::cast_to_decimal(
+                        &self.0.clone().into_series(),
+                        *precision,
+                        *scale,
+                    ),
                     dt => Err(PolarsError::Other(
                         format!("Casting to {:?} is not supported", dt).into(),
                     )),
@@ -483,7 +506,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn value_counts(&self) -> Result<DataFrame> {
-                cast_and_apply!(self, value_counts,)
+                ChunkUnique::value_counts(&self.0)
             }
 
             fn get(&self, index: usize) -> AnyValue {
@@ -500,11 +523,15 @@ macro_rules! impl_dyn_series {
             }
 
             fn sort(&self, reverse: bool) -> Series {
-                physical_dispatch!(self, sort, reverse)
+                ChunkSort::sort(&self.0, reverse).into_series()
             }
 
             fn argsort(&self, reverse: bool) -> UInt32Chunked {
-                cast_and_apply!(self, argsort, reverse)
+                ChunkSort::argsort(&self.0, reverse)
+            }
+
+            fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+                cast_and_apply!(self, argsort_top_k, k, reverse)
             }
 
             fn null_count(&self) -> usize {
@@ -512,15 +539,15 @@ macro_rules! impl_dyn_series {
             }
 
             fn unique(&self) -> Result<Series> {
-                try_physical_dispatch!(self, unique,)
+                ChunkUnique::unique(&self.0).map(|ca| ca.into_series())
             }
 
             fn n_unique(&self) -> Result<usize> {
-                cast_and_apply!(self, n_unique,)
+                ChunkUnique::n_unique(&self.0)
             }
 
             fn arg_unique(&self) -> Result<UInt32Chunked> {
-                cast_and_apply!(self, arg_unique,)
+                ChunkUnique::arg_unique(&self.0)
             }
 
             fn arg_min(&self) -> Option<usize> {
@@ -545,11 +572,11 @@ macro_rules! impl_dyn_series {
             }
 
             fn is_unique(&self) -> Result<BooleanChunked> {
-                cast_and_apply!(self, is_unique,)
+                ChunkUnique::is_unique(&self.0)
             }
 
             fn is_duplicated(&self) -> Result<BooleanChunked> {
-                cast_and_apply!(self, is_duplicated,)
+                ChunkUnique::is_duplicated(&self.0)
             }
 
             fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
@@ -576,10 +603,10 @@ macro_rules! impl_dyn_series {
                 physical_dispatch!(self, sum_as_series,)
             }
             fn max_as_series(&self) -> Series {
-                physical_dispatch!(self, max_as_series,)
+                ChunkAggSeries::max_as_series(&self.0)
             }
             fn min_as_series(&self) -> Series {
-                physical_dispatch!(self, min_as_series,)
+                ChunkAggSeries::min_as_series(&self.0)
             }
             fn mean_as_series(&self) -> Series {
                 physical_dispatch!(self, mean_as_series,)
@@ -661,6 +688,76 @@ macro_rules! impl_dyn_series {
                 )
             }
 
+            fn rolling_var(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_var,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
+
+            fn rolling_std(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_std,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
+
+            fn rolling_median(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_median,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
+
+            fn rolling_quantile(
+                &self,
+                quantile: f64,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_quantile,
+                    quantile,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
+
             fn fmt_list(&self) -> String {
                 FmtList::fmt_list(&self.0)
             }
@@ -0,0 +1,340 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::chunked_array::decimal::MAX_DECIMAL_PRECISION;
+use crate::fmt::FmtList;
+use crate::frame::groupby::*;
+use crate::prelude::*;
+use ahash::RandomState;
+use arrow::array::{ArrayData, ArrayRef};
+use arrow::buffer::Buffer;
+use num::NumCast;
+use std::borrow::Cow;
+
+impl IntoSeries for DecimalChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl FmtList for DecimalChunked {
+    fn fmt_list(&self) -> String {
+        let values: Vec<_> = (0..self.len())
+            .map(|i| format!("{}", self.get_any_value(i)))
+            .collect();
+        format!("[{}]", values.join(", "))
+    }
+}
+
+/// `len` null sums with the dtype of the sum of `ca`.
+fn null_sum(ca: &DecimalChunked, len: usize) -> DecimalChunked {
+    let mut builder =
+        DecimalChunkedBuilder::new_unchecked(ca.name(), len, MAX_DECIMAL_PRECISION, ca.scale());
+    (0..len).for_each(|_| builder.append_null());
+    builder.finish()
+}
+
+impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.get_any_value(idx_self) == other.get(idx_other)
+    }
+
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        self.0.vec_hash(random_state)
+    }
+
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        IntoGroupTuples::group_tuples(&self.0, multithreaded)
+    }
+
+    /// The sum has the maximal precision. It is null if the sum of any group overflows, use
+    /// [try_agg_sum](private::PrivateSeries::try_agg_sum) to get an error instead.
+    fn agg_sum(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(match self.0.agg_sum_decimal(groups) {
+            Ok(ca) => ca.into_series(),
+            Err(_) => null_sum(&self.0, groups.len()).into_series(),
+        })
+    }
+
+    fn try_agg_sum(&self, groups: &GroupTuples) -> Result<Option<Series>> {
+        self.0
+            .agg_sum_decimal(groups)
+            .map(|ca| Some(ca.into_series()))
+    }
+
+    fn agg_mean(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(match self.0.agg_mean_decimal(groups) {
+            Ok(ca) => ca.into_series(),
+            Err(_) => Float64Chunked::full_null(self.0.name(), groups.len()).into_series(),
+        })
+    }
+
+    fn try_agg_mean(&self, groups: &GroupTuples) -> Result<Option<Series>> {
+        self.0
+            .agg_mean_decimal(groups)
+            .map(|ca| Some(ca.into_series()))
+    }
+
+    fn agg_min(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(self.0.agg_min_decimal(groups).into_series())
+    }
+
+    fn agg_max(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(self.0.agg_max_decimal(groups).into_series())
+    }
+
+    fn agg_first(&self, groups: &GroupTuples) -> Series {
+        self.0.agg_first_decimal(groups).into_series()
+    }
+
+    fn agg_last(&self, groups: &GroupTuples) -> Series {
+        self.0.agg_last_decimal(groups).into_series()
+    }
+
+    fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
+        Some(self.0.agg_n_unique_decimal(groups))
+    }
+
+    fn agg_list(&self, groups: &GroupTuples) -> Option<Series> {
+        Some(self.0.agg_list_decimal(groups).into_series())
+    }
+
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::subtract(&self.0, rhs)
+    }
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::add_to(&self.0, rhs)
+    }
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::multiply(&self.0, rhs)
+    }
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        NumOpsDispatch::divide(&self.0, rhs)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<DecimalChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.array_data()
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        self.0.append_array(other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        let other = other.decimal()?;
+        let other = if other.dtype() == self.dtype() {
+            Cow::Borrowed(other)
+        } else {
+            Cow::Owned(other.rescale(self.0.precision(), self.0.scale())?)
+        };
+        self.0.append(&other);
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        let idx = idx.rechunk();
+        Ok(ChunkTake::take_unchecked(&self.0, (&idx).into()).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        ChunkTake::take(&self.0, iter.into()).into_series()
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        let indices = indices.rechunk();
+        ChunkTake::take(&self.0, (&indices).into()).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        ChunkOps::rechunk(&self.0).into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        ChunkTake::take(&self.0, (0..self.0.len()).step_by(n).into()).into_series()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Decimal(precision, scale) => self
+                .0
+                .rescale(*precision, *scale)
+                .map(|ca| ca.into_series()),
+            DataType::Utf8 => Ok(self.0.to_utf8().into_series()),
+            DataType::Float64 => Ok(self.0.to_f64().into_series()),
+            // truncate towards zero
+            DataType::Int64 => {
+                let scaled = self.0.rescale(self.0.precision(), 0)?;
+                let mut ca: Int64Chunked = scaled
+                    .iter()
+                    .map(|opt_v| opt_v.and_then(|v| NumCast::from(v)))
+                    .collect();
+                ca.rename(self.0.name());
+                Ok(ca.into_series())
+            }
+            dt => self.0.to_f64().into_series().cast_with_datatype(dt),
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        ChunkSort::sort_in_place(&mut self.0, reverse)
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        ChunkSort::sort(&self.0, reverse).into_series()
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        ChunkSort::argsort(&self.0, reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        self.0.null_bits()
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    /// The sum has the maximal precision. It is null if the sum overflows, use
+    /// [try_sum_as_series](SeriesTrait::try_sum_as_series) to get an error instead.
+    fn sum_as_series(&self) -> Series {
+        self.0
+            .sum_as_decimal()
+            .unwrap_or_else(|_| null_sum(&self.0, 1))
+            .into_series()
+    }
+
+    fn try_sum_as_series(&self) -> Result<Series> {
+        self.0.sum_as_decimal().map(|ca| ca.into_series())
+    }
+
+    fn max_as_series(&self) -> Series {
+        let v = self.0.max();
+        self.0
+            .collect_same_type(1, std::iter::once(v))
+            .into_series()
+    }
+
+    fn min_as_series(&self) -> Series {
+        let v = self.0.min();
+        self.0
+            .collect_same_type(1, std::iter::once(v))
+            .into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        let mut ca: Float64Chunked = [self.0.mean()].iter().copied().collect();
+        ca.rename(self.0.name());
+        ca.into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_n(n, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_frac(frac, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+}
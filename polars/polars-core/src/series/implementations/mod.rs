@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-binary")]
+pub mod binary;
 #[cfg(any(
     feature = "dtype-date64",
     feature = "dtype-date32",
@@ -6,8 +8,12 @@
     feature = "dtype-time64-ns"
 ))]
 pub mod dates;
+#[cfg(feature = "dtype-decimal")]
+pub mod decimal;
 #[cfg(feature = "object")]
 pub mod object;
+#[cfg(feature = "dtype-struct")]
+pub mod struct_;
 
 use super::private;
 use super::IntoSeries;
@@ -77,60 +83,120 @@ macro_rules! impl_dyn_series {
                 self.0.vec_hash(random_state)
             }
 
-            fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_mean(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_mean(groups)
             }
 
-            fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_min(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_min(groups)
             }
 
-            fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_max(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_max(groups)
             }
 
-            fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_sum(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_sum(groups)
             }
 
-            fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_first(&self, groups: &GroupTuples) -> Series {
                 self.0.agg_first(groups)
             }
 
-            fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_last(&self, groups: &GroupTuples) -> Series {
                 self.0.agg_last(groups)
             }
 
-            fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_std(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_std(groups)
             }
 
-            fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_var(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_var(groups)
             }
 
-            fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+            fn agg_n_unique(&self, groups: &GroupTuples) -> Option<UInt32Chunked> {
                 self.0.agg_n_unique(groups)
             }
 
-            fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_list(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_list(groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
+            fn agg_quantile(&self, groups: &GroupTuples, quantile: f64) -> Option<Series> {
                 self.0.agg_quantile(groups, quantile)
             }
 
-            fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_median(&self, groups: &GroupTuples) -> Option<Series> {
                 self.0.agg_median(groups)
             }
 
+            fn agg_arg_min(&self, groups: &GroupTuples) -> Result<Series> {
+                self.0.agg_arg_min(groups).ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "operation 'agg_arg_min' not supported on dtype {:?}",
+                            self.0.dtype()
+                        )
+                        .into(),
+                    )
+                })
+            }
+
+            fn agg_arg_max(&self, groups: &GroupTuples) -> Result<Series> {
+                self.0.agg_arg_max(groups).ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "operation 'agg_arg_max' not supported on dtype {:?}",
+                            self.0.dtype()
+                        )
+                        .into(),
+                    )
+                })
+            }
+
+            fn agg_product(&self, groups: &GroupTuples) -> Result<Series> {
+                self.0.agg_product(groups).ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "operation 'agg_product' not supported on dtype {:?}",
+                            self.0.dtype()
+                        )
+                        .into(),
+                    )
+                })
+            }
+
+            fn agg_any(&self, groups: &GroupTuples) -> Result<Series> {
+                self.0.agg_any(groups).ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "operation 'agg_any' not supported on dtype {:?}",
+                            self.0.dtype()
+                        )
+                        .into(),
+                    )
+                })
+            }
+
+            fn agg_all(&self, groups: &GroupTuples) -> Result<Series> {
+                self.0.agg_all(groups).ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "operation 'agg_all' not supported on dtype {:?}",
+                            self.0.dtype()
+                        )
+                        .into(),
+                    )
+                })
+            }
+
             #[cfg(feature = "pivot")]
             fn pivot<'a>(
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupTuples,
                 agg_type: PivotAgg,
             ) -> Result<DataFrame> {
                 self.0.pivot(pivot_series, keys, groups, agg_type)
@@ -141,7 +207,7 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupTuples,
             ) -> Result<DataFrame> {
                 self.0.pivot_count(pivot_series, keys, groups)
             }
@@ -198,6 +264,14 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn is_sorted(&self) -> IsSorted {
+                self.0.is_sorted()
+            }
+
+            fn set_sorted(&mut self, sorted: IsSorted) {
+                self.0.set_sorted(sorted)
+            }
+
             fn array_data(&self) -> Vec<&ArrayData> {
                 self.0.array_data()
             }
@@ -644,6 +718,12 @@ macro_rules! impl_dyn_series {
                     Categorical => {
                         ChunkCast::cast::<CategoricalType>(&self.0).map(|ca| ca.into_series())
                     }
+                    #[cfg(feature = "dtype-decimal")]
+                    Decimal(precision, scale) => crate::chunked_array::decimal::cast_to_decimal(
+                        &self.0.clone().into_series(),
+                        *precision,
+                        *scale,
+                    ),
                     dt => Err(PolarsError::Other(
                         format!("Casting to {:?} is not supported", dt).into(),
                     )),
@@ -679,6 +759,10 @@ macro_rules! impl_dyn_series {
                 ChunkSort::argsort(&self.0, reverse)
             }
 
+            fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+                ChunkSort::argsort_top_k(&self.0, k, reverse)
+            }
+
             fn null_count(&self) -> usize {
                 self.0.null_count()
             }
@@ -809,6 +893,58 @@ macro_rules! impl_dyn_series {
                     .map(|ca| ca.into_series())
             }
 
+            fn rolling_var(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                ChunkWindow::rolling_var(&self.0, window_size, weight, ignore_null, min_periods)
+                    .map(|ca| ca.into_series())
+            }
+
+            fn rolling_std(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                ChunkWindow::rolling_std(&self.0, window_size, weight, ignore_null, min_periods)
+                    .map(|ca| ca.into_series())
+            }
+
+            fn rolling_median(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                ChunkWindow::rolling_median(&self.0, window_size, weight, ignore_null, min_periods)
+                    .map(|ca| ca.into_series())
+            }
+
+            fn rolling_quantile(
+                &self,
+                quantile: f64,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                ChunkWindow::rolling_quantile(
+                    &self.0,
+                    quantile,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods,
+                )
+                .map(|ca| ca.into_series())
+            }
+
             fn fmt_list(&self) -> String {
                 FmtList::fmt_list(&self.0)
             }
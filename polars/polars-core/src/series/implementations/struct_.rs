@@ -0,0 +1,194 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::fmt::FmtList;
+use crate::prelude::*;
+use arrow::array::{Array, ArrayData, ArrayRef};
+use arrow::buffer::Buffer;
+
+impl IntoSeries for StructChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl FmtList for StructChunked {
+    fn fmt_list(&self) -> String {
+        let values: Vec<_> = (0..self.len())
+            .map(|i| format!("{}", self.get_any_value(i)))
+            .collect();
+        format!("[{}]", values.join(", "))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<StructChunked> {}
+
+impl SeriesTrait for SeriesWrap<StructChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.array_data()
+    }
+
+    fn chunk_lengths(&self) -> &Vec<usize> {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn struct_(&self) -> Result<&StructChunked> {
+        Ok(&self.0)
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        let other = StructChunked::new_from_chunks(self.0.name(), vec![other])?;
+        self.0.append(&other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0
+            .apply_fields(|s| s.slice(offset, length))
+            .into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        self.0.append(other.struct_()?)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.filter(filter))
+            .map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        let idx: NoNull<UInt32Chunked> = iter.map(|i| i as u32).collect();
+        self.take(&idx.into_inner())
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        let idx: NoNull<UInt32Chunked> = iter.map(|i| i as u32).collect();
+        let idx = idx.into_inner();
+        self.0
+            .apply_fields(|s| s.take_unchecked(&idx).unwrap())
+            .into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.take_unchecked(idx))
+            .map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        self.take_opt_iter(iter)
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        let idx: UInt32Chunked = iter.map(|opt_i| opt_i.map(|i| i as u32)).collect();
+        self.take(&idx)
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        self.0.apply_fields(|s| s.take(indices)).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        // the fields are always rechunked
+        self.0.clone().into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.head(length)).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.tail(length)).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.apply_fields(|s| s.take_every(n)).into_series()
+    }
+
+    fn cast_with_datatype(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Struct(fields) if fields.len() == self.0.fields().len() => {
+                self.0.cast_fields(fields).map(|ca| ca.into_series())
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("cannot cast {:?} to {:?}", self.0.dtype(), dt).into(),
+            )),
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        !self.0.is_not_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        self.0
+            .chunks()
+            .iter()
+            .map(|arr| (arr.null_count(), arr.data().null_buffer().cloned()))
+            .collect()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.apply_fields(|s| s.reverse()).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        let idx: NoNull<UInt32Chunked> = (0..self.0.len() as u32).collect();
+        let idx = idx.into_inner().sample_n(n, with_replacement)?;
+        Ok(self.take(&idx))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        let n = (self.0.len() as f64 * frac) as usize;
+        self.sample_n(n, with_replacement)
+    }
+}
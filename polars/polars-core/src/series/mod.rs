@@ -6,11 +6,13 @@ pub(crate) mod arithmetic;
 mod comparison;
 pub mod implementations;
 pub(crate) mod iterator;
+pub mod ops;
 
 use crate::chunked_array::builder::get_list_builder;
 use crate::chunked_array::float::IsNan;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::utils::get_supertype;
+use crate::vector_hasher::{check_hashable, seeded_random_state};
 use arrow::array::ArrayData;
 use arrow::compute::cast;
 use itertools::Itertools;
@@ -47,48 +49,83 @@ pub(crate) mod private {
         fn vec_hash(&self, _random_state: RandomState) -> UInt64Chunked {
             unimplemented!()
         }
-        fn agg_mean(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_mean(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_min(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_min(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_max(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_max(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_sum(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        /// Like [agg_sum](PrivateSeries::agg_sum), but returns an error where `agg_sum` would
+        /// give null, e.g. on a decimal overflow.
+        fn try_agg_sum(&self, groups: &GroupTuples) -> Result<Option<Series>> {
+            Ok(self.agg_sum(groups))
+        }
+        /// Like [agg_mean](PrivateSeries::agg_mean), but returns an error where `agg_mean` would
+        /// give null, e.g. on a decimal overflow.
+        fn try_agg_mean(&self, groups: &GroupTuples) -> Result<Option<Series>> {
+            Ok(self.agg_mean(groups))
+        }
+        fn agg_std(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_var(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_var(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+        fn agg_first(&self, _groups: &GroupTuples) -> Series {
             unimplemented!()
         }
-        fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+        fn agg_last(&self, _groups: &GroupTuples) -> Series {
             unimplemented!()
         }
-        fn agg_n_unique(&self, _groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        fn agg_n_unique(&self, _groups: &GroupTuples) -> Option<UInt32Chunked> {
             unimplemented!()
         }
-        fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_list(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+        fn agg_quantile(&self, _groups: &GroupTuples, _quantile: f64) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_median(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_median(&self, _groups: &GroupTuples) -> Option<Series> {
             unimplemented!()
         }
+        fn agg_arg_min(&self, _groups: &GroupTuples) -> Result<Series> {
+            Err(PolarsError::InvalidOperation(
+                "operation 'agg_arg_min' not supported".into(),
+            ))
+        }
+        fn agg_arg_max(&self, _groups: &GroupTuples) -> Result<Series> {
+            Err(PolarsError::InvalidOperation(
+                "operation 'agg_arg_max' not supported".into(),
+            ))
+        }
+        fn agg_product(&self, _groups: &GroupTuples) -> Result<Series> {
+            Err(PolarsError::InvalidOperation(
+                "operation 'agg_product' not supported".into(),
+            ))
+        }
+        fn agg_any(&self, _groups: &GroupTuples) -> Result<Series> {
+            Err(PolarsError::InvalidOperation(
+                "operation 'agg_any' not supported".into(),
+            ))
+        }
+        fn agg_all(&self, _groups: &GroupTuples) -> Result<Series> {
+            Err(PolarsError::InvalidOperation(
+                "operation 'agg_all' not supported".into(),
+            ))
+        }
         #[cfg(feature = "pivot")]
         fn pivot<'a>(
             &self,
             _pivot_series: &'a (dyn SeriesTrait + 'a),
             _keys: Vec<Series>,
-            _groups: &[(u32, Vec<u32>)],
+            _groups: &GroupTuples,
             _agg_type: PivotAgg,
         ) -> Result<DataFrame> {
             unimplemented!()
@@ -99,7 +136,7 @@ pub(crate) mod private {
             &self,
             _pivot_series: &'a (dyn SeriesTrait + 'a),
             _keys: Vec<Series>,
-            _groups: &[(u32, Vec<u32>)],
+            _groups: &GroupTuples,
         ) -> Result<DataFrame> {
             unimplemented!()
         }
@@ -167,6 +204,14 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Get whether the values are known to be sorted. See [IsSorted](crate::chunked_array::IsSorted).
+    fn is_sorted(&self) -> IsSorted {
+        IsSorted::Not
+    }
+
+    /// Mark the values as sorted. This is ignored by types that can't be sorted.
+    fn set_sorted(&mut self, _sorted: IsSorted) {}
+
     /// Get Arrow ArrayData
     fn array_data(&self) -> Vec<&ArrayData> {
         unimplemented!()
@@ -347,6 +392,29 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype decimal
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != decimal", self.dtype()).into(),
+        ))
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Unpack to StructChunked
+    fn struct_(&self) -> Result<&StructChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != struct", self.dtype()).into(),
+        ))
+    }
+
+    #[cfg(feature = "dtype-binary")]
+    /// Unpack to ChunkedArray of dtype binary
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != binary", self.dtype()).into(),
+        ))
+    }
+
     /// Append Arrow array of same dtype to this Series.
     fn append_array(&mut self, _other: ArrayRef) -> Result<()> {
         unimplemented!()
@@ -536,6 +604,11 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
+    /// Retrieve the indexes of the first `k` values in sorted order.
+    fn argsort_top_k(&self, k: usize, reverse: bool) -> UInt32Chunked {
+        self.argsort(reverse).slice(0, k)
+    }
+
     /// Count the null values.
     fn null_count(&self) -> usize {
         unimplemented!()
@@ -685,6 +758,12 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     fn sum_as_series(&self) -> Series {
         unimplemented!()
     }
+    /// Get the sum of the Series as a new Series of length 1. Returns an error where
+    /// [sum_as_series](SeriesTrait::sum_as_series) would lose the result, e.g. if the sum of a
+    /// decimal Series overflows.
+    fn try_sum_as_series(&self) -> Result<Series> {
+        Ok(self.sum_as_series())
+    }
     /// Get the max of the Series as a new Series of length 1.
     fn max_as_series(&self) -> Series {
         unimplemented!()
@@ -757,6 +836,59 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     ) -> Result<Series> {
         unimplemented!()
     }
+    /// Apply a rolling var to a Series. See:
+    /// [ChunkedArray::rolling_var](crate::prelude::ChunkWindow::rolling_var).
+    fn rolling_var(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_var not supported on dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling std to a Series. See:
+    /// [ChunkedArray::rolling_std](crate::prelude::ChunkWindow::rolling_std).
+    fn rolling_std(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_std not supported on dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling median to a Series. See:
+    /// [ChunkedArray::rolling_median](crate::prelude::ChunkWindow::rolling_median).
+    fn rolling_median(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_median not supported on dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling quantile to a Series. See:
+    /// [ChunkedArray::rolling_quantile](crate::prelude::ChunkWindow::rolling_quantile).
+    fn rolling_quantile(
+        &self,
+        _quantile: f64,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling_quantile not supported on dtype {:?}", self.dtype()).into(),
+        ))
+    }
 
     fn fmt_list(&self) -> String {
         "fmt implemented".into()
@@ -1057,6 +1189,32 @@ impl Series {
         self
     }
 
+    /// Mark the values as sorted. Operations like `min`, `max`, `sort`, comparisons with a
+    /// scalar, groupby and joins use this to skip work, so this should only be set if the values
+    /// are sorted as described by [IsSorted](crate::chunked_array::IsSorted).
+    pub fn set_sorted(&mut self, sorted: IsSorted) -> &mut Series {
+        self.get_inner_mut().set_sorted(sorted);
+        self
+    }
+
+    /// Scan the values of a numeric or temporal Series to determine whether they are sorted and
+    /// set the sortedness flag accordingly. The scan stops at the first pair of values that is out
+    /// of order. The flag of other types is returned unchanged.
+    pub fn detect_sorted(&mut self) -> IsSorted {
+        use DataType::*;
+        let sorted = match self.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+            | Date32 | Date64 | Time64(_) | Duration(_) => {
+                apply_method_numeric_series!(self, detect_sorted,)
+            }
+            _ => return self.is_sorted(),
+        };
+        if sorted != IsSorted::Not {
+            self.set_sorted(sorted);
+        }
+        sorted
+    }
+
     /// Append arrow array of same datatype.
     pub fn append_array(&mut self, other: ArrayRef) -> Result<&mut Self> {
         self.get_inner_mut().append_array(other)?;
@@ -1220,10 +1378,27 @@ impl Series {
         lhs.zip_with_same_type(mask, rhs.as_ref())
     }
 
+    /// Hash every value of the Series with a hasher seeded by `seed`.
+    ///
+    /// Null values hash to a fixed value (per seed) that is different from the hashes of the
+    /// valid values. The hashes are only reproducible within the same build of polars: they may
+    /// change between versions and platforms, so they should not be persisted.
+    ///
+    /// Returns an error for list, struct and object Series.
+    pub fn hash(&self, seed: u64) -> Result<UInt64Chunked> {
+        check_hashable(self)?;
+        let mut ca = self.vec_hash(seeded_random_state(seed));
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Check if values of this array are in the Series of the list array.
     pub fn is_in(&self, list_array: &ListChunked) -> Result<BooleanChunked> {
         let inner_dt = list_array.inner_dtype();
         let my_dt = self.dtype();
+        if my_dt == &DataType::Categorical {
+            return self.is_in_same_type(list_array);
+        }
 
         let st = get_supertype(my_dt, &inner_dt)?;
         let left = if &st != my_dt {
@@ -1233,6 +1408,94 @@ impl Series {
         };
         left.is_in_same_type(list_array)
     }
+
+    /// Get the categories of a categorical Series ordered by their physical code.
+    pub fn get_categories(&self) -> Result<Series> {
+        Ok(self.categorical()?.get_categories().into_series())
+    }
+
+    /// Set the order that is used to sort a categorical Series and take its minimum and maximum.
+    pub fn set_ordering(&self, ordering: CategoricalOrdering) -> Result<Series> {
+        let mut ca = self.categorical()?.clone();
+        ca.set_ordering(ordering);
+        Ok(ca.into_series())
+    }
+
+    /// Give the categories of a categorical Series new names.
+    /// See [rename_categories](crate::chunked_array::ChunkedArray::rename_categories).
+    pub fn rename_categories(&self, names: &[&str]) -> Result<Series> {
+        self.categorical()?
+            .rename_categories(names)
+            .map(|ca| ca.into_series())
+    }
+
+    /// Replace the categories of a categorical Series given as `(old, new)` pairs.
+    /// See [remap](crate::chunked_array::ChunkedArray::remap).
+    pub fn remap(&self, mapping: &[(&str, &str)]) -> Result<Series> {
+        Ok(self.categorical()?.remap(mapping).into_series())
+    }
+
+    /// Apply a rolling aggregation over a time based window. The window of every value
+    /// contains all values with an `index` in the half-open interval `(t - duration, t]`,
+    /// where `t` is the `index` of that value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - `Date64` Series without null values, sorted in ascending order.
+    /// * `duration` - The length of the window, e.g. "3d" or "1h30m".
+    ///                 Supported units are: ms, s, m, h, d and w.
+    /// * `agg` - The aggregation that is applied to the values in the window.
+    /// * `min_periods` - Amount of (non null) values in the window that should be
+    ///                   present before computing a result.
+    #[cfg(feature = "dtype-date64")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-date64")))]
+    pub fn rolling_by_time(
+        &self,
+        index: &Series,
+        duration: &str,
+        agg: RollingAgg,
+        min_periods: u32,
+    ) -> Result<Series> {
+        use crate::chunked_array::ops::window::{parse_duration_ms, rolling_apply_float};
+
+        if index.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the index should have the same length as the Series".into(),
+            ));
+        }
+        let index = index.date64()?;
+        if index.null_count() > 0 {
+            return Err(PolarsError::ValueError(
+                "the index of a time based rolling window may not contain null values".into(),
+            ));
+        }
+        let duration = parse_duration_ms(duration)?;
+        let index = index.into_no_null_iter().collect::<Vec<_>>();
+        if index.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::ValueError(
+                "the index of a time based rolling window should be sorted in ascending order"
+                    .into(),
+            ));
+        }
+
+        let values = self
+            .cast::<Float64Type>()?
+            .f64()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        let mut start = 0;
+        let bounds = index.iter().enumerate().map(|(i, &t)| {
+            // if `t - duration` overflows, the window starts before every timestamp
+            if let Some(lower) = t.checked_sub(duration) {
+                while index[start] <= lower {
+                    start += 1;
+                }
+            }
+            (start, i + 1)
+        });
+        let ca = rolling_apply_float(self.name(), &values, bounds, None, true, min_periods, agg);
+        Ok(ca.into_series())
+    }
 }
 
 impl Deref for Series {
@@ -1414,6 +1677,26 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
             ArrowDataType::LargeList(_) => {
                 Ok(ListChunked::new_from_chunks(name, chunks).into_series())
             }
+            #[cfg(feature = "dtype-struct")]
+            ArrowDataType::Struct(_) => {
+                Ok(StructChunked::new_from_chunks(name, chunks)?.into_series())
+            }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::LargeBinary => {
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::Binary => {
+                let chunks = chunks
+                    .iter()
+                    .map(crate::chunked_array::binary::to_large_binary)
+                    .collect_vec();
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal(_, _) => {
+                Ok(DecimalChunked::new_from_chunks(name, chunks).into_series())
+            }
             ArrowDataType::Null => {
                 // we don't support null types yet so we use a small digit type filled with nulls
                 let len = chunks.iter().fold(0, |acc, array| acc + array.len());
@@ -1544,6 +1827,73 @@ mod test {
         assert_eq!(slice_3.get(0), AnyValue::Int64(1));
     }
 
+    #[test]
+    #[cfg(feature = "dtype-date64")]
+    fn series_rolling_by_time() {
+        let day = 24 * 3600 * 1000;
+        let index =
+            Date64Chunked::new_from_slice("t", &[0, day, 2 * day, 5 * day, 6 * day]).into_series();
+        let s = Series::new("a", &[1, 2, 3, 4, 5]);
+
+        let out = s.rolling_by_time(&index, "2d", RollingAgg::Sum, 1).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(3.0), Some(5.0), Some(4.0), Some(9.0)]
+        );
+        let out = s.rolling_by_time(&index, "3d", RollingAgg::Max, 2).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(2.0), Some(3.0), None, Some(5.0)]
+        );
+        // index should be sorted
+        let index = index.reverse();
+        assert!(s.rolling_by_time(&index, "2d", RollingAgg::Sum, 1).is_err());
+
+        // the window may reach before the smallest timestamp
+        let index = Date64Chunked::new_from_slice("t", &[i64::MIN, i64::MIN + 1]).into_series();
+        let s = Series::new("a", &[1, 2]);
+        let out = s.rolling_by_time(&index, "1w", RollingAgg::Sum, 1).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(1.0), Some(3.0)]);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn series_rolling_unsupported_dtype() {
+        let s = DecimalChunked::new_from_slice("a", &[1, 2, 3], 5, 0)
+            .unwrap()
+            .into_series();
+        assert!(matches!(
+            s.rolling_var(2, None, true, 1),
+            Err(PolarsError::InvalidOperation(_))
+        ));
+        assert!(s.rolling_std(2, None, true, 1).is_err());
+        assert!(s.rolling_median(2, None, true, 1).is_err());
+        assert!(s.rolling_quantile(0.5, 2, None, true, 1).is_err());
+    }
+
+    #[test]
+    fn series_hash() {
+        let s = Series::new("a", &[Some(1), None, Some(1), Some(2)]);
+        let h = s.hash(0).unwrap();
+        assert_eq!(h.name(), "a");
+        assert_eq!(h.null_count(), 0);
+        let h: Vec<_> = h.into_no_null_iter().collect();
+        assert_eq!(h[0], h[2]);
+        assert_ne!(h[0], h[1]);
+        assert_ne!(h[0], h[3]);
+        assert_eq!(
+            Vec::from(&s.hash(0).unwrap()),
+            Vec::from(&s.hash(0).unwrap())
+        );
+        assert_ne!(
+            Vec::from(&s.hash(0).unwrap()),
+            Vec::from(&s.hash(1).unwrap())
+        );
+
+        let list = Series::new("a", &[s.clone(), s]);
+        assert!(list.hash(0).is_err());
+    }
+
     #[test]
     fn out_of_range_slice_does_not_panic() {
         let series = Series::new("a", &[1i64, 2, 3, 4, 5]);
@@ -0,0 +1,236 @@
+//! Ranking and binning of the values of a Series.
+use crate::chunked_array::builder::CategoricalChunkedBuilder;
+use crate::prelude::*;
+
+/// The method that is used to assign a rank to tied values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// The mean of the ranks the tied values would get.
+    Average,
+    /// The lowest rank the tied values would get.
+    Min,
+    /// The highest rank the tied values would get.
+    Max,
+    /// Like `Min`, but the ranks of subsequent groups of tied values are consecutive.
+    Dense,
+    /// Tied values get distinct ranks in the order they appear in the Series.
+    Ordinal,
+}
+
+impl Default for RankMethod {
+    fn default() -> Self {
+        RankMethod::Average
+    }
+}
+
+impl Series {
+    /// Assign a rank to every value, starting at 1. Null values get a null rank.
+    ///
+    /// The ranks are `Float64` for [RankMethod::Average](RankMethod::Average) and `UInt32`
+    /// for the other methods.
+    pub fn rank(&self, method: RankMethod, descending: bool) -> Series {
+        let is_null: Vec<bool> = self.is_null().into_no_null_iter().collect();
+        let mut sort_idx: Vec<u32> = self
+            .argsort(descending)
+            .into_no_null_iter()
+            .filter(|&i| !is_null[i as usize])
+            .collect();
+
+        // determine the groups of tied values as ranges in `sort_idx`
+        let mut ties = Vec::with_capacity(sort_idx.len());
+        let mut start = 0;
+        for i in 1..=sort_idx.len() {
+            if i == sort_idx.len()
+                || !unsafe {
+                    self.equal_element(sort_idx[i - 1] as usize, sort_idx[i] as usize, self)
+                }
+            {
+                // the sort may not be stable, so restore the order of appearance of the ties
+                sort_idx[start..i].sort_unstable();
+                ties.push((start, i));
+                start = i;
+            }
+        }
+
+        if let RankMethod::Average = method {
+            let mut ranks = vec![None; self.len()];
+            for &(start, end) in &ties {
+                let rank = (start + 1 + end) as f64 / 2.0;
+                for &i in &sort_idx[start..end] {
+                    ranks[i as usize] = Some(rank);
+                }
+            }
+            return Float64Chunked::new_from_opt_slice(self.name(), &ranks).into_series();
+        }
+
+        let mut ranks = vec![None; self.len()];
+        for (dense_rank, &(start, end)) in ties.iter().enumerate() {
+            for (position, &i) in (start..end).zip(&sort_idx[start..end]) {
+                let rank = match method {
+                    RankMethod::Min => start + 1,
+                    RankMethod::Max => end,
+                    RankMethod::Dense => dense_rank + 1,
+                    _ => position + 1,
+                };
+                ranks[i as usize] = Some(rank as u32);
+            }
+        }
+        UInt32Chunked::new_from_opt_slice(self.name(), &ranks).into_series()
+    }
+
+    /// Bin the values in the intervals `(-inf, breaks[0]]`, `(breaks[0], breaks[1]]`, ...,
+    /// `(breaks[n - 1], inf]`. The result is a `Categorical` Series with the label of the
+    /// interval of every value.
+    ///
+    /// # Arguments
+    ///
+    /// * `breaks` - Strictly increasing boundaries of the intervals.
+    /// * `labels` - One label per interval, i.e. `breaks.len() + 1` labels. If `None`, the
+    ///              intervals are labeled like `"(0, 1]"`.
+    pub fn cut(&self, breaks: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        if breaks.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(PolarsError::ValueError(
+                "the breaks of cut should be strictly increasing".into(),
+            ));
+        }
+        let labels: Vec<String> = match labels {
+            Some(labels) => {
+                if labels.len() != breaks.len() + 1 {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "expected {} labels for {} breaks, got {}",
+                            breaks.len() + 1,
+                            breaks.len(),
+                            labels.len()
+                        )
+                        .into(),
+                    ));
+                }
+                labels.iter().map(|l| l.to_string()).collect()
+            }
+            None => (0..=breaks.len())
+                .map(|i| {
+                    let lower = i
+                        .checked_sub(1)
+                        .map_or_else(|| "-inf".to_string(), |i| breaks[i].to_string());
+                    let upper = breaks
+                        .get(i)
+                        .map_or_else(|| "inf".to_string(), |b| b.to_string());
+                    format!("({}, {}]", lower, upper)
+                })
+                .collect(),
+        };
+
+        let values = self.cast::<Float64Type>()?;
+        let values = values.f64()?;
+        // the labels are appended first, so that the categories are ordered by interval
+        let mut builder = CategoricalChunkedBuilder::new(self.name(), labels.len() + self.len());
+        builder.append_values(labels.iter().map(|l| Some(l.as_str())));
+        builder.append_values(values.into_iter().map(|opt_v| {
+            opt_v.filter(|v| !v.is_nan()).map(|v| {
+                let interval = breaks.iter().take_while(|&&b| b < v).count();
+                labels[interval].as_str()
+            })
+        }));
+        let ca = builder.finish().slice(labels.len() as i64, self.len());
+        Ok(ca.into_series())
+    }
+
+    /// Bin the values in intervals bounded by the given quantiles of the values.
+    /// See [cut](Series::cut) for the intervals and labels.
+    ///
+    /// If no `labels` are given, quantiles that lead to the same boundary are merged.
+    pub fn qcut(&self, quantiles: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        let values = self.cast::<Float64Type>()?;
+        let values = values.f64()?;
+        let mut breaks = quantiles
+            .iter()
+            .map(|&q| {
+                values.quantile(q)?.ok_or_else(|| {
+                    PolarsError::NoData(
+                        "cannot compute the quantiles of a Series without valid values".into(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if labels.is_none() {
+            breaks.dedup();
+        }
+        self.cut(&breaks, labels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rank() {
+        let s = Series::new("a", &[Some(3), Some(1), None, Some(3), Some(2)]);
+
+        let out = s.rank(RankMethod::Average, false);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(3.5), Some(1.0), None, Some(3.5), Some(2.0)]
+        );
+        let out = s.rank(RankMethod::Min, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(3), Some(1), None, Some(3), Some(2)]
+        );
+        let out = s.rank(RankMethod::Max, true);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(2), Some(4), None, Some(2), Some(3)]
+        );
+        let out = s.rank(RankMethod::Dense, true);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(1), Some(3), None, Some(1), Some(2)]
+        );
+        let out = s.rank(RankMethod::Ordinal, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(3), Some(1), None, Some(4), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_cut_qcut() {
+        let s = Series::new("a", &[Some(1.0), Some(5.0), None, Some(2.5), Some(10.0)]);
+
+        let out = s.cut(&[2.0, 5.0], None).unwrap();
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        assert_eq!(
+            Vec::from(out.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[
+                Some("(-inf, 2]"),
+                Some("(2, 5]"),
+                None,
+                Some("(2, 5]"),
+                Some("(5, inf]")
+            ]
+        );
+        assert_eq!(out.get_categories().unwrap().len(), 3);
+
+        let out = s.cut(&[2.0], Some(&["low", "high"])).unwrap();
+        assert_eq!(
+            Vec::from(out.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[Some("low"), Some("high"), None, Some("high"), Some("high")]
+        );
+        assert!(s.cut(&[2.0], Some(&["low"])).is_err());
+        assert!(s.cut(&[5.0, 2.0], None).is_err());
+
+        let out = s.qcut(&[0.5], Some(&["bottom", "top"])).unwrap();
+        assert_eq!(
+            Vec::from(out.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[
+                Some("bottom"),
+                Some("bottom"),
+                None,
+                Some("bottom"),
+                Some("top")
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::chunked_array::decimal::MAX_DECIMAL_PRECISION;
 use crate::prelude::*;
 use crate::POOL;
 pub use arrow;
@@ -519,6 +520,26 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         (Duration(_), Float32) => Some(Float32),
         (Duration(_), Float64) => Some(Float64),
 
+        // keep all integer digits and the largest scale
+        (Decimal(l_prec, l_scale), Decimal(r_prec, r_scale)) => {
+            let scale = std::cmp::max(*l_scale, *r_scale);
+            let int_digits = std::cmp::max(l_prec - l_scale, r_prec - r_scale);
+            Some(Decimal(
+                std::cmp::min(MAX_DECIMAL_PRECISION, int_digits + scale),
+                scale,
+            ))
+        }
+        (Decimal(_, scale), UInt8)
+        | (Decimal(_, scale), UInt16)
+        | (Decimal(_, scale), UInt32)
+        | (Decimal(_, scale), UInt64)
+        | (Decimal(_, scale), Int8)
+        | (Decimal(_, scale), Int16)
+        | (Decimal(_, scale), Int32)
+        | (Decimal(_, scale), Int64) => Some(Decimal(MAX_DECIMAL_PRECISION, *scale)),
+        (Decimal(_, _), Float32) => Some(Float64),
+        (Decimal(_, _), Float64) => Some(Float64),
+
         (UInt8, Int8) => Some(Int8),
         (UInt8, Int16) => Some(Int16),
         (UInt8, Int32) => Some(Int32),
@@ -65,55 +65,6 @@ pub(crate) fn this_thread(h: u64, thread_no: u64, n_threads: u64) -> bool {
     (h + thread_no) % n_threads == 0
 }
 
-fn finish_table_from_key_hashes<T>(
-    hashes_nd_keys: Vec<(u64, T)>,
-    mut hash_tbl: HashMap<T, Vec<u32>, RandomState>,
-    offset: usize,
-) -> HashMap<T, Vec<u32>, RandomState>
-where
-    T: Hash + Eq,
-{
-    hashes_nd_keys
-        .into_iter()
-        .enumerate()
-        .for_each(|(idx, (h, t))| {
-            let idx = (idx + offset) as u32;
-            hash_tbl
-                .raw_entry_mut()
-                // uses the key to check equality to find and entry
-                .from_key_hashed_nocheck(h, &t)
-                // if entry is found modify it
-                .and_modify(|_k, v| {
-                    v.push(idx);
-                })
-                // otherwise we insert both the key and new Vec without hashing
-                .or_insert_with(|| (t, vec![idx]));
-        });
-    hash_tbl
-}
-
-pub(crate) fn prepare_hashed_relation<T>(
-    b: impl Iterator<Item = T>,
-) -> HashMap<T, Vec<u32>, RandomState>
-where
-    T: Hash + Eq,
-{
-    let random_state = RandomState::default();
-
-    let hashes_nd_keys = b
-        .map(|val| {
-            let mut hasher = random_state.build_hasher();
-            val.hash(&mut hasher);
-            (hasher.finish(), val)
-        })
-        .collect::<Vec<_>>();
-
-    let hash_tbl: HashMap<T, Vec<u32>, RandomState> =
-        HashMap::with_capacity_and_hasher(hashes_nd_keys.len(), random_state);
-
-    finish_table_from_key_hashes(hashes_nd_keys, hash_tbl, 0)
-}
-
 pub(crate) fn prepare_hashed_relation_threaded<T, I>(
     iters: Vec<I>,
 ) -> Vec<HashMap<T, Vec<u32>, RandomState>>
@@ -202,6 +153,33 @@ where
     (hashes, random_state)
 }
 
+/// Return an error if the values of the Series can't be hashed with `vec_hash`.
+pub(crate) fn check_hashable(s: &Series) -> Result<()> {
+    match s.dtype() {
+        DataType::List(_) | DataType::Struct(_) | DataType::Null => {
+            Err(PolarsError::InvalidOperation(
+                format!("cannot hash a Series of dtype {:?}", s.dtype()).into(),
+            ))
+        }
+        #[cfg(feature = "object")]
+        DataType::Object => Err(PolarsError::InvalidOperation(
+            "cannot hash a Series of dtype object".into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Create a deterministic `RandomState` from a single seed, so that hashes are reproducible
+/// between runs of the same build.
+pub(crate) fn seeded_random_state(seed: u64) -> RandomState {
+    RandomState::with_seeds(
+        seed,
+        seed.rotate_left(16),
+        seed.rotate_left(32),
+        seed.rotate_left(48),
+    )
+}
+
 // Combines two hashes into one hash
 // http://myeyesareblind.com/2017/02/06/Combine-hash-values/
 fn combine_hashes(l: u64, r: u64) -> u64 {
@@ -56,7 +56,7 @@
 //! ```
 //!
 use crate::csv_core::csv::{build_csv_reader, SequentialReader};
-use crate::{SerReader, SerWriter};
+use crate::{set_sorted_flags, SerReader, SerWriter};
 pub use arrow::csv::WriterBuilder;
 use polars_core::prelude::*;
 use std::fs::File;
@@ -424,16 +424,17 @@ where
             csv_reader.as_df(None, None)?
         };
 
-        match rechunk {
+        let df = match rechunk {
             true => {
                 if df.n_chunks()? > 1 {
-                    Ok(df.agg_chunks())
+                    df.agg_chunks()
                 } else {
-                    Ok(df)
+                    df
                 }
             }
-            false => Ok(df),
-        }
+            false => df,
+        };
+        Ok(set_sorted_flags(df))
     }
 }
 
@@ -131,4 +131,78 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn write_and_read_ipc_binary() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let bytes = BinaryChunked::new_from_opt_slice("bytes", &[Some(&b"\x00\xff"[..]), None]);
+        let mut df = DataFrame::new(vec![bytes.into_series()]).unwrap();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert_eq!(df_read.column("bytes").unwrap().dtype(), &DataType::Binary);
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_ipc_struct() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let x = Series::new("x", &[Some(1.0), Some(-2.5), None]);
+        let y = Series::new("y", &["a", "b", "c"]);
+        let validity = BooleanChunked::new_from_slice("", &[true, false, true]);
+        let point = StructChunked::new("point", &[x, y])
+            .unwrap()
+            .with_validity(validity)
+            .unwrap();
+        let mut df =
+            DataFrame::new(vec![Series::new("a", &[1, 2, 3]), point.into_series()]).unwrap();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        let point = df_read.column("point").unwrap();
+        assert_eq!(point.dtype(), df.column("point").unwrap().dtype());
+        assert_eq!(
+            Vec::from(&point.is_null()),
+            &[Some(false), Some(true), Some(false)]
+        );
+        assert!(df
+            .unnest(&["point"])
+            .unwrap()
+            .frame_equal_missing(&df_read.unnest(&["point"]).unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn write_and_read_ipc_decimal() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let amounts =
+            DecimalChunked::new_from_opt_iter("amount", vec![Some(1234), None, Some(-5)], 10, 2)
+                .unwrap();
+        let mut df = DataFrame::new(vec![amounts.into_series()]).unwrap();
+
+        IpcWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert_eq!(
+            df_read.column("amount").unwrap().dtype(),
+            &DataType::Decimal(10, 2)
+        );
+        assert!(df.frame_equal_missing(&df_read));
+    }
 }
@@ -60,11 +60,24 @@
 //! +-----+--------+-------+--------+
 //! ```
 //!
+//! ## Write a DataFrame to newline delimited JSON
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example(df: &mut DataFrame) -> Result<()> {
+//!     let mut buf: Vec<u8> = Vec::new();
+//!     JsonWriter::new(&mut buf).finish(df)
+//! }
+//! ```
+//!
 use crate::finish_reader;
 use crate::prelude::*;
+use arrow::json::LineDelimitedWriter;
 pub use arrow::json::ReaderBuilder;
 use polars_core::prelude::*;
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, Write};
 use std::sync::Arc;
 
 pub struct JsonReader<R>
@@ -135,6 +148,29 @@ where
     }
 }
 
+/// Write a DataFrame to newline delimited JSON. Struct columns are written as JSON objects.
+pub struct JsonWriter<'a, W> {
+    writer: &'a mut W,
+}
+
+impl<'a, W> SerWriter<'a, W> for JsonWriter<'a, W>
+where
+    W: Write,
+{
+    fn new(writer: &'a mut W) -> Self {
+        JsonWriter { writer }
+    }
+
+    fn finish(self, df: &mut DataFrame) -> Result<()> {
+        let mut json_writer = LineDelimitedWriter::new(self.writer);
+
+        let batches: Vec<_> = df.iter_record_batches(df.height()).collect();
+        json_writer.write_batches(&batches)?;
+        json_writer.finish()?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -166,4 +202,31 @@ mod test {
         assert_eq!("d", df.get_columns()[3].name());
         assert_eq!((12, 4), df.shape());
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_json_struct() {
+        let nested_json = r#"{"a":1, "point":{"x":1.0, "y":"a"}}
+{"a":2, "point":{"x":-2.5, "y":"b"}}
+{"a":3, "point":{"x":0.5, "y":null}}"#;
+        let df = JsonReader::new(Cursor::new(nested_json))
+            .infer_schema(Some(3))
+            .finish()
+            .unwrap();
+        let point = df.column("point").unwrap().struct_().unwrap();
+        assert_eq!(
+            Vec::from(point.field("x").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(-2.5), Some(0.5)]
+        );
+
+        let mut df = df;
+        let mut buf: Vec<u8> = Vec::new();
+        JsonWriter::new(&mut buf).finish(&mut df).unwrap();
+        let df_read = JsonReader::new(Cursor::new(buf))
+            .infer_schema(None)
+            .finish()
+            .unwrap();
+        let df_read = df_read.unnest(&["point"]).unwrap();
+        assert_eq!(df_read.get_column_names(), &["a", "x", "y"]);
+    }
 }
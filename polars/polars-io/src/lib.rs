@@ -109,8 +109,8 @@ pub(crate) fn finish_reader<R: ArrowReader>(
         if let Some(aggregate) = aggregate {
             let cols = aggregate
                 .iter()
-                .map(|scan_agg| scan_agg.evaluate_batch(&df).unwrap())
-                .collect();
+                .map(|scan_agg| scan_agg.evaluate_batch(&df))
+                .collect::<Result<_>>()?;
             if cfg!(debug_assertions) {
                 df = DataFrame::new(cols).unwrap();
             } else {
@@ -130,17 +130,39 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     if let Some(aggregate) = aggregate {
         let cols = aggregate
             .iter()
-            .map(|scan_agg| scan_agg.finish(&df).unwrap())
-            .collect();
+            .map(|scan_agg| scan_agg.finish(&df))
+            .collect::<Result<_>>()?;
         df = DataFrame::new_no_checks(cols)
     }
 
-    match rechunk {
-        true => Ok(df.agg_chunks()),
-        false => Ok(df),
+    if rechunk {
+        df = df.agg_chunks();
     }
+    Ok(set_sorted_flags(df))
 }
 
+/// Scan the numeric and temporal columns and set the sortedness flag of the ones that are sorted,
+/// so that operations on data that was written in sorted order, like time series, can skip work.
+#[cfg(any(
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "parquet",
+    feature = "json"
+))]
+pub fn set_sorted_flags(df: DataFrame) -> DataFrame {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let mut s = s.clone();
+            s.detect_sorted();
+            s
+        })
+        .collect();
+    DataFrame::new_no_checks(columns)
+}
+
+#[derive(Debug)]
 pub enum ScanAggregation {
     Sum {
         column: String,
@@ -170,7 +192,7 @@ impl ScanAggregation {
     pub(crate) fn evaluate_batch(&self, df: &DataFrame) -> Result<Series> {
         use ScanAggregation::*;
         let s = match self {
-            Sum { column, .. } => df.column(column)?.sum_as_series(),
+            Sum { column, .. } => df.column(column)?.try_sum_as_series()?,
             Min { column, .. } => df.column(column)?.min_as_series(),
             Max { column, .. } => df.column(column)?.max_as_series(),
             First { column, .. } => df.column(column)?.head(Some(1)),
@@ -184,7 +206,7 @@ impl ScanAggregation {
         use ScanAggregation::*;
         match self {
             Sum { column, alias } => {
-                let mut s = df.column(column)?.sum_as_series();
+                let mut s = df.column(column)?.try_sum_as_series()?;
                 if let Some(alias) = alias {
                     s.rename(alias);
                 }
@@ -14,10 +14,19 @@
 //! }
 //! ```
 //!
+//! ## Decimals
+//!
+//! The arrow parquet writer can't write decimals. The [ParquetWriter] writes decimal columns as
+//! their exact text representation and stores their precision and scale in the key-value metadata
+//! of the file, which the [ParquetReader] uses to read them back as decimals. `DECIMAL` columns
+//! written by other tools are read as decimals as well.
 use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
 use crate::prelude::*;
 use crate::{PhysicalIoExpr, ScanAggregation};
 use arrow::record_batch::RecordBatchReader;
+#[cfg(feature = "dtype-decimal")]
+use parquet_lib::file::metadata::KeyValue;
+use parquet_lib::file::properties::WriterProperties;
 use parquet_lib::file::reader::{FileReader, SerializedFileReader};
 pub use parquet_lib::file::serialized_reader::SliceableCursor;
 use parquet_lib::{
@@ -41,6 +50,66 @@ fn set_batch_size(max_rows: usize, stop_after_n_rows: Option<usize>) -> usize {
     batch_size
 }
 
+/// Prefix of the key-value metadata entries that hold the `precision,scale` of a decimal column.
+#[cfg(feature = "dtype-decimal")]
+const DECIMAL_METADATA_PREFIX: &str = "polars.decimal.";
+
+/// Cast the decimal columns to utf8 and return the metadata to restore them with.
+#[cfg(feature = "dtype-decimal")]
+fn decimals_to_utf8(df: &DataFrame) -> Result<(DataFrame, Vec<KeyValue>)> {
+    let mut metadata = vec![];
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Decimal(precision, scale) => {
+                metadata.push(KeyValue {
+                    key: format!("{}{}", DECIMAL_METADATA_PREFIX, s.name()),
+                    value: Some(format!("{},{}", precision, scale)),
+                });
+                s.cast::<Utf8Type>()
+            }
+            _ => Ok(s.clone()),
+        })
+        .collect::<Result<_>>()?;
+    Ok((DataFrame::new_no_checks(columns), metadata))
+}
+
+/// The names and data types of the decimal columns written by [ParquetWriter].
+#[cfg(feature = "dtype-decimal")]
+fn decimal_fields(metadata: &Option<Vec<KeyValue>>) -> Vec<(String, DataType)> {
+    metadata
+        .iter()
+        .flatten()
+        .filter_map(|kv| {
+            let name = kv.key.strip_prefix(DECIMAL_METADATA_PREFIX)?;
+            let mut parts = kv.value.as_ref()?.split(',');
+            let precision = parts.next()?.parse().ok()?;
+            let scale = parts.next()?.parse().ok()?;
+            Some((name.to_string(), DataType::Decimal(precision, scale)))
+        })
+        .collect()
+}
+
+/// Cast the columns written by [decimals_to_utf8] back to decimals.
+#[cfg(feature = "dtype-decimal")]
+fn utf8_to_decimals(df: DataFrame, decimals: &[(String, DataType)]) -> Result<DataFrame> {
+    if decimals.is_empty() {
+        return Ok(df);
+    }
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(
+            |s| match decimals.iter().find(|(name, _)| name == s.name()) {
+                Some((_, dtype)) => s.cast_with_datatype(dtype),
+                None => Ok(s.clone()),
+            },
+        )
+        .collect::<Result<_>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 /// Read Apache parquet format into a DataFrame.
 pub struct ParquetReader<R> {
     reader: R,
@@ -77,6 +146,9 @@ where
         };
         let batch_size = set_batch_size(batch_size, self.stop_after_n_rows);
 
+        #[cfg(feature = "dtype-decimal")]
+        let decimals = decimal_fields(file_reader.metadata().file_metadata().key_value_metadata());
+
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
         let record_reader = match projection {
             Some(projection) => {
@@ -84,6 +156,26 @@ where
             }
             None => arrow_reader.get_record_reader(batch_size),
         }?;
+
+        // the predicate and the aggregations must see the decimals, not their text
+        #[cfg(feature = "dtype-decimal")]
+        if !decimals.is_empty() {
+            let df = finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)?;
+            let mut df = utf8_to_decimals(df, &decimals)?;
+            if let Some(predicate) = predicate {
+                let s = predicate.evaluate(&df)?;
+                df = df.filter(s.bool()?)?;
+            }
+            if let Some(aggregate) = aggregate {
+                let cols = aggregate
+                    .iter()
+                    .map(|scan_agg| scan_agg.finish(&df))
+                    .collect::<Result<_>>()?;
+                df = DataFrame::new_no_checks(cols);
+            }
+            return Ok(df);
+        }
+
         finish_reader(
             record_reader,
             rechunk,
@@ -102,9 +194,24 @@ where
 
     pub fn schema(self) -> Result<Schema> {
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        #[cfg(feature = "dtype-decimal")]
+        let decimals = decimal_fields(file_reader.metadata().file_metadata().key_value_metadata());
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
-        let schema = arrow_reader.get_schema()?;
-        Ok(schema.into())
+        let schema: Schema = arrow_reader.get_schema()?.into();
+        #[cfg(feature = "dtype-decimal")]
+        let schema = Schema::new(
+            schema
+                .fields()
+                .iter()
+                .map(
+                    |fld| match decimals.iter().find(|(name, _)| name == fld.name()) {
+                        Some((name, dtype)) => Field::new(name, dtype.clone()),
+                        None => fld.clone(),
+                    },
+                )
+                .collect(),
+        );
+        Ok(schema)
     }
 }
 
@@ -142,9 +249,14 @@ where
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
         let n_rows = file_reader.metadata().file_metadata().num_rows() as usize;
         let batch_size = set_batch_size(n_rows, self.stop_after_n_rows);
+        #[cfg(feature = "dtype-decimal")]
+        let decimals = decimal_fields(file_reader.metadata().file_metadata().key_value_metadata());
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
         let record_reader = arrow_reader.get_record_reader(batch_size)?;
-        finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)
+        let df = finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)?;
+        #[cfg(feature = "dtype-decimal")]
+        let df = utf8_to_decimals(df, &decimals)?;
+        Ok(df)
     }
 }
 
@@ -167,8 +279,17 @@ where
 
     /// Write the given DataFrame in the the writer `W`.
     pub fn finish(self, df: &mut DataFrame) -> Result<()> {
-        let mut parquet_writer =
-            ParquetArrowWriter::try_new(self.writer, Arc::new(df.schema().to_arrow()), None)?;
+        let props = WriterProperties::builder();
+        #[cfg(feature = "dtype-decimal")]
+        let (df, props) = {
+            let (df, metadata) = decimals_to_utf8(df)?;
+            (df, props.set_key_value_metadata(Some(metadata)))
+        };
+        let mut parquet_writer = ParquetArrowWriter::try_new(
+            self.writer,
+            Arc::new(df.schema().to_arrow()),
+            Some(props.build()),
+        )?;
 
         let iter = df.iter_record_batches(df.height());
 
@@ -195,4 +316,100 @@ mod test {
             assert_eq!(df.shape(), (3, 2));
         }
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_parquet_struct() {
+        let path = std::env::temp_dir().join("polars_write_and_read_parquet_struct.parquet");
+        let x = Series::new("x", &[Some(1.0), Some(-2.5), None]);
+        let y = Series::new("y", &["a", "b", "c"]);
+        let validity = BooleanChunked::new_from_slice("", &[true, false, true]);
+        let point = StructChunked::new("point", &[x, y])
+            .unwrap()
+            .with_validity(validity)
+            .unwrap();
+        let mut df =
+            DataFrame::new(vec![Series::new("a", &[1, 2, 3]), point.into_series()]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let point = df_read.column("point").unwrap();
+        assert_eq!(point.dtype(), df.column("point").unwrap().dtype());
+        let is_valid = point.is_not_null();
+        assert_eq!(Vec::from(&is_valid), &[Some(true), Some(false), Some(true)]);
+        // parquet doesn't store the values of the fields below a null struct value
+        let expected = df.filter(&is_valid).unwrap().unnest(&["point"]).unwrap();
+        let df_read = df_read
+            .filter(&is_valid)
+            .unwrap()
+            .unnest(&["point"])
+            .unwrap();
+        assert!(expected.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn write_and_read_parquet_binary() {
+        use parquet_lib::basic::Type as PhysicalType;
+        use parquet_lib::file::reader::{FileReader, SerializedFileReader};
+
+        let path = std::env::temp_dir().join("polars_write_and_read_parquet_binary.parquet");
+        let bytes =
+            BinaryChunked::new_from_opt_slice("bytes", &[Some(&b"\x00\xff"[..]), None, Some(b"")]);
+        let mut df = DataFrame::new(vec![bytes.into_series()]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        // binary columns are stored as BYTE_ARRAY
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        let column = reader.metadata().file_metadata().schema_descr().column(0);
+        assert_eq!(column.physical_type(), PhysicalType::BYTE_ARRAY);
+
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(df_read.column("bytes").unwrap().dtype(), &DataType::Binary);
+        assert!(df.frame_equal_missing(&df_read));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn write_and_read_parquet_decimal() {
+        let path = std::env::temp_dir().join("polars_write_and_read_parquet_decimal.parquet");
+        let amounts =
+            DecimalChunked::new_from_opt_iter("amount", vec![Some(1234), None, Some(-5)], 10, 2)
+                .unwrap();
+        let mut df =
+            DataFrame::new(vec![amounts.into_series(), Series::new("id", &[1, 2, 3])]).unwrap();
+
+        ParquetWriter::new(File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+
+        let schema = ParquetReader::new(File::open(&path).unwrap())
+            .schema()
+            .unwrap();
+        assert_eq!(
+            schema.field_with_name("amount").unwrap().data_type(),
+            &DataType::Decimal(10, 2)
+        );
+
+        let df_read = ParquetReader::new(File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            df_read.column("amount").unwrap().dtype(),
+            &DataType::Decimal(10, 2)
+        );
+        assert!(df.frame_equal_missing(&df_read));
+    }
 }
@@ -84,6 +84,36 @@ impl<T> Deref for NoEq<T> {
     }
 }
 
+pub trait UdfOutputField: Send + Sync {
+    fn get_field(&self, _input_schema: &Schema, cntxt: Context, field: &Field) -> Option<Field>;
+}
+
+impl<F> UdfOutputField for F
+where
+    F: Fn(&Schema, Context, &Field) -> Option<Field> + Send + Sync,
+{
+    fn get_field(&self, input_schema: &Schema, cntxt: Context, field: &Field) -> Option<Field> {
+        self(input_schema, cntxt, field)
+    }
+}
+
+/// A fixed output type, as the former `output_type` field of `Expr::Udf`. `None` keeps the
+/// field of the input.
+impl UdfOutputField for Option<DataType> {
+    fn get_field(&self, _input_schema: &Schema, _cntxt: Context, field: &Field) -> Option<Field> {
+        match self {
+            Some(output_type) => Some(Field::new(field.name(), output_type.clone())),
+            None => Some(field.clone()),
+        }
+    }
+}
+
+impl From<Option<DataType>> for NoEq<Arc<dyn UdfOutputField>> {
+    fn from(output_type: Option<DataType>) -> Self {
+        NoEq::new(Arc::new(output_type))
+    }
+}
+
 pub trait BinaryUdfOutputField: Send + Sync {
     fn get_field(
         &self,
@@ -125,6 +155,13 @@ pub enum AggExpr {
     AggGroups(Box<Expr>),
     Std(Box<Expr>),
     Var(Box<Expr>),
+    ArgMin(Box<Expr>),
+    ArgMax(Box<Expr>),
+    Mode(Box<Expr>),
+    Product(Box<Expr>),
+    Any(Box<Expr>),
+    All(Box<Expr>),
+    NNulls(Box<Expr>),
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -145,6 +182,13 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e) => e,
             Var(e) => e,
+            ArgMin(e) => e,
+            ArgMax(e) => e,
+            Mode(e) => e,
+            Product(e) => e,
+            Any(e) => e,
+            All(e) => e,
+            NNulls(e) => e,
         }
     }
 }
@@ -191,7 +235,8 @@ pub enum Expr {
     Udf {
         input: Box<Expr>,
         function: NoEq<Arc<dyn SeriesUdf>>,
-        output_type: Option<DataType>,
+        /// Delays output type evaluation until input schema is known.
+        output_field: NoEq<Arc<dyn UdfOutputField>>,
     },
     Shift {
         input: Box<Expr>,
@@ -209,7 +254,7 @@ pub enum Expr {
     Window {
         /// Also has the input. i.e. avg("foo")
         function: Box<Expr>,
-        partition_by: Box<Expr>,
+        partition_by: Vec<Expr>,
         order_by: Option<Box<Expr>>,
     },
     Wildcard,
@@ -291,6 +336,13 @@ impl fmt::Debug for Expr {
                     Count(expr) => write!(f, "AGG COUNT {:?}", expr),
                     Var(expr) => write!(f, "AGG VAR {:?}", expr),
                     Std(expr) => write!(f, "AGG STD {:?}", expr),
+                    ArgMin(expr) => write!(f, "AGG ARG MIN {:?}", expr),
+                    ArgMax(expr) => write!(f, "AGG ARG MAX {:?}", expr),
+                    Mode(expr) => write!(f, "AGG MODE {:?}", expr),
+                    Product(expr) => write!(f, "AGG PRODUCT {:?}", expr),
+                    Any(expr) => write!(f, "AGG ANY {:?}", expr),
+                    All(expr) => write!(f, "AGG ALL {:?}", expr),
+                    NNulls(expr) => write!(f, "AGG N NULLS {:?}", expr),
                     Quantile { expr, .. } => write!(f, "AGG QUANTILE {:?}", expr),
                 }
             }
@@ -583,7 +635,22 @@ impl Expr {
         Expr::Udf {
             input: Box::new(self),
             function: NoEq::new(Arc::new(function)),
-            output_type,
+            output_field: output_type.into(),
+        }
+    }
+
+    /// Apply a function/closure once the logical plan get executed, where the output field is
+    /// determined from the input field when the schema is known. Return `None` from
+    /// `output_field` if the input field is not supported.
+    pub fn map_lazy_field<F, Fld>(self, function: F, output_field: Fld) -> Self
+    where
+        F: SeriesUdf + 'static,
+        Fld: Fn(&Schema, Context, &Field) -> Option<Field> + Send + Sync + 'static,
+    {
+        Expr::Udf {
+            input: Box::new(self),
+            function: NoEq::new(Arc::new(function)),
+            output_field: NoEq::new(Arc::new(output_field)),
         }
     }
 
@@ -679,8 +746,192 @@ impl Expr {
         self.map(move |s: Series| Ok(s.cum_max(reverse)), None)
     }
 
+    /// Hash every value with a hasher seeded by `seed`. See
+    /// [Series::hash](polars_core::series::Series::hash).
+    pub fn hash(self, seed: u64) -> Self {
+        self.map(
+            move |s: Series| s.hash(seed).map(|ca| ca.into_series()),
+            Some(DataType::UInt64),
+        )
+    }
+
+    /// Apply a rolling sum. See:
+    /// [ChunkedArray::rolling_sum](polars_core::prelude::ChunkWindow::rolling_sum).
+    pub fn rolling_sum(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_sum(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            None,
+        )
+    }
+
+    /// Apply a rolling mean. See:
+    /// [ChunkedArray::rolling_mean](polars_core::prelude::ChunkWindow::rolling_mean).
+    pub fn rolling_mean(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_mean(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            None,
+        )
+    }
+
+    /// Apply a rolling min. See:
+    /// [ChunkedArray::rolling_min](polars_core::prelude::ChunkWindow::rolling_min).
+    pub fn rolling_min(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_min(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            None,
+        )
+    }
+
+    /// Apply a rolling max. See:
+    /// [ChunkedArray::rolling_max](polars_core::prelude::ChunkWindow::rolling_max).
+    pub fn rolling_max(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_max(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            None,
+        )
+    }
+
+    /// Apply a rolling variance. See:
+    /// [ChunkedArray::rolling_var](polars_core::prelude::ChunkWindow::rolling_var).
+    pub fn rolling_var(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_var(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Apply a rolling standard deviation. See:
+    /// [ChunkedArray::rolling_std](polars_core::prelude::ChunkWindow::rolling_std).
+    pub fn rolling_std(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_std(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Apply a rolling median. See:
+    /// [ChunkedArray::rolling_median](polars_core::prelude::ChunkWindow::rolling_median).
+    pub fn rolling_median(
+        self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_median(window_size, weight.as_deref(), ignore_null, min_periods)
+            },
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Apply a rolling quantile. See:
+    /// [ChunkedArray::rolling_quantile](polars_core::prelude::ChunkWindow::rolling_quantile).
+    pub fn rolling_quantile(
+        self,
+        quantile: f64,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Self {
+        let weight = weight.map(|w| w.to_vec());
+        self.map(
+            move |s: Series| {
+                s.rolling_quantile(
+                    quantile,
+                    window_size,
+                    weight.as_deref(),
+                    ignore_null,
+                    min_periods,
+                )
+            },
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Apply a rolling aggregation over a time based window, given by a `Date64` `index` column
+    /// that is sorted in ascending order. The `duration` of the window is given by a string,
+    /// e.g. "3d" or "1h30m". This can also be used in a window function with [over](Expr::over).
+    ///
+    /// See: [Series::rolling_by_time](polars_core::series::Series::rolling_by_time).
+    #[cfg(feature = "dtype-date64")]
+    pub fn rolling_by_time(
+        self,
+        index: Expr,
+        duration: &str,
+        agg: RollingAgg,
+        min_periods: u32,
+    ) -> Self {
+        let duration = duration.to_string();
+        map_binary_lazy_field(
+            self,
+            index,
+            move |s, index| s.rolling_by_time(&index, &duration, agg, min_periods),
+            |_, _, field, _| Some(Field::new(field.name(), DataType::Float64)),
+        )
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a groupby + aggregation + self join.
+    /// An aggregation is broadcasted to all the rows of its group, other expressions, such as
+    /// `cum_sum` and `shift`, are applied to every group and keep the original row positions.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
     ///
     /// # Example
@@ -739,13 +990,33 @@ impl Expr {
     /// ╰────────┴────────╯
     /// ```
     pub fn over(self, partition_by: Expr) -> Self {
+        self.over_multi([partition_by])
+    }
+
+    /// Apply window function over a subgroup that is determined by several columns.
+    ///
+    /// See [over](Expr::over).
+    pub fn over_multi<E: AsRef<[Expr]>>(self, partition_by: E) -> Self {
         Expr::Window {
             function: Box::new(self),
-            partition_by: Box::new(partition_by),
+            partition_by: partition_by.as_ref().to_vec(),
             order_by: None,
         }
     }
 
+    /// Apply window function over a subgroup, where the rows in every group are sorted by `order_by`.
+    /// The ordering determines the result of order dependent expressions, such as
+    /// `cum_sum`, `shift`, `first` and `last`.
+    ///
+    /// See [over](Expr::over).
+    pub fn over_ordered<E: AsRef<[Expr]>>(self, partition_by: E, order_by: Expr) -> Self {
+        Expr::Window {
+            function: Box::new(self),
+            partition_by: partition_by.as_ref().to_vec(),
+            order_by: Some(Box::new(order_by)),
+        }
+    }
+
     /// Shift the values in the array by some period. See [the eager implementation](polars_core::series::SeriesTrait::fill_none).
     pub fn fill_none(self, fill_value: Expr) -> Self {
         let name = output_name(&self).unwrap();
@@ -771,6 +1042,46 @@ impl Expr {
         AggExpr::Var(Box::new(self)).into()
     }
 
+    /// Get the index of the minimal value
+    /// or
+    /// Get the index of the minimal value within every group.
+    pub fn arg_min(self) -> Self {
+        AggExpr::ArgMin(Box::new(self)).into()
+    }
+
+    /// Get the index of the maximal value
+    /// or
+    /// Get the index of the maximal value within every group.
+    pub fn arg_max(self) -> Self {
+        AggExpr::ArgMax(Box::new(self)).into()
+    }
+
+    /// Get the most frequent value. Null values are ignored.
+    pub fn mode(self) -> Self {
+        AggExpr::Mode(Box::new(self)).into()
+    }
+
+    /// Compute the product of the values. Integers are multiplied as `i64`, or as `u64` for
+    /// `UInt64` values, and a product that overflows is null.
+    pub fn product(self) -> Self {
+        AggExpr::Product(Box::new(self)).into()
+    }
+
+    /// Check if any of the boolean values is true.
+    pub fn any(self) -> Self {
+        AggExpr::Any(Box::new(self)).into()
+    }
+
+    /// Check if all of the boolean values are true. Null values are ignored.
+    pub fn all(self) -> Self {
+        AggExpr::All(Box::new(self)).into()
+    }
+
+    /// Count the null values.
+    pub fn n_nulls(self) -> Self {
+        AggExpr::NNulls(Box::new(self)).into()
+    }
+
     /// Get a mask of duplicated values
     #[allow(clippy::wrong_self_convention)]
     pub fn is_duplicated(self) -> Self {
@@ -825,6 +1136,159 @@ impl Expr {
         )
     }
 
+    /// Rank the values. See [the eager implementation](polars_core::series::Series::rank).
+    pub fn rank(self, method: RankMethod, descending: bool) -> Expr {
+        let output_type = match method {
+            RankMethod::Average => DataType::Float64,
+            _ => DataType::UInt32,
+        };
+        self.map(
+            move |s: Series| Ok(s.rank(method, descending)),
+            Some(output_type),
+        )
+    }
+
+    /// Bin the values in a Categorical column. See [the eager implementation](polars_core::series::Series::cut).
+    pub fn cut(self, breaks: Vec<f64>, labels: Option<Vec<String>>) -> Expr {
+        let function = move |s: Series| {
+            let labels: Option<Vec<_>> = labels
+                .as_ref()
+                .map(|labels| labels.iter().map(|l| l.as_str()).collect());
+            s.cut(&breaks, labels.as_deref())
+        };
+        self.map(function, Some(DataType::Categorical))
+    }
+
+    /// Bin the values by their quantiles in a Categorical column. See [the eager implementation](polars_core::series::Series::qcut).
+    pub fn qcut(self, quantiles: Vec<f64>, labels: Option<Vec<String>>) -> Expr {
+        let function = move |s: Series| {
+            let labels: Option<Vec<_>> = labels
+                .as_ref()
+                .map(|labels| labels.iter().map(|l| l.as_str()).collect());
+            s.qcut(&quantiles, labels.as_deref())
+        };
+        self.map(function, Some(DataType::Categorical))
+    }
+
+    /// Get the categories of a Categorical column. See [the eager implementation](polars_core::series::Series::get_categories).
+    pub fn cat_get_categories(self) -> Expr {
+        self.map(|s: Series| s.get_categories(), Some(DataType::Utf8))
+    }
+
+    /// Set the order that is used to sort a Categorical column and take its minimum and maximum.
+    pub fn cat_set_ordering(self, ordering: CategoricalOrdering) -> Expr {
+        self.map(move |s: Series| s.set_ordering(ordering), None)
+    }
+
+    /// Give the categories of a Categorical column new names. See [the eager implementation](polars_core::series::Series::rename_categories).
+    pub fn cat_rename_categories(self, names: Vec<String>) -> Expr {
+        let function = move |s: Series| {
+            let names: Vec<_> = names.iter().map(|name| name.as_str()).collect();
+            s.rename_categories(&names)
+        };
+        self.map(function, None)
+    }
+
+    /// Replace the categories of a Categorical column given as `(old, new)` pairs.
+    /// See [the eager implementation](polars_core::series::Series::remap).
+    pub fn cat_remap(self, mapping: Vec<(String, String)>) -> Expr {
+        let function = move |s: Series| {
+            let mapping: Vec<_> = mapping
+                .iter()
+                .map(|(old, new)| (old.as_str(), new.as_str()))
+                .collect();
+            s.remap(&mapping)
+        };
+        self.map(function, None)
+    }
+
+    /// Get the length of every list. See [the eager implementation](polars_core::prelude::ListChunked::lst_lengths).
+    pub fn list_lengths(self) -> Expr {
+        self.map(
+            |s: Series| Ok(s.list()?.lst_lengths().into_series()),
+            Some(DataType::UInt32),
+        )
+    }
+
+    /// Get the sum of every list. See [the eager implementation](polars_core::prelude::ListChunked::lst_sum).
+    pub fn list_sum(self) -> Expr {
+        self.map_lazy_field(|s: Series| s.list()?.lst_sum(), list_agg_field)
+    }
+
+    /// Get the mean of every list.
+    pub fn list_mean(self) -> Expr {
+        self.map(|s: Series| s.list()?.lst_mean(), Some(DataType::Float64))
+    }
+
+    /// Get the minimum of every list. See [the eager implementation](polars_core::prelude::ListChunked::lst_min).
+    pub fn list_min(self) -> Expr {
+        self.map_lazy_field(|s: Series| s.list()?.lst_min(), list_agg_field)
+    }
+
+    /// Get the maximum of every list. See [the eager implementation](polars_core::prelude::ListChunked::lst_max).
+    pub fn list_max(self) -> Expr {
+        self.map_lazy_field(|s: Series| s.list()?.lst_max(), list_agg_field)
+    }
+
+    /// Get the value at `index` of every list. A negative index counts from the end of the list.
+    pub fn list_get(self, index: i64) -> Expr {
+        self.map_lazy_field(
+            move |s: Series| Ok(s.list()?.lst_get(index)),
+            |_: &Schema, _: Context, field: &Field| list_inner_field(field),
+        )
+    }
+
+    /// Slice every list. A negative `offset` counts from the end of the list.
+    pub fn list_slice(self, offset: i64, length: usize) -> Expr {
+        self.map(
+            move |s: Series| Ok(s.list()?.lst_slice(offset, length).into_series()),
+            None,
+        )
+    }
+
+    /// Check if every list contains `item`. See [the eager implementation](polars_core::prelude::ListChunked::lst_contains).
+    pub fn list_contains(self, item: Series) -> Expr {
+        self.map(
+            move |s: Series| s.list()?.lst_contains(&item).map(|ca| ca.into_series()),
+            Some(DataType::Boolean),
+        )
+    }
+
+    /// Sort the values within every list.
+    pub fn list_sort(self, reverse: bool) -> Expr {
+        self.map(
+            move |s: Series| Ok(s.list()?.lst_sort(reverse).into_series()),
+            None,
+        )
+    }
+
+    /// Keep the unique values of every list.
+    pub fn list_unique(self) -> Expr {
+        self.map(
+            |s: Series| s.list()?.lst_unique().map(|ca| ca.into_series()),
+            None,
+        )
+    }
+
+    /// Join the strings of every list with `separator`.
+    pub fn list_join(self, separator: &str) -> Expr {
+        let separator = separator.to_string();
+        self.map(
+            move |s: Series| s.list()?.lst_join(&separator).map(|ca| ca.into_series()),
+            Some(DataType::Utf8),
+        )
+    }
+
+    /// Concatenate the lists of this expression and `other` row by row.
+    pub fn list_concat(self, other: Expr) -> Expr {
+        map_binary_lazy_field(
+            self,
+            other,
+            |l: Series, r: Series| l.list()?.lst_concat(r.list()?).map(|ca| ca.into_series()),
+            |_: &Schema, _: Context, l: &Field, _: &Field| Some(l.clone()),
+        )
+    }
+
     /// Get the year of a Date32/Date64
     #[cfg(feature = "temporal")]
     pub fn year(self) -> Expr {
@@ -938,6 +1402,23 @@ pub fn quantile(name: &str, quantile: f64) -> Expr {
     col(name).quantile(quantile)
 }
 
+/// The field of the values of a list column.
+fn list_inner_field(field: &Field) -> Option<Field> {
+    match field.data_type() {
+        DataType::List(inner) => Some(Field::new(field.name(), inner.into())),
+        _ => None,
+    }
+}
+
+/// The output field of the sum, minimum and maximum of every list. Booleans are counted as
+/// `UInt32`, like in the aggregations of a groupby.
+fn list_agg_field(_: &Schema, _: Context, field: &Field) -> Option<Field> {
+    list_inner_field(field).map(|fld| match fld.data_type() {
+        DataType::Boolean => Field::new(fld.name(), DataType::UInt32),
+        _ => fld,
+    })
+}
+
 /// Apply a closure on the two columns that are evaluated from `Expr` a and `Expr` b.
 pub fn map_binary<F: 'static>(a: Expr, b: Expr, f: F, output_field: Option<Field>) -> Expr
 where
@@ -6,20 +6,24 @@ use std::sync::Mutex;
 use ahash::RandomState;
 use itertools::Itertools;
 
+pub use polars_core::frame::hash_join::JoinOptions;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
-use polars_core::toggle_string_cache;
+use polars_core::StringCacheHolder;
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
+use crate::logical_plan::optimizer::join_reorder::JoinReorder;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
+    slice_pushdown::SlicePushDown,
 };
+use crate::logical_plan::replace_wildcard_with_column;
 use crate::prelude::aggregate_scan_projections::agg_projection;
 use crate::prelude::simplify_expr::SimplifyBooleanRule;
-use crate::utils::combine_predicates_expr;
+use crate::utils::{combine_predicates_expr, output_name};
 use crate::{logical_plan::FETCH_ROWS, prelude::*};
 
 #[derive(Clone)]
@@ -119,21 +123,6 @@ impl<'a> LazyCsvReader<'a> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct JoinOptions {
-    pub allow_parallel: bool,
-    pub force_parallel: bool,
-}
-
-impl Default for JoinOptions {
-    fn default() -> Self {
-        JoinOptions {
-            allow_parallel: true,
-            force_parallel: false,
-        }
-    }
-}
-
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -183,6 +172,8 @@ pub struct OptState {
     pub agg_scan_projection: bool,
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
+    pub slice_pushdown: bool,
+    pub join_reorder: bool,
 }
 
 impl Default for OptState {
@@ -196,6 +187,8 @@ impl Default for OptState {
             agg_scan_projection: false,
             aggregate_pushdown: false,
             global_string_cache: true,
+            slice_pushdown: true,
+            join_reorder: true,
         }
     }
 }
@@ -237,11 +230,11 @@ impl LazyFrame {
         LogicalPlanBuilder::from(self.logical_plan)
     }
 
-    fn get_opt_state(&self) -> OptState {
+    pub(crate) fn get_opt_state(&self) -> OptState {
         self.opt_state
     }
 
-    fn from_logical_plan(logical_plan: LogicalPlan, opt_state: OptState) -> Self {
+    pub(crate) fn from_logical_plan(logical_plan: LogicalPlan, opt_state: OptState) -> Self {
         LazyFrame {
             logical_plan,
             opt_state,
@@ -260,6 +253,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle slice pushdown optimization.
+    pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.slice_pushdown = toggle;
+        self
+    }
+
+    /// Toggle join reordering optimization.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Toggle type coercion optimization.
     pub fn with_type_coercion(mut self, toggle: bool) -> Self {
         self.opt_state.type_coercion = toggle;
@@ -321,6 +326,25 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Sort the DataFrame by multiple columns. See
+    /// [eager](polars_core::frame::DataFrame::sort_by_columns).
+    pub fn sort_by_columns(self, by_columns: Vec<String>, reverse: Vec<bool>) -> Self {
+        if let ([by_column], [reverse]) = (by_columns.as_slice(), reverse.as_slice()) {
+            return self.sort(by_column, *reverse);
+        }
+        let schema = self.logical_plan.schema().clone();
+        // the sort columns may not be projected away
+        let optimizations = AllowedOptimizations {
+            projection_pushdown: false,
+            ..Default::default()
+        };
+        self.map(
+            move |df: DataFrame| df.sort_by_columns(&by_columns, &reverse),
+            Some(optimizations),
+            Some(schema),
+        )
+    }
+
     /// Reverse the DataFrame
     ///
     /// # Example
@@ -413,6 +437,8 @@ impl LazyFrame {
         let simplify_expr = self.opt_state.simplify_expr;
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
+        let slice_pushdown = self.opt_state.slice_pushdown;
+        let join_reorder = self.opt_state.join_reorder;
 
         let logical_plan = self.get_plan_builder().build();
 
@@ -444,6 +470,15 @@ impl LazyFrame {
             lp_arena.replace(lp_top, alp);
         }
 
+        if slice_pushdown {
+            let slice_pushdown_opt = SlicePushDown::default();
+            let alp = lp_arena.take(lp_top);
+            let alp = slice_pushdown_opt
+                .optimize(alp, lp_arena, expr_arena)
+                .expect("slice pushdown failed");
+            lp_arena.replace(lp_top, alp);
+        }
+
         if type_coercion {
             rules.push(Box::new(TypeCoercionRule {}))
         }
@@ -457,6 +492,10 @@ impl LazyFrame {
             rules.push(Box::new(AggregatePushdown::new()))
         }
 
+        if join_reorder {
+            rules.push(Box::new(JoinReorder {}))
+        }
+
         if agg_scan_projection {
             // scan the LP to aggregate all the column used in scans
             // these columns will be added to the state of the AggScanProjection rule
@@ -512,7 +551,12 @@ impl LazyFrame {
         let mut lp_arena = Arena::with_capacity(512);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
 
-        toggle_string_cache(use_string_cache);
+        // keep the global string cache alive until the result is produced
+        let _holder = if use_string_cache {
+            Some(StringCacheHolder::hold())
+        } else {
+            None
+        };
         let planner = DefaultPlanner::default();
         let mut physical_plan =
             planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
@@ -520,11 +564,7 @@ impl LazyFrame {
             64,
             RandomState::default(),
         )));
-        let out = physical_plan.execute(&cache);
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
-        }
-        out
+        physical_plan.execute(&cache)
     }
 
     /// Filter by some predicate expression.
@@ -615,6 +655,7 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             keys: by,
+            apply_columns: None,
         }
     }
 
@@ -724,7 +765,6 @@ impl LazyFrame {
         how: JoinType,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
-        let opts = options.unwrap_or_default();
         let lp = self
             .get_plan_builder()
             .join(
@@ -732,8 +772,7 @@ impl LazyFrame {
                 how,
                 left_on,
                 right_on,
-                opts.allow_parallel,
-                opts.force_parallel,
+                options.unwrap_or_default(),
             )
             .build();
         Self::from_logical_plan(lp, opt_state)
@@ -840,6 +879,58 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Collect the rows back into lists per group of `by`, the inverse of
+    /// [explode](LazyFrame::explode). All columns that are not in `by` become list columns.
+    ///
+    /// Every expression in `by` must have an output name, otherwise collecting the query
+    /// returns an error.
+    pub fn implode(self, by: Vec<Expr>) -> LazyFrame {
+        let keys = match by.iter().map(output_name).collect::<Result<Vec<_>>>() {
+            Ok(keys) => keys,
+            Err(err) => {
+                let msg = err.to_string();
+                return self.map(
+                    move |_: DataFrame| Err(PolarsError::Other(msg.clone().into())),
+                    None,
+                    None,
+                );
+            }
+        };
+        let aggs = self
+            .logical_plan
+            .schema()
+            .fields()
+            .iter()
+            .filter(|field| !keys.iter().any(|key| key.as_str() == field.name()))
+            .map(|field| col(field.name()).list().alias(field.name()))
+            .collect();
+        self.groupby(by).agg(aggs)
+    }
+
+    /// Alias for [implode](LazyFrame::implode).
+    pub fn agg_list(self, by: Vec<Expr>) -> LazyFrame {
+        self.implode(by)
+    }
+
+    /// Summary statistics of the columns. [See eager describe](polars_core::frame::DataFrame::describe).
+    ///
+    /// The statistics are computed over all rows, so no predicates or projections are pushed
+    /// down past this operation.
+    pub fn describe(self, percentiles: &[f64]) -> LazyFrame {
+        let schema = describe_schema(&self.logical_plan.schema());
+        let percentiles = percentiles.to_vec();
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            ..Default::default()
+        };
+        self.map(
+            move |df: DataFrame| df.describe(&percentiles),
+            Some(optimizations),
+            Some(schema),
+        )
+    }
+
     /// Drop duplicate rows. [See eager](polars_core::prelude::DataFrame::drop_duplicates).
     pub fn drop_duplicates(self, maintain_order: bool, subset: Option<Vec<String>>) -> LazyFrame {
         let opt_state = self.get_opt_state();
@@ -889,14 +980,148 @@ impl LazyFrame {
 
     /// Melt the DataFrame from wide to long format
     pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> LazyFrame {
+        self.melt_with(MeltArgs::new(id_vars, value_vars))
+    }
+
+    /// Melt the DataFrame from wide to long format with the given [MeltArgs].
+    ///
+    /// The value columns are selected when the query is built, so the output schema is known
+    /// to the optimizer.
+    pub fn melt_with(self, args: MeltArgs) -> LazyFrame {
         let opt_state = self.get_opt_state();
-        let lp = self
-            .get_plan_builder()
-            .melt(Arc::new(id_vars), Arc::new(value_vars))
-            .build();
+        let lp = self.get_plan_builder().melt(args).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// The rows are grouped by the `index` columns and every value in `pivot_values` becomes a
+    /// new column holding the aggregation of the rows where the `columns` column (cast to
+    /// `Utf8`) equals that value. Rows with null index values form their own group.
+    ///
+    /// The aggregation `agg` must be written on a wildcard: every `col("*")` in it is replaced
+    /// by the values column that is aggregated. Any aggregation expression can be used this way,
+    /// e.g. `col("*").sum()` or `col("*").max() - col("*").min()`. A named column in `agg` is
+    /// not replaced, so every values column would get the aggregation of that column.
+    ///
+    /// The pivot values are declared up front so that the output schema is known before the
+    /// query runs. With a single values column the new columns are named after the pivot
+    /// values, otherwise they are named `{value}_{pivot_value}`. The new columns follow the
+    /// index columns in sorted order. If a new column name is an index column name or occurs
+    /// twice, collecting the query returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(ldf: LazyFrame) -> LazyFrame {
+    ///     ldf.pivot(
+    ///         vec!["city".into()],
+    ///         "month",
+    ///         vec!["rain".into()],
+    ///         col("*").mean(),
+    ///         vec!["jan".into(), "feb".into()],
+    ///     )
+    /// }
+    /// ```
+    pub fn pivot(
+        self,
+        index: Vec<String>,
+        columns: &str,
+        values: Vec<String>,
+        agg: Expr,
+        mut pivot_values: Vec<String>,
+    ) -> LazyFrame {
+        pivot_values.sort();
+        pivot_values.dedup();
+        let keys: Vec<Expr> = index.iter().map(|name| col(name)).collect();
+        let column_name = |value: &str, pivot_value: &str| {
+            if values.len() == 1 {
+                pivot_value.to_string()
+            } else {
+                format!("{}_{}", value, pivot_value)
+            }
+        };
+
+        let mut new_columns = values
+            .iter()
+            .flat_map(|name| {
+                pivot_values
+                    .iter()
+                    .map(move |pivot_value| column_name(name, pivot_value))
+            })
+            .collect::<Vec<_>>();
+        new_columns.sort();
+        let duplicate = new_columns
+            .windows(2)
+            .find(|w| w[0] == w[1])
+            .map(|w| w[0].clone())
+            .or_else(|| {
+                new_columns
+                    .iter()
+                    .find(|name| index.contains(*name))
+                    .cloned()
+            });
+        if let Some(name) = duplicate {
+            return self.map(
+                move |_: DataFrame| {
+                    Err(PolarsError::Duplicate(
+                        format!("the pivot creates the column {} more than once", name).into(),
+                    ))
+                },
+                None,
+                None,
+            );
+        }
+
+        let mut by = keys.clone();
+        by.push(col(columns));
+        let aggs = values
+            .iter()
+            .map(|name| {
+                replace_wildcard_with_column(agg.clone(), Arc::new(name.clone())).alias(name)
+            })
+            .collect();
+        // one row per index and pivot value
+        let aggregated = self.groupby(by).agg(aggs).cache();
+
+        let mut out = aggregated.clone().select(&keys).drop_duplicates(true, None);
+        for pivot_value in &pivot_values {
+            let mut selection = keys.clone();
+            selection.extend(
+                values
+                    .iter()
+                    .map(|name| col(name).alias(&column_name(name, pivot_value))),
+            );
+            let spread = aggregated
+                .clone()
+                .filter(
+                    col(columns)
+                        .cast(DataType::Utf8)
+                        .eq(lit(pivot_value.as_str())),
+                )
+                .select(selection);
+            // null index values are a group of their own, so they must match in the join
+            let options = JoinOptions {
+                join_nulls: true,
+                ..Default::default()
+            };
+            out = out.join(
+                spread,
+                keys.clone(),
+                keys.clone(),
+                Some(options),
+                JoinType::Left,
+            );
+        }
+
+        let mut selection = keys;
+        selection.extend(new_columns.iter().map(|name| col(name)));
+        out.select(selection)
+    }
+
     /// Limit the DataFrame to the first `n` rows. Note if you don't want the rows to be scanned,
     /// use [fetch](LazyFrame::fetch).
     pub fn limit(self, n: usize) -> LazyFrame {
@@ -937,6 +1162,7 @@ pub struct LazyGroupBy {
     pub(crate) logical_plan: LogicalPlan,
     opt_state: OptState,
     keys: Vec<Expr>,
+    apply_columns: Option<Vec<String>>,
 }
 
 impl LazyGroupBy {
@@ -969,12 +1195,75 @@ impl LazyGroupBy {
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Select the columns that are passed to the function of an apply next to the keys,
+    /// like the eager [select](polars_core::frame::groupby::GroupBy::select).
+    ///
+    /// Without a selection the function gets all columns, so projections above the apply can't
+    /// prune its input.
+    pub fn select<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        self.apply_columns = Some(columns.iter().map(|s| s.as_ref().to_string()).collect());
+        self
+    }
+
     pub fn apply<F>(self, f: F) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
     {
+        let apply = GroupByApply {
+            function: Arc::new(f),
+            schema: None,
+            columns: self.apply_columns,
+        };
+        let lp = LogicalPlanBuilder::from(self.logical_plan)
+            .groupby(Arc::new(self.keys), vec![], Some(apply))
+            .build();
+        LazyFrame::from_logical_plan(lp, self.opt_state)
+    }
+
+    /// Apply a function that returns a DataFrame with the given `schema` to every group.
+    ///
+    /// The groups are processed in parallel and the key columns are added to the output,
+    /// repeated for every row the function returns for a group. Because the output schema is
+    /// known, the result can be optimized like any other query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     let schema = Schema::new(vec![Field::new("rain", DataType::Float64)]);
+    ///     // the two days with the most rain of every month
+    ///     df.lazy()
+    ///         .groupby(vec![col("month")])
+    ///         .apply_with_schema(
+    ///             |df| df.sort("rain", true).map(|df| df.head(Some(2))),
+    ///             schema,
+    ///         )
+    /// }
+    /// ```
+    pub fn apply_with_schema<F>(self, f: F, schema: Schema) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        self.agg_and_apply(vec![], f, schema)
+    }
+
+    /// Like [apply_with_schema](LazyGroupBy::apply_with_schema), but also compute aggregations
+    /// over the same groups. The aggregated values are repeated like the key columns and placed
+    /// between the keys and the output of the function.
+    pub fn agg_and_apply<F>(self, aggs: Vec<Expr>, f: F, schema: Schema) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let apply = GroupByApply {
+            function: Arc::new(f),
+            schema: Some(Arc::new(schema)),
+            columns: self.apply_columns,
+        };
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)))
+            .groupby(Arc::new(self.keys), aggs, Some(apply))
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -986,7 +1275,7 @@ mod test {
     use polars_core::utils::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
     use polars_core::*;
 
-    use crate::functions::pearson_corr;
+    use crate::functions::{concat, pearson_corr, ConcatMode};
     use crate::tests::get_df;
 
     use super::*;
@@ -1080,6 +1369,283 @@ mod test {
         dbg!(out);
     }
 
+    #[test]
+    fn test_lazy_melt_with() {
+        let df = df! {
+            "id" => &["a", "b"],
+            "x" => &[1, 2],
+            "y" => &[0.5, 1.5]
+        }
+        .unwrap();
+
+        let args = MeltArgs {
+            id_vars: vec!["id".into()],
+            variable_name: Some("column".into()),
+            value_name: Some("v".into()),
+            ..Default::default()
+        };
+        let lf = df.clone().lazy().melt_with(args);
+        let schema = lf.logical_plan.schema().clone();
+        assert_eq!(
+            schema.field_with_name("column").unwrap().data_type(),
+            &DataType::Categorical
+        );
+        assert_eq!(
+            schema.field_with_name("v").unwrap().data_type(),
+            &DataType::Float64
+        );
+
+        let out = lf
+            .filter(col("v").gt(lit(1.0)))
+            .select(&[col("id"), col("v")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("v").unwrap().f64().unwrap()),
+            &[Some(2.0), Some(1.5)]
+        );
+
+        // arguments that can't be resolved are an error when the query is collected
+        let args = MeltArgs {
+            id_vars: vec!["id".into()],
+            value_vars: vec!["missing".into()],
+            ..Default::default()
+        };
+        assert!(df.clone().lazy().melt_with(args).collect().is_err());
+        let args = MeltArgs {
+            value_regex: Some("(".into()),
+            ..Default::default()
+        };
+        assert!(df.lazy().melt_with(args).collect().is_err());
+    }
+
+    #[test]
+    fn test_lazy_pivot() {
+        let df = df! {
+            "city" => &["a", "a", "a", "b", "c"],
+            "year" => &[2020, 2020, 2020, 2020, 2021],
+            "month" => &["jan", "jan", "feb", "feb", "jan"],
+            "rain" => &[1, 2, 3, 4, 5],
+            "sun" => &[10, 20, 30, 40, 50]
+        }
+        .unwrap();
+
+        let lf = df.clone().lazy().pivot(
+            vec!["city".into(), "year".into()],
+            "month",
+            vec!["rain".into(), "sun".into()],
+            col("*").sum(),
+            vec!["jan".into(), "feb".into()],
+        );
+        // the schema is known before the query runs
+        let schema = lf.logical_plan.schema().clone();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(
+            names,
+            &["city", "year", "rain_feb", "rain_jan", "sun_feb", "sun_jan"]
+        );
+
+        let out = lf.sort("city", false).collect().unwrap();
+        assert_eq!(out.get_column_names(), names);
+        assert_eq!(
+            Vec::from(out.column("rain_feb").unwrap().i32().unwrap()),
+            &[Some(3), Some(4), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("sun_jan").unwrap().i32().unwrap()),
+            &[Some(30), None, Some(50)]
+        );
+
+        // a single values column is named after the pivot values
+        let out = df
+            .lazy()
+            .pivot(
+                vec!["city".into()],
+                "month",
+                vec!["rain".into()],
+                col("*").first(),
+                vec!["jan".into(), "feb".into()],
+            )
+            .sort("city", false)
+            .collect()
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["city", "feb", "jan"]);
+        assert_eq!(
+            Vec::from(out.column("jan").unwrap().i32().unwrap()),
+            &[Some(1), None, Some(5)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_pivot_nulls_and_names() {
+        let df = df! {
+            "city" => &[Some("a"), None, Some("a"), None, Some("a"), None],
+            "month" => &["jan", "jan", "jan", "jan", "feb", "feb"],
+            "rain" => &[1, 2, 5, 7, 3, 4]
+        }
+        .unwrap();
+
+        // rows with a null index value keep their pivoted values
+        let out = df
+            .clone()
+            .lazy()
+            .pivot(
+                vec!["city".into()],
+                "month",
+                vec!["rain".into()],
+                col("*").max() - col("*").min(),
+                vec!["jan".into(), "feb".into()],
+            )
+            .collect()
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["city", "feb", "jan"]);
+        assert_eq!(out.height(), 2);
+        assert_eq!(out.column("city").unwrap().null_count(), 1);
+        assert_eq!(
+            Vec::from(&out.column("jan").unwrap().i32().unwrap().sort(false)),
+            &[Some(4), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("feb").unwrap().i32().unwrap()),
+            &[Some(0), Some(0)]
+        );
+
+        // a pivot value can't replace an index column
+        let lf = df.lazy().pivot(
+            vec!["city".into()],
+            "month",
+            vec!["rain".into()],
+            col("*").sum(),
+            vec!["jan".into(), "city".into()],
+        );
+        assert!(lf.collect().is_err());
+    }
+
+    #[test]
+    fn test_lazy_concat() {
+        let jan = df! {
+            "day" => &[1, 2],
+            "rain" => &[0.5, 1.0]
+        }
+        .unwrap();
+        let feb = df! {
+            "day" => &[1i64, 2, 3],
+            "sun" => &[3, 4, 5]
+        }
+        .unwrap();
+
+        let out = concat(
+            vec![jan.clone().lazy(), feb.clone().lazy()],
+            ConcatMode::Diagonal,
+        )
+        .unwrap()
+        .filter(col("day").gt(lit(1)))
+        .select(&[col("day"), col("rain")])
+        .collect()
+        .unwrap();
+        assert_eq!(out.column("day").unwrap().dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("rain").unwrap().f64().unwrap()),
+            &[Some(1.0), None, None]
+        );
+
+        // the projection and predicate are pushed into every input
+        let out = concat(
+            vec![jan.clone().lazy(), jan.clone().lazy()],
+            ConcatMode::Vertical,
+        )
+        .unwrap()
+        .filter(col("day").eq(lit(2)))
+        .select(&[col("rain")])
+        .collect()
+        .unwrap();
+        assert_eq!(
+            Vec::from(out.column("rain").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(1.0)]
+        );
+
+        // an input that is cast to the union schema is filtered after the cast
+        let codes = df! {
+            "day" => &["3", "2"]
+        }
+        .unwrap();
+        let out = concat(
+            vec![jan.clone().lazy().select(&[col("day")]), codes.lazy()],
+            ConcatMode::Vertical,
+        )
+        .unwrap()
+        .filter(col("day").eq(lit("2")))
+        .collect()
+        .unwrap();
+        assert_eq!(
+            Vec::from(out.column("day").unwrap().utf8().unwrap()),
+            &[Some("2"), Some("2")]
+        );
+
+        let out = concat(
+            vec![jan.lazy(), feb.lazy().select(&[col("sun")])],
+            ConcatMode::Horizontal,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+        assert_eq!(out.shape(), (3, 3));
+        assert_eq!(out.column("rain").unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_lazy_explode_implode() {
+        let df = df! {
+            "k" => &[1, 2],
+            "a" => &[Series::new("", &[1, 2]), Series::new("", &[3])],
+            "b" => &[Series::new("", &["x", "y"]), Series::new("", &["z"])]
+        }
+        .unwrap();
+
+        let exploded = df
+            .clone()
+            .lazy()
+            .explode(&[col("a"), col("b")])
+            .collect()
+            .unwrap();
+        assert_eq!(exploded.shape(), (3, 3));
+
+        let out = exploded
+            .lazy()
+            .implode(vec![col("k")])
+            .sort("k", false)
+            .collect()
+            .unwrap();
+        assert!(out.frame_equal(&df));
+
+        // a key without an output name is an error instead of a panic
+        let lf = df.lazy().implode(vec![lit(1) + lit(2)]);
+        assert!(lf.collect().is_err());
+    }
+
+    #[test]
+    fn test_lazy_describe() {
+        let df = df! {
+            "a" => &[1, 2, 3, 4],
+            "b" => &["x", "y", "x", "z"]
+        }
+        .unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .describe(&[0.25, 0.75])
+            .select(&[col("describe"), col("b")])
+            .collect()
+            .unwrap();
+        let expected = df
+            .describe(&[0.25, 0.75])
+            .unwrap()
+            .select(&["describe", "b"])
+            .unwrap();
+        assert!(out.frame_equal_missing(&expected));
+    }
+
     #[test]
     fn test_lazy_drop_nulls() {
         let df = df! {
@@ -1776,22 +2342,171 @@ mod test {
     }
 
     #[test]
-    fn test_lazy_shift_and_fill() {
+    fn test_lazy_groupby_apply_with_schema() {
         let df = df! {
-            "A" => &[1, 2, 3, 4, 5],
-            "B" => &[5, 4, 3, 2, 1]
+            "fruits" => &["banana", "banana", "apple", "apple", "banana"],
+            "B" => &[5, 4, 3, 2, 1],
+            "cars" => &["beetle", "audi", "beetle", "beetle", "beetle"],
+            "unused" => &[1, 2, 3, 4, 5]
         }
         .unwrap();
-        let out = df
-            .clone()
-            .lazy()
-            .with_column(col("A").shift_and_fill(2, col("B").mean()))
-            .collect()
-            .unwrap();
-        assert_eq!(out.column("A").unwrap().null_count(), 0);
+        let schema = Schema::new(vec![
+            Field::new("B", DataType::Int32),
+            Field::new("rank", DataType::UInt32),
+        ]);
 
-        // shift from the other side
-        let out = df
+        // the two highest values of B per fruit
+        let lf = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("fruits")])
+            .select(&["B"])
+            .agg_and_apply(
+                vec![col("cars").n_unique().alias("n_cars")],
+                |df| {
+                    assert_eq!(df.get_column_names(), &["fruits", "B"]);
+                    let df = df.sort("B", true)?.head(Some(2));
+                    let rank: Vec<u32> = (0..df.height() as u32).collect();
+                    DataFrame::new(vec![df.column("B")?.clone(), Series::new("rank", &rank)])
+                },
+                schema.clone(),
+            );
+        assert_eq!(
+            lf.logical_plan.schema().fields().len(),
+            4,
+            "keys, aggregations and the declared schema"
+        );
+
+        let lf = lf
+            .select(&[col("fruits"), col("n_cars"), col("B")])
+            .sort("B", false);
+        // the input only reads the keys, the aggregated and the selected columns
+        assert!(lf
+            .describe_optimized_plan()
+            .unwrap()
+            .contains("PROJECT 3/4 COLUMNS"));
+        let out = lf.collect().unwrap();
+        assert_eq!(out.get_column_names(), &["fruits", "n_cars", "B"]);
+        assert_eq!(
+            Vec::from(out.column("B").unwrap().i32().unwrap()),
+            &[Some(2), Some(3), Some(4), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("n_cars").unwrap().u32().unwrap()),
+            &[Some(1), Some(1), Some(2), Some(2)]
+        );
+
+        // an aggregation with the name of a declared column is an error when collecting
+        assert!(df
+            .lazy()
+            .groupby(vec![col("fruits")])
+            .agg_and_apply(vec![col("B").sum()], Ok, schema)
+            .collect()
+            .is_err());
+    }
+
+    #[test]
+    fn test_lazy_groupby_extended_aggregations() {
+        let df = df! {
+            "g" => &["a", "a", "a", "b", "b"],
+            "x" => &[1, 3, 2, 4, 4],
+            "y" => &[-1, 5, 2, 0, 1]
+        }
+        .unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![
+                col("x").arg_max(),
+                col("x").product(),
+                col("x").mode(),
+                col("x").n_nulls(),
+                col("x").gt(lit(2)).any().alias("any_gt"),
+                col("x").gt(lit(2)).all().alias("all_gt"),
+                col("x").sort_by(col("y"), true).first().alias("x_at_max_y"),
+                col("x").filter(col("y").gt(lit(0))).sum().alias("x_pos_y"),
+            ])
+            .sort("g", false)
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            Vec::from(out.column("x_arg_max").unwrap().u32().unwrap()),
+            &[Some(1), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_product").unwrap().i64().unwrap()),
+            &[Some(6), Some(16)]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_mode").unwrap().i32().unwrap()),
+            &[Some(1), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_n_nulls").unwrap().u32().unwrap()),
+            &[Some(0), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("any_gt").unwrap().bool().unwrap()),
+            &[Some(true), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(out.column("all_gt").unwrap().bool().unwrap()),
+            &[Some(false), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_at_max_y").unwrap().i32().unwrap()),
+            &[Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_pos_y").unwrap().i32().unwrap()),
+            &[Some(5), Some(4)]
+        );
+
+        // unsupported data types are an error
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("x")])
+            .agg(vec![col("g").product()])
+            .collect();
+        assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+
+        // outside of a groupby the whole column is a single group
+        let out = df
+            .lazy()
+            .select(&[col("x").product(), col("y").arg_min().alias("y_arg_min")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("x").unwrap().i64().unwrap()),
+            &[Some(96)]
+        );
+        assert_eq!(
+            Vec::from(out.column("y_arg_min").unwrap().u32().unwrap()),
+            &[Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_shift_and_fill() {
+        let df = df! {
+            "A" => &[1, 2, 3, 4, 5],
+            "B" => &[5, 4, 3, 2, 1]
+        }
+        .unwrap();
+        let out = df
+            .clone()
+            .lazy()
+            .with_column(col("A").shift_and_fill(2, col("B").mean()))
+            .collect()
+            .unwrap();
+        assert_eq!(out.column("A").unwrap().null_count(), 0);
+
+        // shift from the other side
+        let out = df
             .clone()
             .lazy()
             .with_column(col("A").shift_and_fill(-2, col("B").mean()))
@@ -1965,4 +2680,477 @@ mod test {
             [Some(6), Some(0), Some(0)]
         );
     }
+
+    #[test]
+    fn test_lazy_slice_pushdown() {
+        let df = df! {
+            "a" => [5, 3, 1, 4, 2, 6],
+            "b" => [1, 2, 3, 4, 5, 6]
+        }
+        .unwrap();
+
+        // slice is pushed down through the projection into the scan
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("a"), (col("b") * lit(2)).alias("c")])
+            .slice(1, 3)
+            .limit(2)
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("c").unwrap().i32().unwrap()),
+            [Some(4), Some(6)]
+        );
+
+        // slice on top of a sort is executed as a top-k
+        for &reverse in &[false, true] {
+            let out = df
+                .clone()
+                .lazy()
+                .sort("a", reverse)
+                .slice(1, 3)
+                .collect()
+                .unwrap();
+            let expected = df.sort("a", reverse).unwrap().slice(1, 3);
+            assert!(out.frame_equal(&expected));
+        }
+
+        let out = scan_foods_csv().limit(3).collect().unwrap();
+        assert_eq!(out.height(), 3);
+    }
+
+    #[test]
+    fn test_lazy_cached_scan_with_slice() -> Result<()> {
+        // the limited and the unlimited scan of the same file must not share a cached result
+        let lf = scan_foods_csv();
+        let limited = lf.clone().limit(3);
+
+        let out = lf
+            .clone()
+            .left_join(limited.clone(), col("category"), col("category"), None)
+            .collect()?;
+        let expected =
+            lf.clone()
+                .collect()?
+                .left_join(&limited.clone().collect()?, "category", "category")?;
+        assert_eq!(out.shape(), expected.shape());
+
+        let out = limited
+            .clone()
+            .left_join(lf.clone(), col("category"), col("category"), None)
+            .collect()?;
+        let expected =
+            limited
+                .clone()
+                .collect()?
+                .left_join(&lf.clone().collect()?, "category", "category")?;
+        assert_eq!(out.shape(), expected.shape());
+
+        let out = concat(vec![limited, lf], ConcatMode::Vertical)?.collect()?;
+        assert_eq!(out.height(), 30);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_reorder() {
+        let fact = df! {
+            "id" => [0, 1, 2, 3, 4, 5, 6, 7],
+            "a_key" => [0, 1, 2, 3, 0, 1, 2, 3],
+            "b_key" => [0, 1, 0, 1, 0, 1, 0, 1]
+        }
+        .unwrap();
+        let dim_a = df! {
+            "a_key" => [0, 1, 2, 3],
+            "a" => ["a0", "a1", "a2", "a3"]
+        }
+        .unwrap();
+        let dim_b = df! {
+            "b_key" => [0, 1],
+            "b" => ["b0", "b1"]
+        }
+        .unwrap();
+
+        let query = |join_reorder: bool| {
+            fact.clone()
+                .lazy()
+                .inner_join(dim_a.clone().lazy(), col("a_key"), col("a_key"), None)
+                .inner_join(dim_b.clone().lazy(), col("b_key"), col("b_key"), None)
+                .with_join_reorder(join_reorder)
+                .collect()
+                .unwrap()
+                .sort("id", false)
+                .unwrap()
+        };
+        let out = query(true);
+        assert_eq!(out.get_column_names(), &["id", "a_key", "b_key", "a", "b"]);
+        assert!(out.frame_equal(&query(false)));
+    }
+
+    #[test]
+    fn test_lazy_window_multiple_keys_ordered() {
+        let df = df! {
+            "a" => ["x", "x", "y", "x", "y", "x"],
+            "b" => [1, 1, 1, 2, 1, 1],
+            "t" => [3, 1, 2, 1, 1, 2],
+            "v" => [1, 2, 3, 4, 5, 6]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .select(&[
+                col("v")
+                    .sum()
+                    .over_multi(vec![col("a"), col("b")])
+                    .alias("sum"),
+                col("v")
+                    .cum_sum(false)
+                    .over_ordered(vec![col("a"), col("b")], col("t"))
+                    .alias("cum_sum"),
+                col("v")
+                    .shift(1)
+                    .over_ordered(vec![col("a"), col("b")], col("t"))
+                    .alias("shift"),
+                col("v")
+                    .first()
+                    .over_ordered(vec![col("a"), col("b")], col("t"))
+                    .alias("first"),
+            ])
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            Vec::from(out.column("sum").unwrap().i32().unwrap()),
+            &[Some(9), Some(9), Some(8), Some(4), Some(8), Some(9)]
+        );
+        assert_eq!(
+            Vec::from(out.column("cum_sum").unwrap().i32().unwrap()),
+            &[Some(9), Some(2), Some(8), Some(4), Some(5), Some(8)]
+        );
+        assert_eq!(
+            Vec::from(out.column("shift").unwrap().i32().unwrap()),
+            &[Some(6), None, Some(5), None, None, Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("first").unwrap().i32().unwrap()),
+            &[Some(2), Some(2), Some(5), Some(4), Some(5), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_udf_output_type() {
+        let df = df! {
+            "v" => [1, 2, 3]
+        }
+        .unwrap();
+
+        // an output type converts to the output field of a udf
+        let udf = Expr::Udf {
+            input: Box::new(col("v")),
+            function: NoEq::new(Arc::new(|s: Series| s.cast::<Float64Type>())),
+            output_field: Some(DataType::Float64).into(),
+        };
+        let lf = df
+            .lazy()
+            .select(&[udf, col("v").map(|s: Series| Ok(s), None).alias("same")]);
+        let schema = lf.logical_plan.schema().clone();
+        assert_eq!(
+            schema.field_with_name("v").unwrap().data_type(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            schema.field_with_name("same").unwrap().data_type(),
+            &DataType::Int32
+        );
+        let out = lf.collect().unwrap();
+        assert_eq!(out.column("v").unwrap().dtype(), &DataType::Float64);
+    }
+
+    #[test]
+    fn test_lazy_window_udf() {
+        let df = df! {
+            "g" => ["a", "a", "b", "a"],
+            "v" => [1, 2, 3, 4]
+        }
+        .unwrap();
+
+        // a udf is evaluated on the rows of every group and the unaliased output is named by its root column
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("v")
+                .map(|s: Series| Ok(s.shift(1)), None)
+                .over(col("g"))])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("v").unwrap().i32().unwrap()),
+            &[None, Some(1), None, Some(2)]
+        );
+
+        // a udf that returns a single value per group is no longer broadcast to the rows
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("v")
+                .map(|s: Series| Ok(s.head(Some(1))), None)
+                .over(col("g"))])
+            .collect();
+        assert!(matches!(out, Err(PolarsError::ShapeMisMatch(_))));
+
+        // a window function needs a root column
+        let out = df
+            .lazy()
+            .select(&[lit(1).cum_sum(false).over(col("g"))])
+            .collect();
+        assert!(matches!(out, Err(PolarsError::ValueError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date64")]
+    fn test_lazy_rolling() {
+        let day = 24 * 3600 * 1000i64;
+        let df = df! {
+            "g" => ["a", "a", "b", "a", "b"],
+            "t" => [0, day, 0, 3 * day, 2 * day],
+            "v" => [1.0, 2.0, 3.0, 4.0, 5.0]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .select(&[
+                col("v").rolling_mean(2, None, true, 1).alias("mean"),
+                col("v").rolling_median(3, None, true, 1).alias("median"),
+                col("v")
+                    .rolling_by_time(col("t").cast(DataType::Date64), "2d", RollingAgg::Sum, 1)
+                    .over(col("g"))
+                    .alias("time_sum"),
+            ])
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            Vec::from(out.column("mean").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(1.5), Some(2.5), Some(3.5), Some(4.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("median").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(1.5), Some(2.0), Some(3.0), Some(4.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("time_sum").unwrap().f64().unwrap()),
+            &[Some(1.0), Some(3.0), Some(3.0), Some(4.0), Some(5.0)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_categorical_ops() {
+        let df = df! {
+            "cat" => ["b", "c", "a", "b"]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .select(&[col("cat")
+                .cast(DataType::Categorical)
+                .cat_remap(vec![("c".into(), "a".into())])
+                .cat_set_ordering(CategoricalOrdering::Lexical)
+                .sort(false)])
+            .collect()
+            .unwrap();
+        let cat = out.column("cat").unwrap();
+        assert_eq!(cat.get_categories().unwrap().len(), 2);
+        assert_eq!(
+            Vec::from(cat.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[Some("a"), Some("a"), Some("b"), Some("b")]
+        );
+    }
+
+    #[test]
+    fn test_lazy_list_ops() {
+        let df = df! {
+            "g" => ["a", "a", "b", "a"],
+            "v" => [3, 1, 2, 3]
+        }
+        .unwrap();
+
+        let lf = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").list().alias("v")])
+            .sort("g", false)
+            .select(&[
+                col("v").list_lengths().alias("lengths"),
+                col("v").list_sum().alias("sum"),
+                col("v").list_get(0).alias("first"),
+                col("v")
+                    .list_unique()
+                    .list_sort(true)
+                    .list_get(-1)
+                    .alias("min"),
+                col("v")
+                    .list_contains(Series::new("", &[1]))
+                    .alias("contains"),
+                col("v").list_max().alias("max"),
+            ]);
+        // the schema has the types of the values, not of the lists
+        let schema = lf.logical_plan.schema().clone();
+        for name in &["sum", "first", "min", "max"] {
+            assert_eq!(
+                schema.field_with_name(name).unwrap().data_type(),
+                &DataType::Int32
+            );
+        }
+
+        let out = lf.collect().unwrap();
+        assert_eq!(out.schema(), schema);
+
+        assert_eq!(
+            Vec::from(out.column("lengths").unwrap().u32().unwrap()),
+            &[Some(3), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sum").unwrap().i32().unwrap()),
+            &[Some(7), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("first").unwrap().i32().unwrap()),
+            &[Some(3), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("min").unwrap().i32().unwrap()),
+            &[Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("contains").unwrap().bool().unwrap()),
+            &[Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_lazy_hash() {
+        let df = df! {
+            "a" => [Some(1), None, Some(1)]
+        }
+        .unwrap();
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[col("a").hash(7)])
+            .collect()
+            .unwrap();
+        let h = out.column("a").unwrap().u64().unwrap();
+        assert_eq!(
+            Vec::from(h),
+            Vec::from(&df.column("a").unwrap().hash(7).unwrap())
+        );
+        assert_eq!(h.get(0), h.get(2));
+    }
+
+    #[test]
+    fn test_lazy_rank_and_cut() {
+        let df = df! {
+            "g" => ["a", "a", "b", "b", "a"],
+            "v" => [3, 1, 5, 2, 2]
+        }
+        .unwrap();
+
+        let out = df
+            .lazy()
+            .select(&[
+                col("v")
+                    .rank(RankMethod::Dense, false)
+                    .over(col("g"))
+                    .alias("rank"),
+                col("v")
+                    .qcut(vec![0.5], Some(vec!["low".into(), "high".into()]))
+                    .over(col("g"))
+                    .alias("qcut"),
+                col("v").cut(vec![2.0], None).alias("cut"),
+            ])
+            .collect()
+            .unwrap();
+
+        assert_eq!(
+            Vec::from(out.column("rank").unwrap().u32().unwrap()),
+            &[Some(3), Some(1), Some(2), Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(
+                out.column("qcut")
+                    .unwrap()
+                    .cast::<Utf8Type>()
+                    .unwrap()
+                    .utf8()
+                    .unwrap()
+            ),
+            &[
+                Some("high"),
+                Some("low"),
+                Some("low"),
+                Some("low"),
+                Some("low")
+            ]
+        );
+        assert_eq!(out.column("cut").unwrap().dtype(), &DataType::Categorical);
+    }
+
+    #[test]
+    fn test_lazy_join_options() {
+        let left = df! {
+            "k" => [1, 2, 2],
+            "v" => [1, 2, 3]
+        }
+        .unwrap();
+        let right = df! {
+            "k" => [1, 2],
+            "v" => [10, 20]
+        }
+        .unwrap();
+
+        let options = JoinOptions {
+            suffix: "_r".into(),
+            validate: JoinValidation::ManyToOne,
+            ..Default::default()
+        };
+        let out = left
+            .clone()
+            .lazy()
+            .left_join(right.clone().lazy(), col("k"), col("k"), Some(options))
+            .select(&[col("v_r")])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("v_r").unwrap().i32().unwrap()),
+            &[Some(10), Some(20), Some(20)]
+        );
+
+        let options = JoinOptions {
+            validate: JoinValidation::OneToOne,
+            ..Default::default()
+        };
+        assert!(left
+            .clone()
+            .lazy()
+            .inner_join(
+                right.clone().lazy(),
+                col("k"),
+                col("k"),
+                Some(options.clone())
+            )
+            .collect()
+            .is_err());
+
+        // the filter would remove the duplicate key if it were pushed below the join
+        assert!(left
+            .lazy()
+            .inner_join(right.lazy(), col("k"), col("k"), Some(options))
+            .filter(col("v").neq(lit(3)))
+            .collect()
+            .is_err());
+    }
 }
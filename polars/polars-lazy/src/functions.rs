@@ -1,4 +1,5 @@
 use crate::prelude::*;
+pub use polars_core::functions::ConcatMode;
 use polars_core::prelude::*;
 
 pub fn cov(a: Expr, b: Expr) -> Expr {
@@ -54,3 +55,31 @@ pub fn pearson_corr(a: Expr, b: Expr) -> Expr {
     };
     map_binary(a, b, function, Some(Field::new(name, DataType::Float32))).alias(name)
 }
+
+/// Concatenate `LazyFrame`s vertically, horizontally or diagonally. See [ConcatMode].
+///
+/// The columns are cast to the supertype of their data types in the inputs. The inputs are
+/// computed in parallel if they don't read from the same file.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+/// use polars_lazy::functions::{concat, ConcatMode};
+///
+/// fn example(months: Vec<LazyFrame>) -> Result<LazyFrame> {
+///     concat(months, ConcatMode::Diagonal)
+/// }
+/// ```
+pub fn concat(inputs: Vec<LazyFrame>, mode: ConcatMode) -> Result<LazyFrame> {
+    let opt_state = inputs
+        .first()
+        .ok_or_else(|| {
+            PolarsError::NoData("cannot concatenate an empty list of LazyFrames".into())
+        })?
+        .get_opt_state();
+    let lps = inputs.into_iter().map(|lf| lf.logical_plan).collect();
+    let lp = LogicalPlanBuilder::union(lps, mode)?.build();
+    Ok(LazyFrame::from_logical_plan(lp, opt_state))
+}
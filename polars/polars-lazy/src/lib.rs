@@ -200,6 +200,7 @@ pub mod functions;
 mod logical_plan;
 pub mod physical_plan;
 pub mod prelude;
+pub mod sql;
 pub(crate) mod utils;
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use crate::logical_plan::iterator::ArenaExprIter;
 use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::rename_field;
+use polars_core::chunked_array::decimal::MAX_DECIMAL_PRECISION;
 use polars_core::frame::groupby::{fmt_groupby_column, GroupByMethod};
 use polars_core::prelude::*;
 use polars_core::utils::{get_supertype, Arena, Node};
@@ -23,6 +24,13 @@ pub enum AAggExpr {
     Std(Node),
     Var(Node),
     AggGroups(Node),
+    ArgMin(Node),
+    ArgMax(Node),
+    Mode(Node),
+    Product(Node),
+    Any(Node),
+    All(Node),
+    NNulls(Node),
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
@@ -69,7 +77,7 @@ pub enum AExpr {
     Udf {
         input: Node,
         function: NoEq<Arc<dyn SeriesUdf>>,
-        output_type: Option<DataType>,
+        output_field: NoEq<Arc<dyn UdfOutputField>>,
     },
     Shift {
         input: Node,
@@ -77,7 +85,7 @@ pub enum AExpr {
     },
     Window {
         function: Node,
-        partition_by: Node,
+        partition_by: Vec<Node>,
         order_by: Option<Node>,
     },
     Wildcard,
@@ -248,11 +256,19 @@ impl AExpr {
                             }
                         }
                     }
-                    Sum(expr) => field_by_context(
-                        arena.get(*expr).to_field(schema, ctxt, arena)?,
-                        ctxt,
-                        GroupByMethod::Sum,
-                    ),
+                    Sum(expr) => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::Sum,
+                        );
+                        // the sum of decimals is widened to the maximal precision
+                        if let DataType::Decimal(_, scale) = field.data_type() {
+                            let dtype = DataType::Decimal(MAX_DECIMAL_PRECISION, *scale);
+                            field.coerce(dtype);
+                        }
+                        field
+                    }
                     Count(expr) => {
                         let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
                         let field = Field::new(field.name(), DataType::UInt32);
@@ -279,6 +295,51 @@ impl AExpr {
                         field.coerce(DataType::Float64);
                         field
                     }
+                    ArgMin(expr) => typed_field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        DataType::UInt32,
+                        ctxt,
+                        GroupByMethod::ArgMin,
+                    ),
+                    ArgMax(expr) => typed_field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        DataType::UInt32,
+                        ctxt,
+                        GroupByMethod::ArgMax,
+                    ),
+                    Mode(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = field.data_type().clone();
+                        typed_field_by_context(field, dtype, ctxt, GroupByMethod::Mode)
+                    }
+                    Product(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = match field.data_type() {
+                            DataType::Float32 => DataType::Float32,
+                            DataType::Float64 => DataType::Float64,
+                            DataType::UInt64 => DataType::UInt64,
+                            _ => DataType::Int64,
+                        };
+                        typed_field_by_context(field, dtype, ctxt, GroupByMethod::Product)
+                    }
+                    Any(expr) => typed_field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        DataType::Boolean,
+                        ctxt,
+                        GroupByMethod::Any,
+                    ),
+                    All(expr) => typed_field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        DataType::Boolean,
+                        ctxt,
+                        GroupByMethod::All,
+                    ),
+                    NNulls(expr) => typed_field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        DataType::UInt32,
+                        ctxt,
+                        GroupByMethod::NNulls,
+                    ),
                 };
                 Ok(field)
             }
@@ -288,14 +349,24 @@ impl AExpr {
             }
             Ternary { truthy, .. } => arena.get(*truthy).to_field(schema, ctxt, arena),
             Udf {
-                output_type, input, ..
-            } => match output_type {
-                None => arena.get(*input).to_field(schema, ctxt, arena),
-                Some(output_type) => {
-                    let input_field = arena.get(*input).to_field(schema, ctxt, arena)?;
-                    Ok(Field::new(input_field.name(), output_type.clone()))
-                }
-            },
+                output_field,
+                input,
+                ..
+            } => {
+                let input_field = arena.get(*input).to_field(schema, ctxt, arena)?;
+                output_field
+                    .get_field(schema, ctxt, &input_field)
+                    .ok_or_else(|| {
+                        PolarsError::DataTypeMisMatch(
+                            format!(
+                                "function is not supported for column {} of dtype {:?}",
+                                input_field.name(),
+                                input_field.data_type()
+                            )
+                            .into(),
+                        )
+                    })
+            }
             BinaryFunction {
                 input_a,
                 input_b,
@@ -337,6 +408,23 @@ impl AExpr {
     }
 }
 
+/// Like [field_by_context], but the output has the given data type.
+pub(crate) fn typed_field_by_context(
+    field: Field,
+    data_type: DataType,
+    ctxt: Context,
+    groupby_method: GroupByMethod,
+) -> Field {
+    let field = Field::new(field.name(), data_type);
+    match ctxt {
+        Context::Default => field,
+        Context::Aggregation => {
+            let new_name = fmt_groupby_column(field.name(), groupby_method);
+            rename_field(&field, &new_name)
+        }
+    }
+}
+
 pub(crate) fn field_by_context(
     mut field: Field,
     ctxt: Context,
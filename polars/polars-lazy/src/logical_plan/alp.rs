@@ -1,8 +1,9 @@
-use crate::logical_plan::{det_melt_schema, Context};
+use crate::logical_plan::{det_groupby_schema, det_melt_schema, Context};
 use crate::prelude::*;
 use crate::utils::{aexprs_to_schema, PushNode};
 use ahash::RandomState;
 use polars_core::frame::hash_join::JoinType;
+use polars_core::functions::ConcatMode;
 use polars_core::prelude::*;
 use polars_core::utils::{Arena, Node};
 use std::collections::HashSet;
@@ -13,8 +14,7 @@ use std::sync::Arc;
 pub enum ALogicalPlan {
     Melt {
         input: Node,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     Slice {
@@ -82,7 +82,7 @@ pub enum ALogicalPlan {
         keys: Vec<Node>,
         aggs: Vec<Node>,
         schema: SchemaRef,
-        apply: Option<Arc<dyn DataFrameUdf>>,
+        apply: Option<GroupByApply>,
     },
     Join {
         input_left: Node,
@@ -91,14 +91,18 @@ pub enum ALogicalPlan {
         how: JoinType,
         left_on: Vec<Node>,
         right_on: Vec<Node>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     },
     HStack {
         input: Node,
         exprs: Vec<Node>,
         schema: SchemaRef,
     },
+    Union {
+        inputs: Vec<Node>,
+        mode: ConcatMode,
+        schema: SchemaRef,
+    },
     Distinct {
         input: Node,
         maintain_order: bool,
@@ -143,6 +147,7 @@ impl ALogicalPlan {
             Aggregate { schema, .. } => schema,
             Join { schema, .. } => schema,
             HStack { schema, .. } => schema,
+            Union { schema, .. } => schema,
             Distinct { input, .. } => arena.get(*input).schema(arena),
             Slice { input, .. } => arena.get(*input).schema(arena),
             Melt { schema, .. } => schema,
@@ -160,15 +165,9 @@ impl ALogicalPlan {
         use ALogicalPlan::*;
 
         match self {
-            Melt {
-                id_vars,
-                value_vars,
-                schema,
-                ..
-            } => Melt {
+            Melt { args, schema, .. } => Melt {
                 input: inputs[0],
-                id_vars: id_vars.clone(),
-                value_vars: value_vars.clone(),
+                args: args.clone(),
                 schema: schema.clone(),
             },
             Slice { offset, len, .. } => Slice {
@@ -206,8 +205,7 @@ impl ALogicalPlan {
                 schema,
                 how,
                 left_on,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => Join {
                 input_left: inputs[0],
@@ -216,8 +214,12 @@ impl ALogicalPlan {
                 how: *how,
                 left_on: exprs[..left_on.len()].to_vec(),
                 right_on: exprs[left_on.len()..].to_vec(),
-                allow_par: *allow_par,
-                force_par: *force_par,
+                options: options.clone(),
+            },
+            Union { mode, schema, .. } => Union {
+                inputs,
+                mode: *mode,
+                schema: schema.clone(),
             },
             Sort {
                 by_column, reverse, ..
@@ -349,6 +351,7 @@ impl ALogicalPlan {
             | Explode { .. }
             | Cache { .. }
             | Distinct { .. }
+            | Union { .. }
             | Udf { .. } => {}
             Selection { predicate, .. } => container.push(*predicate),
             Projection { expr, .. } => container.extend_from_slice(expr),
@@ -408,8 +411,8 @@ impl ALogicalPlan {
     }
 
     /// Push inputs of the LP in of this node to an existing container.
-    /// Most plans have typically one input. A join has two and a scan (CsvScan)
-    /// or an in-memory DataFrame has none.
+    /// Most plans have typically one input. A join has two, a union any number and a scan
+    /// (CsvScan) or an in-memory DataFrame has none.
     pub(crate) fn copy_inputs<T>(&self, container: &mut T)
     where
         T: PushNode,
@@ -434,6 +437,12 @@ impl ALogicalPlan {
                 container.push_node(*input_right);
                 return;
             }
+            Union { inputs, .. } => {
+                for node in inputs {
+                    container.push_node(*node);
+                }
+                return;
+            }
             HStack { input, .. } => *input,
             Distinct { input, .. } => *input,
             Udf { input, .. } => *input,
@@ -470,13 +479,12 @@ impl<'a> ALogicalPlanBuilder<'a> {
         }
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.schema());
+    pub fn melt(self, args: MeltArgs) -> Self {
+        let (args, schema) = det_melt_schema(&args, self.schema());
 
         let lp = ALogicalPlan::Melt {
             input: self.root,
-            id_vars,
-            value_vars,
+            args,
             schema,
         };
         let node = self.lp_arena.add(lp);
@@ -559,12 +567,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
         Self::new(root, self.expr_arena, self.lp_arena)
     }
 
-    pub fn groupby(
-        self,
-        keys: Vec<Node>,
-        aggs: Vec<Node>,
-        apply: Option<Arc<dyn DataFrameUdf>>,
-    ) -> Self {
+    pub fn groupby(self, keys: Vec<Node>, aggs: Vec<Node>, apply: Option<GroupByApply>) -> Self {
         debug_assert!(!keys.is_empty());
         let current_schema = self.schema();
         // TODO! add this line if LogicalPlan is dropped in favor of ALogicalPlan
@@ -573,8 +576,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
         let schema1 = aexprs_to_schema(&keys, current_schema, Context::Default, self.expr_arena);
         let schema2 =
             aexprs_to_schema(&aggs, current_schema, Context::Aggregation, self.expr_arena);
-
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let schema = det_groupby_schema(&schema1, &schema2, apply.as_ref());
 
         let lp = ALogicalPlan::Aggregate {
             input: self.root,
@@ -593,8 +595,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
         how: JoinType,
         left_on: Vec<Node>,
         right_on: Vec<Node>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     ) -> Self {
         let schema_left = self.schema();
         let schema_right = self.lp_arena.get(other).schema(self.lp_arena);
@@ -625,7 +626,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
             let name = f.name();
             if !right_names.contains(name) {
                 if names.contains(name) {
-                    let new_name = format!("{}_right", name);
+                    let new_name = format!("{}{}", name, options.suffix);
                     let field = Field::new(&new_name, f.data_type().clone());
                     fields.push(field)
                 } else {
@@ -643,8 +644,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
             schema,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         };
         let root = self.lp_arena.add(lp);
         Self::new(root, self.expr_arena, self.lp_arena)
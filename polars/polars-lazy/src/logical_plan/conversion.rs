@@ -60,6 +60,13 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Std(expr) => AAggExpr::Std(to_aexpr(*expr, arena)),
                 AggExpr::Var(expr) => AAggExpr::Var(to_aexpr(*expr, arena)),
                 AggExpr::AggGroups(expr) => AAggExpr::AggGroups(to_aexpr(*expr, arena)),
+                AggExpr::ArgMin(expr) => AAggExpr::ArgMin(to_aexpr(*expr, arena)),
+                AggExpr::ArgMax(expr) => AAggExpr::ArgMax(to_aexpr(*expr, arena)),
+                AggExpr::Mode(expr) => AAggExpr::Mode(to_aexpr(*expr, arena)),
+                AggExpr::Product(expr) => AAggExpr::Product(to_aexpr(*expr, arena)),
+                AggExpr::Any(expr) => AAggExpr::Any(to_aexpr(*expr, arena)),
+                AggExpr::All(expr) => AAggExpr::All(to_aexpr(*expr, arena)),
+                AggExpr::NNulls(expr) => AAggExpr::NNulls(to_aexpr(*expr, arena)),
             };
             AExpr::Agg(a_agg)
         }
@@ -80,11 +87,11 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
         Expr::Udf {
             input,
             function,
-            output_type,
+            output_field,
         } => AExpr::Udf {
             input: to_aexpr(*input, arena),
             function,
-            output_type,
+            output_field,
         },
         Expr::BinaryFunction {
             input_a,
@@ -107,7 +114,10 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             order_by,
         } => AExpr::Window {
             function: to_aexpr(*function, arena),
-            partition_by: to_aexpr(*partition_by, arena),
+            partition_by: partition_by
+                .into_iter()
+                .map(|e| to_aexpr(e, arena))
+                .collect(),
             order_by: order_by.map(|ob| to_aexpr(*ob, arena)),
         },
         Expr::Slice {
@@ -145,15 +155,13 @@ pub(crate) fn to_alp(
         }
         LogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Melt {
                 input,
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
@@ -293,8 +301,7 @@ pub(crate) fn to_alp(
             how,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         } => {
             let i_l = to_alp(*input_left, expr_arena, lp_arena);
             let i_r = to_alp(*input_right, expr_arena, lp_arena);
@@ -315,8 +322,7 @@ pub(crate) fn to_alp(
                 left_on: l_on,
                 how,
                 right_on: r_on,
-                allow_par,
-                force_par,
+                options,
             }
         }
         LogicalPlan::HStack {
@@ -332,6 +338,21 @@ pub(crate) fn to_alp(
                 schema,
             }
         }
+        LogicalPlan::Union {
+            inputs,
+            mode,
+            schema,
+        } => {
+            let inputs = inputs
+                .into_iter()
+                .map(|lp| to_alp(lp, expr_arena, lp_arena))
+                .collect();
+            ALogicalPlan::Union {
+                inputs,
+                mode,
+                schema,
+            }
+        }
         LogicalPlan::Distinct {
             input,
             maintain_order,
@@ -492,6 +513,34 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Count(Box::new(exp)).into()
             }
+            AAggExpr::ArgMin(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ArgMin(Box::new(exp)).into()
+            }
+            AAggExpr::ArgMax(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ArgMax(Box::new(exp)).into()
+            }
+            AAggExpr::Mode(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Mode(Box::new(exp)).into()
+            }
+            AAggExpr::Product(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Product(Box::new(exp)).into()
+            }
+            AAggExpr::Any(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Any(Box::new(exp)).into()
+            }
+            AAggExpr::All(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::All(Box::new(exp)).into()
+            }
+            AAggExpr::NNulls(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::NNulls(Box::new(exp)).into()
+            }
         },
         AExpr::Shift { input, periods } => {
             let e = node_to_exp(input, expr_arena);
@@ -518,13 +567,13 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         AExpr::Udf {
             input,
             function,
-            output_type,
+            output_field,
         } => {
             let i = node_to_exp(input, expr_arena);
             Expr::Udf {
                 input: Box::new(i),
                 function,
-                output_type,
+                output_field,
             }
         }
         AExpr::BinaryFunction {
@@ -544,7 +593,10 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             order_by,
         } => {
             let function = Box::new(node_to_exp(function, expr_arena));
-            let partition_by = Box::new(node_to_exp(partition_by, expr_arena));
+            let partition_by = partition_by
+                .into_iter()
+                .map(|node| node_to_exp(node, expr_arena))
+                .collect();
             let order_by = order_by.map(|ob| Box::new(node_to_exp(ob, expr_arena)));
             Expr::Window {
                 function,
@@ -727,8 +779,7 @@ pub(crate) fn node_to_lp(
             how,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         } => {
             let i_l = node_to_lp(input_left, expr_arena, lp_arena);
             let i_r = node_to_lp(input_right, expr_arena, lp_arena);
@@ -749,8 +800,7 @@ pub(crate) fn node_to_lp(
                 how,
                 left_on: l_on,
                 right_on: r_on,
-                allow_par,
-                force_par,
+                options,
             }
         }
         ALogicalPlan::HStack {
@@ -767,6 +817,21 @@ pub(crate) fn node_to_lp(
                 schema,
             }
         }
+        ALogicalPlan::Union {
+            inputs,
+            mode,
+            schema,
+        } => {
+            let inputs = inputs
+                .into_iter()
+                .map(|node| node_to_lp(node, expr_arena, lp_arena))
+                .collect();
+            LogicalPlan::Union {
+                inputs,
+                mode,
+                schema,
+            }
+        }
         ALogicalPlan::Distinct {
             input,
             maintain_order,
@@ -781,15 +846,13 @@ pub(crate) fn node_to_lp(
         }
         ALogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = node_to_lp(input, expr_arena, lp_arena);
             LogicalPlan::Melt {
                 input: Box::new(input),
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
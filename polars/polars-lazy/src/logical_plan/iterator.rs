@@ -49,6 +49,13 @@ impl<'a> Iterator for ExprIter<'a> {
                         AggGroups(e) => push(e),
                         Std(e) => push(e),
                         Var(e) => push(e),
+                        ArgMin(e) => push(e),
+                        ArgMax(e) => push(e),
+                        Mode(e) => push(e),
+                        Product(e) => push(e),
+                        Any(e) => push(e),
+                        All(e) => push(e),
+                        NNulls(e) => push(e),
                     }
                 }
                 Ternary {
@@ -72,7 +79,9 @@ impl<'a> Iterator for ExprIter<'a> {
                     order_by,
                 } => {
                     push(function);
-                    push(partition_by);
+                    for e in partition_by {
+                        push(e);
+                    }
                     if let Some(e) = order_by {
                         push(e);
                     }
@@ -145,6 +154,13 @@ impl AExpr {
                     AggGroups(e) => push(e),
                     Std(e) => push(e),
                     Var(e) => push(e),
+                    ArgMin(e) => push(e),
+                    ArgMax(e) => push(e),
+                    Mode(e) => push(e),
+                    Product(e) => push(e),
+                    Any(e) => push(e),
+                    All(e) => push(e),
+                    NNulls(e) => push(e),
                 }
             }
             Ternary {
@@ -168,7 +184,9 @@ impl AExpr {
                 order_by,
             } => {
                 push(function);
-                push(partition_by);
+                for e in partition_by {
+                    push(e);
+                }
                 if let Some(e) = order_by {
                     push(e);
                 }
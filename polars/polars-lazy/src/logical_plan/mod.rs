@@ -9,6 +9,7 @@ use ahash::RandomState;
 use itertools::Itertools;
 
 use polars_core::frame::hash_join::JoinType;
+use polars_core::functions::{concat_schema, ConcatMode};
 use polars_core::prelude::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
 #[cfg(feature = "temporal")]
@@ -60,6 +61,17 @@ impl Debug for dyn DataFrameUdf {
     }
 }
 
+/// A function that is applied to every group of an aggregation.
+#[derive(Clone, Debug)]
+pub struct GroupByApply {
+    pub function: Arc<dyn DataFrameUdf>,
+    /// The declared schema of the frames returned by `function`. If set, the keys and the
+    /// aggregations are added to these frames, otherwise the output of `function` is the result.
+    pub schema: Option<SchemaRef>,
+    /// The columns passed to `function` next to the keys. If `None`, `function` gets all columns.
+    pub columns: Option<Vec<String>>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum LiteralValue {
     Null,
@@ -194,7 +206,7 @@ pub enum LogicalPlan {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         schema: SchemaRef,
-        apply: Option<Arc<dyn DataFrameUdf>>,
+        apply: Option<GroupByApply>,
     },
     Join {
         input_left: Box<LogicalPlan>,
@@ -203,14 +215,18 @@ pub enum LogicalPlan {
         how: JoinType,
         left_on: Vec<Expr>,
         right_on: Vec<Expr>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     },
     HStack {
         input: Box<LogicalPlan>,
         exprs: Vec<Expr>,
         schema: SchemaRef,
     },
+    Union {
+        inputs: Vec<LogicalPlan>,
+        mode: ConcatMode,
+        schema: SchemaRef,
+    },
     Distinct {
         input: Box<LogicalPlan>,
         maintain_order: bool,
@@ -232,8 +248,8 @@ pub enum LogicalPlan {
     },
     Melt {
         input: Box<LogicalPlan>,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        /// resolved against the input schema
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     Udf {
@@ -373,6 +389,7 @@ impl fmt::Debug for LogicalPlan {
             HStack { input, exprs, .. } => {
                 write!(f, "STACK [{:?}\n\tWITH COLUMN(S)\n{:?}\n]", input, exprs)
             }
+            Union { inputs, mode, .. } => write!(f, "UNION {:?} {:?}", mode, inputs),
             Distinct { input, .. } => write!(f, "DISTINCT {:?}", input),
             Slice { input, offset, len } => {
                 write!(f, "SLICE {:?}, offset: {}, len: {}", input, offset, len)
@@ -598,6 +615,14 @@ impl LogicalPlan {
                 input_left.dot(acc_str, id + 1, &current_node)?;
                 input_right.dot(acc_str, id + 1, &current_node)
             }
+            Union { inputs, mode, .. } => {
+                let current_node = format!("UNION {:?} [{}]", mode, id);
+                self.write_dot(acc_str, prev_node, &current_node, id)?;
+                for input in inputs {
+                    input.dot(acc_str, id + 1, &current_node)?;
+                }
+                Ok(())
+            }
             Udf { input, .. } => {
                 let current_node = format!("UDF [{}]", id);
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
@@ -615,7 +640,7 @@ impl LogicalPlan {
     }
 }
 
-fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
+pub(crate) fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
     match expr {
         Expr::Window {
             function,
@@ -653,11 +678,11 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
         Expr::Udf {
             input,
             function,
-            output_type,
+            output_field,
         } => Expr::Udf {
             input: Box::new(replace_wildcard_with_column(*input, column_name)),
             function,
-            output_type,
+            output_field,
         },
         Expr::BinaryFunction {
             input_a,
@@ -732,6 +757,27 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::Std(e) => {
                 AggExpr::Std(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::ArgMin(e) => {
+                AggExpr::ArgMin(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::ArgMax(e) => {
+                AggExpr::ArgMax(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Mode(e) => {
+                AggExpr::Mode(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Product(e) => {
+                AggExpr::Product(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Any(e) => {
+                AggExpr::Any(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::All(e) => {
+                AggExpr::All(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::NNulls(e) => {
+                AggExpr::NNulls(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
         }
         .into(),
         Expr::Shift { input, periods } => Expr::Shift {
@@ -845,6 +891,7 @@ impl LogicalPlan {
             Aggregate { schema, .. } => schema,
             Join { schema, .. } => schema,
             HStack { schema, .. } => schema,
+            Union { schema, .. } => schema,
             Distinct { input, .. } => input.schema(),
             Slice { input, .. } => input.schema(),
             Melt { schema, .. } => schema,
@@ -1035,7 +1082,7 @@ impl LogicalPlanBuilder {
         self,
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
-        apply: Option<Arc<dyn DataFrameUdf>>,
+        apply: Option<GroupByApply>,
     ) -> Self {
         debug_assert!(!keys.is_empty());
         let current_schema = self.0.schema();
@@ -1043,7 +1090,7 @@ impl LogicalPlanBuilder {
 
         let schema1 = utils::expressions_to_schema(&keys, current_schema, Context::Default);
         let schema2 = utils::expressions_to_schema(&aggs, current_schema, Context::Aggregation);
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let schema = det_groupby_schema(&schema1, &schema2, apply.as_ref());
 
         LogicalPlan::Aggregate {
             input: Box::new(self.0),
@@ -1070,6 +1117,18 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    /// Concatenate the plans, see [ConcatMode].
+    pub fn union(inputs: Vec<LogicalPlan>, mode: ConcatMode) -> Result<Self> {
+        let schemas = inputs.iter().map(|lp| lp.schema()).collect::<Vec<_>>();
+        let schema = Arc::new(concat_schema(&schemas, mode)?);
+        Ok(LogicalPlan::Union {
+            inputs,
+            mode,
+            schema,
+        }
+        .into())
+    }
+
     pub fn sort(self, by_column: String, reverse: bool) -> Self {
         LogicalPlan::Sort {
             input: Box::new(self.0),
@@ -1087,12 +1146,11 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.0.schema());
+    pub fn melt(self, args: MeltArgs) -> Self {
+        let (args, schema) = det_melt_schema(&args, self.0.schema());
         LogicalPlan::Melt {
             input: Box::new(self.0),
-            id_vars,
-            value_vars,
+            args,
             schema,
         }
         .into()
@@ -1122,8 +1180,7 @@ impl LogicalPlanBuilder {
         how: JoinType,
         left_on: Vec<Expr>,
         right_on: Vec<Expr>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     ) -> Self {
         let schema_left = self.0.schema();
         let schema_right = other.schema();
@@ -1148,7 +1205,7 @@ impl LogicalPlanBuilder {
 
             if !right_names.contains(name) {
                 if names.contains(name) {
-                    let new_name = format!("{}_right", name);
+                    let new_name = format!("{}{}", name, options.suffix);
                     let field = Field::new(&new_name, f.data_type().clone());
                     fields.push(field)
                 } else {
@@ -1166,8 +1223,7 @@ impl LogicalPlanBuilder {
             schema,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         }
         .into()
     }
@@ -1191,25 +1247,52 @@ impl LogicalPlanBuilder {
     }
 }
 
-pub(crate) fn det_melt_schema(value_vars: &[String], input_schema: &Schema) -> SchemaRef {
-    let mut fields = input_schema
-        .fields()
-        .iter()
-        .filter(|field| !value_vars.contains(field.name()))
-        .cloned()
-        .collect_vec();
-
-    fields.reserve(2);
+/// Determine the output schema of an aggregation from the schemas of the keys, the aggregations
+/// and the declared output of an apply. A name that is in several of them is only added once;
+/// an aggregation that has the name of a declared column is an error when the query is collected.
+pub(crate) fn det_groupby_schema(
+    keys: &Schema,
+    aggs: &Schema,
+    apply: Option<&GroupByApply>,
+) -> Schema {
+    let declared = apply
+        .and_then(|apply| apply.schema.as_ref())
+        .map(|schema| schema.fields().as_slice())
+        .unwrap_or(&[]);
+    let mut fields: Vec<Field> = Vec::with_capacity(keys.len() + aggs.len() + declared.len());
+    for field in keys.fields().iter().chain(aggs.fields()).chain(declared) {
+        if !fields.iter().any(|fld| fld.name() == field.name()) {
+            fields.push(field.clone())
+        }
+    }
+    Schema::new(fields)
+}
 
-    let value_dtype = input_schema
-        .field_with_name(&value_vars[0])
-        .expect("field not found")
-        .data_type();
+/// Resolve the melt arguments and determine the output schema.
+///
+/// Arguments that can't be resolved are kept as given and the value type is unknown. The melt
+/// then returns the error when the query is collected.
+pub(crate) fn det_melt_schema(
+    args: &MeltArgs,
+    input_schema: &Schema,
+) -> (Arc<MeltArgs>, SchemaRef) {
+    let (args, value_dtype) = match args.resolve(input_schema) {
+        Ok(args) => {
+            let value_dtype = args.value_dtype(input_schema).unwrap_or(DataType::Null);
+            (args, value_dtype)
+        }
+        Err(_) => (args.clone(), DataType::Null),
+    };
 
-    fields.push(Field::new("variable", DataType::Utf8));
-    fields.push(Field::new("value", value_dtype.clone()));
+    let mut fields = args
+        .id_vars
+        .iter()
+        .filter_map(|name| input_schema.field_with_name(name).ok().cloned())
+        .collect_vec();
+    fields.push(Field::new(args.variable_name(), DataType::Categorical));
+    fields.push(Field::new(args.value_name(), value_dtype));
 
-    Arc::new(Schema::new(fields))
+    (Arc::new(args), Arc::new(Schema::new(fields)))
 }
 
 #[cfg(test)]
@@ -84,12 +84,16 @@ impl OptimizationRule for AggregatePushdown {
                 schema,
             } => self.pushdown_projection(node, expr, input, schema, lp_arena, expr_arena),
             // todo! hstack should pushown not dependent columns
-            Join { .. } | Aggregate { .. } | HStack { .. } | DataFrameScan { .. } => {
+            Join { .. }
+            | Aggregate { .. }
+            | HStack { .. }
+            | Union { .. }
+            | DataFrameScan { .. } => {
                 if self.accumulated_projections.is_empty() {
                     lp_arena.replace(node, lp);
                     None
                 } else {
-                    // we cannot pass a join or GroupBy so we do the projection here
+                    // we cannot pass a join, union or GroupBy so we do the projection here
                     let new_node = lp_arena.add(lp.clone());
                     let input_schema = lp_arena.get(new_node).schema(lp_arena);
 
@@ -0,0 +1,254 @@
+use crate::logical_plan::optimizer::stack_opt::OptimizationRule;
+use crate::prelude::*;
+use crate::utils::check_down_node;
+use ahash::RandomState;
+use polars_core::functions::ConcatMode;
+use polars_core::prelude::*;
+use std::collections::HashSet;
+
+/// Fraction of the rows we expect to remain after a filter.
+const FILTER_SELECTIVITY: f64 = 0.25;
+/// Number of bytes we assume a single value takes in a file.
+const BYTES_PER_VALUE: u64 = 8;
+
+fn apply_filter(n_rows: usize) -> usize {
+    (n_rows as f64 * FILTER_SELECTIVITY) as usize
+}
+
+/// Estimate the number of rows in a file from its size on disk as
+/// `file size / (BYTES_PER_VALUE * number of columns)`.
+///
+/// This is a rough guess: it ignores compression, strings, nulls and the file format's own
+/// overhead. It is only meant to rank relations by their order of magnitude.
+fn file_cardinality(path: &str, schema: &Schema) -> Option<usize> {
+    let n_bytes = std::fs::metadata(path).ok()?.len();
+    let row_bytes = BYTES_PER_VALUE * std::cmp::max(schema.len(), 1) as u64;
+    Some((n_bytes / row_bytes) as usize)
+}
+
+fn scan_cardinality(
+    path: &str,
+    schema: &Schema,
+    stop_after_n_rows: Option<usize>,
+    predicate: Option<Node>,
+) -> Option<usize> {
+    let n_rows = file_cardinality(path, schema)?;
+    let n_rows = stop_after_n_rows.map_or(n_rows, |n| std::cmp::min(n, n_rows));
+    match predicate {
+        Some(_) => Some(apply_filter(n_rows)),
+        None => Some(n_rows),
+    }
+}
+
+/// Estimate the number of rows a logical plan produces.
+/// Returns `None` if no reasonable estimate can be made.
+pub(crate) fn estimate_cardinality(node: Node, lp_arena: &Arena<ALogicalPlan>) -> Option<usize> {
+    use ALogicalPlan::*;
+    let n_rows = match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => match selection {
+            Some(_) => apply_filter(df.height()),
+            None => df.height(),
+        },
+        CsvScan {
+            path,
+            schema,
+            stop_after_n_rows,
+            predicate,
+            ..
+        } => scan_cardinality(path, schema, *stop_after_n_rows, *predicate)?,
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            schema,
+            stop_after_n_rows,
+            predicate,
+            ..
+        } => scan_cardinality(path, schema, *stop_after_n_rows, *predicate)?,
+        Selection { input, .. } => apply_filter(estimate_cardinality(*input, lp_arena)?),
+        Slice { input, len, .. } => std::cmp::min(*len, estimate_cardinality(*input, lp_arena)?),
+        Melt { input, args, .. } => {
+            estimate_cardinality(*input, lp_arena)? * std::cmp::max(args.value_vars.len(), 1)
+        }
+        Join {
+            input_left,
+            input_right,
+            how,
+            ..
+        } => {
+            let left = estimate_cardinality(*input_left, lp_arena)?;
+            let right = estimate_cardinality(*input_right, lp_arena)?;
+            match how {
+                JoinType::Inner => std::cmp::max(left, right),
+                JoinType::Left => left,
+                JoinType::Outer => left + right,
+            }
+        }
+        Union { inputs, mode, .. } => {
+            let mut cardinalities = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                cardinalities.push(estimate_cardinality(*input, lp_arena)?);
+            }
+            match mode {
+                ConcatMode::Horizontal => cardinalities.into_iter().max().unwrap_or(0),
+                _ => cardinalities.into_iter().sum(),
+            }
+        }
+        // the remaining nodes have a single input and we assume that
+        // the number of rows of that input is an upper bound
+        lp => estimate_cardinality(lp.get_inputs()[0], lp_arena)?,
+    };
+    Some(n_rows)
+}
+
+/// A relation that is joined onto the base relation of a join chain.
+struct JoinInput {
+    input: Node,
+    left_on: Vec<Node>,
+    right_on: Vec<Node>,
+    options: JoinOptions,
+}
+
+/// Collect a left-deep chain of inner joins. Returns the base relation, `inputs` is filled with the
+/// relations that are joined onto the base relation in the order of execution.
+fn collect_inner_joins(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    inputs: &mut Vec<JoinInput>,
+) -> Node {
+    match lp_arena.get(node) {
+        ALogicalPlan::Join {
+            input_left,
+            input_right,
+            how: JoinType::Inner,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let base = collect_inner_joins(*input_left, lp_arena, inputs);
+            inputs.push(JoinInput {
+                input: *input_right,
+                left_on: left_on.clone(),
+                right_on: right_on.clone(),
+                options: options.clone(),
+            });
+            base
+        }
+        _ => node,
+    }
+}
+
+/// Reorders chains of inner joins, such as star-schema queries that join a large fact table
+/// with several dimension tables, so that the relations with the lowest estimated cardinality
+/// are joined first. This keeps the intermediate results small.
+///
+/// This rule does not choose the build side of the hash tables. Inner joins hash the shorter
+/// of their two inputs during execution, where the exact lengths are known. Left joins always
+/// hash the right input, so they are left as written.
+pub(crate) struct JoinReorder {}
+
+impl OptimizationRule for JoinReorder {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        match lp_arena.get(node) {
+            ALogicalPlan::Join {
+                input_left,
+                how: JoinType::Inner,
+                ..
+            } if matches!(
+                lp_arena.get(*input_left),
+                ALogicalPlan::Join {
+                    how: JoinType::Inner,
+                    ..
+                }
+            ) => {}
+            _ => return None,
+        }
+
+        let mut inputs = Vec::with_capacity(4);
+        let base = collect_inner_joins(node, lp_arena, &mut inputs);
+        let base_schema = lp_arena.get(base).schema(lp_arena);
+
+        // all joins should be on columns of the base relation, otherwise a join
+        // depends on the output of a previous join.
+        if !inputs.iter().all(|join_input| {
+            join_input
+                .left_on
+                .iter()
+                .all(|e| check_down_node(*e, base_schema, expr_arena))
+        }) {
+            return None;
+        }
+
+        // the left keys of a join depend on the order of the joins, so their uniqueness
+        // can only be validated in the order the user gave.
+        if inputs
+            .iter()
+            .any(|join_input| join_input.options.validate != JoinValidation::ManyToMany)
+        {
+            return None;
+        }
+
+        // the order of the joins may not influence the names of the output columns, so we
+        // don't reorder if columns need to be renamed with a suffix.
+        let mut names: HashSet<&String, RandomState> =
+            base_schema.fields().iter().map(|f| f.name()).collect();
+        for join_input in &inputs {
+            let mut right_names = Vec::with_capacity(join_input.right_on.len());
+            for e in &join_input.right_on {
+                match expr_arena.get(*e) {
+                    AExpr::Alias(_, name) | AExpr::Column(name) => right_names.push(name.clone()),
+                    _ => return None,
+                }
+            }
+            for f in lp_arena.get(join_input.input).schema(lp_arena).fields() {
+                if !right_names.iter().any(|name| &**name == f.name()) && !names.insert(f.name()) {
+                    return None;
+                }
+            }
+        }
+
+        let estimates = inputs
+            .iter()
+            .map(|join_input| estimate_cardinality(join_input.input, lp_arena))
+            .collect::<Option<Vec<_>>>()?;
+        let mut order = (0..inputs.len()).collect::<Vec<_>>();
+        // stable sort, so that an already optimal order is not changed
+        order.sort_by_key(|&idx| estimates[idx]);
+        if order.iter().enumerate().all(|(i, &idx)| i == idx) {
+            return None;
+        }
+
+        let schema = lp_arena.get(node).schema(lp_arena).clone();
+
+        let mut root = base;
+        for idx in order {
+            let join_input = &inputs[idx];
+            root = ALogicalPlanBuilder::new(root, expr_arena, lp_arena)
+                .join(
+                    join_input.input,
+                    JoinType::Inner,
+                    join_input.left_on.clone(),
+                    join_input.right_on.clone(),
+                    join_input.options.clone(),
+                )
+                .into_node();
+        }
+
+        // restore the original column order
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|f| expr_arena.add(AExpr::Column(Arc::new(f.name().clone()))))
+            .collect();
+        Some(
+            ALogicalPlanBuilder::new(root, expr_arena, lp_arena)
+                .project(exprs)
+                .build(),
+        )
+    }
+}
@@ -6,6 +6,7 @@ use crate::utils::{
     aexpr_to_root_column_name, aexpr_to_root_names, aexprs_to_schema, check_down_node, has_aexpr,
 };
 use ahash::RandomState;
+use polars_core::functions::ConcatMode;
 use polars_core::prelude::*;
 use std::collections::HashMap;
 
@@ -302,14 +303,15 @@ impl PredicatePushDown {
 
             Melt {
                 input,
-                id_vars,
-                value_vars,
+                args,
                 schema,
             } => {
                 // predicates that will be done at this level
                 let condition = |name: Arc<String>| {
                     let name = &*name;
-                    name == "variable" || name == "value" || value_vars.contains(name)
+                    name == args.variable_name()
+                        || name == args.value_name()
+                        || args.value_vars.contains(name)
                 };
                 let local_predicates =
                     transfer_to_local(expr_arena, &mut acc_predicates, condition);
@@ -318,8 +320,7 @@ impl PredicatePushDown {
 
                 let lp = ALogicalPlan::Melt {
                     input,
-                    id_vars,
-                    value_vars,
+                    args,
                     schema,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
@@ -460,10 +461,36 @@ impl PredicatePushDown {
                 left_on,
                 right_on,
                 how,
-                allow_par,
-                force_par,
+                options,
                 schema,
             } => {
+                // a filter below the join changes the keys whose uniqueness is validated, so a
+                // validated join keeps the predicates local
+                if options.validate != JoinValidation::ManyToMany {
+                    self.pushdown_and_assign(
+                        input_left,
+                        optimizer::init_hashmap(),
+                        lp_arena,
+                        expr_arena,
+                    )?;
+                    self.pushdown_and_assign(
+                        input_right,
+                        optimizer::init_hashmap(),
+                        lp_arena,
+                        expr_arena,
+                    )?;
+                    let lp = Join {
+                        input_left,
+                        input_right,
+                        left_on,
+                        right_on,
+                        how,
+                        options,
+                        schema,
+                    };
+                    return Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena));
+                }
+
                 let schema_left = lp_arena.get(input_left).schema(lp_arena);
                 let schema_right = lp_arena.get(input_right).schema(lp_arena);
 
@@ -527,8 +554,7 @@ impl PredicatePushDown {
                     left_on,
                     right_on,
                     how,
-                    allow_par,
-                    force_par,
+                    options,
                     schema,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
@@ -625,6 +651,64 @@ impl PredicatePushDown {
                     schema,
                 })
             }
+            Union {
+                inputs,
+                mode,
+                schema,
+            } => {
+                if mode == ConcatMode::Vertical {
+                    // the inputs are cast to the union schema after they are computed, so the
+                    // predicates can only be pushed into inputs that already have its dtypes
+                    let mut local = false;
+                    for &input in &inputs {
+                        let matches_schema = lp_arena
+                            .get(input)
+                            .schema(lp_arena)
+                            .fields()
+                            .iter()
+                            .all(|fld| {
+                                schema
+                                    .field_with_name(fld.name())
+                                    .map_or(false, |union_fld| {
+                                        union_fld.data_type() == fld.data_type()
+                                    })
+                            });
+                        let predicates = if matches_schema {
+                            acc_predicates.clone()
+                        } else {
+                            local = true;
+                            optimizer::init_hashmap()
+                        };
+                        self.pushdown_and_assign(input, predicates, lp_arena, expr_arena)?;
+                    }
+                    let lp = Union {
+                        inputs,
+                        mode,
+                        schema,
+                    };
+                    if local {
+                        Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
+                    } else {
+                        Ok(lp)
+                    }
+                } else {
+                    // an input may miss the columns of a predicate or be padded with nulls
+                    for &input in &inputs {
+                        self.pushdown_and_assign(
+                            input,
+                            optimizer::init_hashmap(),
+                            lp_arena,
+                            expr_arena,
+                        )?;
+                    }
+                    let lp = Union {
+                        inputs,
+                        mode,
+                        schema,
+                    };
+                    Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
+                }
+            }
             lp => {
                 let inputs = lp.get_inputs();
                 let exprs = lp.get_exprs();
@@ -2,6 +2,7 @@ use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{aexpr_to_root_names, aexpr_to_root_nodes, check_down_node, has_aexpr};
 use ahash::RandomState;
+use polars_core::functions::{concat_schema, ConcatMode};
 use polars_core::prelude::*;
 use std::collections::HashSet;
 
@@ -448,12 +449,7 @@ impl ProjectionPushDown {
                 )?;
                 Ok(Selection { predicate, input })
             }
-            Melt {
-                input,
-                id_vars,
-                value_vars,
-                ..
-            } => {
+            Melt { input, args, .. } => {
                 let (mut acc_projections, mut local_projections, names) = split_acc_projections(
                     acc_projections,
                     lp_arena.get(input).schema(lp_arena),
@@ -465,7 +461,7 @@ impl ProjectionPushDown {
                 }
 
                 // make sure that the requested columns are projected
-                id_vars.iter().for_each(|name| {
+                args.id_vars.iter().for_each(|name| {
                     add_str_to_accumulated(
                         name,
                         &mut acc_projections,
@@ -473,7 +469,7 @@ impl ProjectionPushDown {
                         expr_arena,
                     )
                 });
-                value_vars.iter().for_each(|name| {
+                args.value_vars.iter().for_each(|name| {
                     add_str_to_accumulated(
                         name,
                         &mut acc_projections,
@@ -492,7 +488,7 @@ impl ProjectionPushDown {
                 )?;
 
                 let builder =
-                    ALogicalPlanBuilder::new(input, expr_arena, lp_arena).melt(id_vars, value_vars);
+                    ALogicalPlanBuilder::new(input, expr_arena, lp_arena).melt((*args).clone());
                 Ok(self.finish_node(local_projections, builder))
             }
             Aggregate {
@@ -502,8 +498,31 @@ impl ProjectionPushDown {
                 apply,
                 schema,
             } => {
-                // the custom function may need all columns so we do the projections here.
+                // the output of the custom function is unknown so we do the projections here.
                 if let Some(f) = apply {
+                    // the function may need all columns, unless the columns it gets are selected
+                    let mut pushdown = init_vec();
+                    let mut names = init_set();
+                    if let Some(columns) = &f.columns {
+                        for name in columns {
+                            let node = expr_arena.add(AExpr::Column(Arc::new(name.clone())));
+                            add_expr_to_accumulated(node, &mut pushdown, &mut names, expr_arena);
+                        }
+                        for agg in &aggs {
+                            add_expr_to_accumulated(*agg, &mut pushdown, &mut names, expr_arena);
+                        }
+                        for key in &*keys {
+                            add_expr_to_accumulated(*key, &mut pushdown, &mut names, expr_arena);
+                        }
+                    }
+                    self.pushdown_and_assign(
+                        input,
+                        pushdown,
+                        names,
+                        projections_seen,
+                        lp_arena,
+                        expr_arena,
+                    )?;
                     let lp = Aggregate {
                         input,
                         keys,
@@ -553,8 +572,7 @@ impl ProjectionPushDown {
                 left_on,
                 right_on,
                 how,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => {
                 let mut pushdown_left = init_vec();
@@ -589,7 +607,7 @@ impl ProjectionPushDown {
                         }
 
                         // Path for renamed columns due to the join. The column name of the left table
-                        // stays as is, the column of the right will have the "_right" suffix.
+                        // stays as is, the column of the right will have the suffix, "_right" by default.
                         // Thus joining two tables with both a foo column leads to ["foo", "foo_right"]
                         if !self.join_push_down(
                             schema_left,
@@ -605,12 +623,13 @@ impl ProjectionPushDown {
                             let root_column_name =
                                 aexpr_to_root_names(proj, expr_arena).pop().unwrap();
 
-                            // If _right suffix exists we need to push a projection down without this
+                            // If the suffix exists we need to push a projection down without this
                             // suffix.
-                            if root_column_name.ends_with("_right") {
-                                // downwards name is the name without the _right i.e. "foo".
+                            let suffix = options.suffix.as_str();
+                            if !suffix.is_empty() && root_column_name.ends_with(suffix) {
+                                // downwards name is the name without the suffix i.e. "foo".
                                 let (downwards_name, _) = root_column_name
-                                    .split_at(root_column_name.len() - "_right".len());
+                                    .split_at(root_column_name.len() - suffix.len());
 
                                 let downwards_name_column =
                                     expr_arena.add(AExpr::Column(Arc::new(downwards_name.into())));
@@ -622,7 +641,7 @@ impl ProjectionPushDown {
                                 // locally we project and alias
                                 let projection = expr_arena.add(AExpr::Alias(
                                     downwards_name_column,
-                                    Arc::new(format!("{}_right", downwards_name)),
+                                    Arc::new(format!("{}{}", downwards_name, suffix)),
                                 ));
                                 local_projection.push(projection);
                             }
@@ -663,8 +682,7 @@ impl ProjectionPushDown {
                     how,
                     left_on,
                     right_on,
-                    allow_par,
-                    force_par,
+                    options,
                 );
                 Ok(self.finish_node(local_projection, builder))
             }
@@ -726,6 +744,53 @@ impl ProjectionPushDown {
                     schema,
                 })
             }
+            Union {
+                inputs,
+                mode,
+                schema,
+            } => {
+                // the inputs of a vertical union have the same columns. In the other modes the
+                // columns of an input are needed to determine its number of rows and the schema.
+                let push_projections = mode == ConcatMode::Vertical;
+                for &input in &inputs {
+                    let (acc_projections, names) = if push_projections {
+                        (acc_projections.clone(), projected_names.clone())
+                    } else {
+                        (init_vec(), init_set())
+                    };
+                    self.pushdown_and_assign(
+                        input,
+                        acc_projections,
+                        names,
+                        projections_seen,
+                        lp_arena,
+                        expr_arena,
+                    )?;
+                }
+                if push_projections {
+                    let schema = {
+                        let schemas = inputs
+                            .iter()
+                            .map(|node| lp_arena.get(*node).schema(lp_arena))
+                            .collect::<Vec<_>>();
+                        Arc::new(concat_schema(&schemas, mode)?)
+                    };
+                    Ok(Union {
+                        inputs,
+                        mode,
+                        schema,
+                    })
+                } else {
+                    let lp = Union {
+                        inputs,
+                        mode,
+                        schema,
+                    };
+                    let input = lp_arena.add(lp);
+                    let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena);
+                    Ok(self.finish_node(acc_projections, builder))
+                }
+            }
             lp @ Slice { .. } | lp @ Cache { .. } => {
                 let inputs = lp.get_inputs();
                 let exprs = lp.get_exprs();
@@ -0,0 +1,243 @@
+use crate::prelude::*;
+use crate::utils::has_aexpr;
+use polars_core::prelude::*;
+
+pub(crate) struct SlicePushDown {}
+
+impl Default for SlicePushDown {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Copy, Clone)]
+struct State {
+    offset: i64,
+    len: usize,
+}
+
+/// Check if all expressions are elementwise, so that slicing before or after the
+/// expressions leads to the same result.
+fn all_elementwise(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    let matches = |e: &AExpr| {
+        matches!(
+            e,
+            AExpr::Agg(_)
+                | AExpr::Shift { .. }
+                | AExpr::Sort { .. }
+                | AExpr::SortBy { .. }
+                | AExpr::Reverse(_)
+                | AExpr::Window { .. }
+                | AExpr::Explode(_)
+                | AExpr::Slice { .. }
+                | AExpr::Filter { .. }
+                | AExpr::IsUnique(_)
+                | AExpr::Duplicated(_)
+                | AExpr::Udf { .. }
+                | AExpr::BinaryFunction { .. }
+                | AExpr::Literal(LiteralValue::Series(_))
+                | AExpr::Literal(LiteralValue::Range { .. })
+        )
+    };
+    exprs
+        .iter()
+        .all(|node| !has_aexpr(*node, expr_arena, matches))
+}
+
+impl SlicePushDown {
+    fn apply_slice(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+    ) -> ALogicalPlan {
+        match state {
+            Some(state) => {
+                let input = lp_arena.add(lp);
+                ALogicalPlan::Slice {
+                    input,
+                    offset: state.offset,
+                    len: state.len,
+                }
+            }
+            None => lp,
+        }
+    }
+
+    fn pushdown_and_assign(
+        &self,
+        input: Node,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<()> {
+        let alp = lp_arena.take(input);
+        let lp = self.push_down(alp, state, lp_arena, expr_arena)?;
+        lp_arena.replace(input, lp);
+        Ok(())
+    }
+
+    /// The slice cannot be pushed past this node. We restart the optimization
+    /// in the inputs and apply the slice on top of this node.
+    fn no_pushdown_restart(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        let inputs = lp.get_inputs();
+        let exprs = lp.get_exprs();
+
+        for &node in &inputs {
+            self.pushdown_and_assign(node, None, lp_arena, expr_arena)?;
+        }
+        let lp = lp.from_exprs_and_input(exprs, inputs);
+        Ok(self.apply_slice(lp, state, lp_arena))
+    }
+
+    /// Slice pushdown optimizer
+    ///
+    /// # Arguments
+    ///
+    /// * `AlogicalPlan` - Arena based logical plan tree representing the query.
+    /// * `state` - The slice that is accumulated during tree traversal. It is pushed down
+    ///             until it meets a node that changes the number or order of the rows.
+    /// * `lp_arena` - The local memory arena for the logical plan.
+    /// * `expr_arena` - The local memory arena for the expressions.
+    fn push_down(
+        &self,
+        logical_plan: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        use ALogicalPlan::*;
+
+        match (logical_plan, state) {
+            (Slice { input, offset, len }, None) => {
+                let state = Some(State { offset, len });
+                let alp = lp_arena.take(input);
+                self.push_down(alp, state, lp_arena, expr_arena)
+            }
+            // two consecutive slices with a positive offset can be combined into one
+            (Slice { input, offset, len }, Some(outer)) if offset >= 0 && outer.offset >= 0 => {
+                let state = Some(State {
+                    offset: offset + outer.offset,
+                    len: std::cmp::min(outer.len, len.saturating_sub(outer.offset as usize)),
+                });
+                let alp = lp_arena.take(input);
+                self.push_down(alp, state, lp_arena, expr_arena)
+            }
+            (
+                DataFrameScan {
+                    df,
+                    schema,
+                    projection,
+                    selection: None,
+                },
+                Some(state),
+            ) => {
+                let df = Arc::new(df.slice(state.offset, state.len));
+                Ok(DataFrameScan {
+                    df,
+                    schema,
+                    projection,
+                    selection: None,
+                })
+            }
+            (
+                CsvScan {
+                    path,
+                    schema,
+                    has_header,
+                    delimiter,
+                    ignore_errors,
+                    skip_rows,
+                    stop_after_n_rows,
+                    with_columns,
+                    predicate: None,
+                    aggregate,
+                    cache,
+                },
+                Some(state),
+            ) if state.offset >= 0 && aggregate.is_empty() => {
+                let n_rows = (state.offset as usize).saturating_add(state.len);
+                let stop_after_n_rows = Some(stop_after_n_rows.map_or(n_rows, |n| n.min(n_rows)));
+                let lp = CsvScan {
+                    path,
+                    schema,
+                    has_header,
+                    delimiter,
+                    ignore_errors,
+                    skip_rows,
+                    stop_after_n_rows,
+                    with_columns,
+                    predicate: None,
+                    aggregate,
+                    cache,
+                };
+                // the scan stops after `offset + len` rows, so we only need to slice if there is an offset
+                match state.offset {
+                    0 => Ok(lp),
+                    _ => Ok(self.apply_slice(lp, Some(state), lp_arena)),
+                }
+            }
+            #[cfg(feature = "parquet")]
+            (
+                ParquetScan {
+                    path,
+                    schema,
+                    with_columns,
+                    predicate: None,
+                    aggregate,
+                    stop_after_n_rows,
+                    cache,
+                },
+                Some(state),
+            ) if state.offset >= 0 && aggregate.is_empty() => {
+                let n_rows = (state.offset as usize).saturating_add(state.len);
+                let stop_after_n_rows = Some(stop_after_n_rows.map_or(n_rows, |n| n.min(n_rows)));
+                let lp = ParquetScan {
+                    path,
+                    schema,
+                    with_columns,
+                    predicate: None,
+                    aggregate,
+                    stop_after_n_rows,
+                    cache,
+                };
+                match state.offset {
+                    0 => Ok(lp),
+                    _ => Ok(self.apply_slice(lp, Some(state), lp_arena)),
+                }
+            }
+            // these nodes don't change the number or order of the rows if all
+            // their expressions are elementwise
+            (lp @ Projection { .. }, state)
+            | (lp @ LocalProjection { .. }, state)
+            | (lp @ HStack { .. }, state) => {
+                let exprs = lp.get_exprs();
+                if all_elementwise(&exprs, expr_arena) {
+                    let input = lp.get_inputs()[0];
+                    self.pushdown_and_assign(input, state, lp_arena, expr_arena)?;
+                    Ok(lp)
+                } else {
+                    self.no_pushdown_restart(lp, state, lp_arena, expr_arena)
+                }
+            }
+            // all other nodes stop the slice pushdown. Note that a slice directly on top of a
+            // sort node is fused into a top-k sort by the physical planner.
+            (lp, state) => self.no_pushdown_restart(lp, state, lp_arena, expr_arena),
+        }
+    }
+
+    pub fn optimize(
+        &self,
+        logical_plan: ALogicalPlan,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        self.push_down(logical_plan, None, lp_arena, expr_arena)
+    }
+}
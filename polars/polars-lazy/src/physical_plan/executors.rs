@@ -2,10 +2,11 @@ use super::*;
 use crate::logical_plan::{Context, FETCH_ROWS};
 use crate::utils::rename_aexpr_root_name;
 use itertools::Itertools;
+use polars_core::functions::{concat, ConcatMode};
 use polars_core::utils::{accumulate_dataframes_vertical, num_cpus, split_df};
 use polars_core::{frame::hash_join::JoinType, POOL};
 use polars_io::prelude::*;
-use polars_io::{csv::CsvEncoding, ScanAggregation};
+use polars_io::{csv::CsvEncoding, set_sorted_flags, ScanAggregation};
 use rayon::prelude::*;
 use std::io::{Read, Seek};
 use std::mem;
@@ -30,11 +31,11 @@ impl<'a, R: 'static + Read + Seek + Sync + Send> FinishScanOps for CsvReader<'a,
 
         let rechunk = self.rechunk;
         let mut csv_reader = self.build_inner_reader()?;
-        let df = csv_reader.as_df(predicate, aggregate)?;
-        match rechunk {
-            true => Ok(df.agg_chunks()),
-            false => Ok(df),
+        let mut df = csv_reader.as_df(predicate, aggregate)?;
+        if rechunk {
+            df = df.agg_chunks();
         }
+        Ok(set_sorted_flags(df))
     }
 }
 
@@ -48,6 +49,23 @@ fn set_n_rows(stop_after_n_rows: Option<usize>) -> Option<usize> {
     }
 }
 
+/// Key of a cached file scan. Every option that changes the scanned DataFrame is part of the
+/// key, so that a scan that is sliced, projected or aggregated is not served to another scan of
+/// the same file.
+fn scan_cache_key(
+    path: &str,
+    predicate: &Option<Arc<dyn PhysicalExpr>>,
+    with_columns: &Option<Vec<String>>,
+    stop_after_n_rows: Option<usize>,
+    aggregate: &[ScanAggregation],
+) -> String {
+    let predicate = predicate.as_ref().map(|p| p.as_expression());
+    format!(
+        "{}{:?}{:?}{:?}{:?}",
+        path, predicate, with_columns, stop_after_n_rows, aggregate
+    )
+}
+
 pub struct CacheExec {
     pub key: String,
     pub input: Box<dyn Executor>,
@@ -114,10 +132,13 @@ impl ParquetExec {
 #[cfg(feature = "parquet")]
 impl Executor for ParquetExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
-        let cache_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", self.path, predicate.as_expression()),
-            None => self.path.to_string(),
-        };
+        let cache_key = scan_cache_key(
+            &self.path,
+            &self.predicate,
+            &self.with_columns,
+            set_n_rows(self.stop_after_n_rows),
+            &self.aggregate,
+        );
         if self.cache {
             let guard = cache.lock().unwrap();
             // cache hit
@@ -218,10 +239,13 @@ impl CsvExec {
 
 impl Executor for CsvExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
-        let cache_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", self.path, predicate.as_expression()),
-            None => self.path.to_string(),
-        };
+        let cache_key = scan_cache_key(
+            &self.path,
+            &self.predicate,
+            &self.with_columns,
+            set_n_rows(self.stop_after_n_rows),
+            &self.aggregate,
+        );
         if self.cache {
             let guard = cache.lock().unwrap();
             // cache hit
@@ -427,12 +451,25 @@ pub(crate) struct SortExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) by_column: String,
     pub(crate) reverse: bool,
+    /// Slice (offset, len) that is applied on the sorted result.
+    /// Only the first `offset + len` rows are fully sorted.
+    pub(crate) slice: Option<(i64, usize)>,
 }
 
 impl Executor for SortExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
         let df = self.input.execute(cache)?;
-        df.sort(&self.by_column, self.reverse)
+        match self.slice {
+            Some((offset, len)) => {
+                let k = (offset as usize).saturating_add(len);
+                if std::env::var(POLARS_VERBOSE).is_ok() {
+                    println!("sort with top-k: {}", k);
+                }
+                let df = df.top_k(k, &self.by_column, self.reverse)?;
+                Ok(df.slice(offset, len))
+            }
+            None => df.sort(&self.by_column, self.reverse),
+        }
     }
 }
 
@@ -457,7 +494,7 @@ pub struct GroupByExec {
     input: Box<dyn Executor>,
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
-    apply: Option<Arc<dyn DataFrameUdf>>,
+    apply: Option<GroupByApply>,
 }
 
 impl GroupByExec {
@@ -465,7 +502,7 @@ impl GroupByExec {
         input: Box<dyn Executor>,
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
-        apply: Option<Arc<dyn DataFrameUdf>>,
+        apply: Option<GroupByApply>,
     ) -> Self {
         Self {
             input,
@@ -480,17 +517,23 @@ fn groupby_helper(
     df: DataFrame,
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
-    apply: Option<&Arc<dyn DataFrameUdf>>,
+    apply: Option<&GroupByApply>,
 ) -> Result<DataFrame> {
-    let gb = df.groupby_with_series(keys, true)?;
-    if let Some(f) = apply {
-        return gb.apply(|df| f.call_udf(df));
+    let mut gb = df.groupby_with_series(keys, true)?;
+    if let Some(columns) = apply.and_then(|apply| apply.columns.as_ref()) {
+        gb = gb.select(columns.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+    }
+    if let Some(GroupByApply {
+        function,
+        schema: None,
+        ..
+    }) = apply
+    {
+        return gb.apply(|df| function.call_udf(df));
     }
 
     let groups = gb.get_groups();
 
-    let mut columns = gb.keys();
-
     let agg_columns = POOL.install(|| {
        aggs
             .par_iter()
@@ -511,6 +554,17 @@ fn groupby_helper(
             .collect::<Result<Vec<_>>>()
     })?;
 
+    if let Some(GroupByApply {
+        function,
+        schema: Some(schema),
+        ..
+    }) = apply
+    {
+        let aggs = agg_columns.into_iter().flatten().collect();
+        return gb.apply_with_schema_and_aggs(|df| function.call_udf(df), schema, aggs);
+    }
+
+    let mut columns = gb.keys();
     columns.extend(agg_columns.into_iter().flatten());
 
     let df = DataFrame::new_no_checks(columns);
@@ -694,6 +748,7 @@ pub struct JoinExec {
     left_on: Vec<Arc<dyn PhysicalExpr>>,
     right_on: Vec<Arc<dyn PhysicalExpr>>,
     parallel: bool,
+    options: JoinOptions,
 }
 
 impl JoinExec {
@@ -704,6 +759,7 @@ impl JoinExec {
         left_on: Vec<Arc<dyn PhysicalExpr>>,
         right_on: Vec<Arc<dyn PhysicalExpr>>,
         parallel: bool,
+        options: JoinOptions,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -712,6 +768,7 @@ impl JoinExec {
             left_on,
             right_on,
             parallel,
+            options,
         }
     }
 }
@@ -756,7 +813,13 @@ impl Executor for JoinExec {
             .map(|e| e.evaluate(&df_right).map(|s| s.name().to_string()))
             .collect::<Result<Vec<_>>>()?;
 
-        let df = df_left.join(&df_right, &left_names, &right_names, self.how);
+        let df = df_left.join_with_options(
+            &df_right,
+            &left_names,
+            &right_names,
+            self.how,
+            self.options.clone(),
+        );
         if std::env::var(POLARS_VERBOSE).is_ok() {
             println!("{:?} join dataframes finished", self.how);
         };
@@ -813,16 +876,46 @@ impl Executor for SliceExec {
         Ok(df.slice(self.offset, self.len))
     }
 }
+pub struct UnionExec {
+    pub inputs: Vec<Box<dyn Executor>>,
+    pub mode: ConcatMode,
+    pub parallel: bool,
+}
+
+impl Executor for UnionExec {
+    fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
+        let inputs = mem::take(&mut self.inputs);
+        let dfs = if self.parallel {
+            // propagate the fetch_rows static value to the spawning threads.
+            let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
+            POOL.install(|| {
+                inputs
+                    .into_par_iter()
+                    .map(|mut input| {
+                        FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                        input.execute(cache)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?
+        } else {
+            inputs
+                .into_iter()
+                .map(|mut input| input.execute(cache))
+                .collect::<Result<Vec<_>>>()?
+        };
+        concat(&dfs, self.mode)
+    }
+}
+
 pub struct MeltExec {
     pub input: Box<dyn Executor>,
-    pub id_vars: Arc<Vec<String>>,
-    pub value_vars: Arc<Vec<String>>,
+    pub args: Arc<MeltArgs>,
 }
 
 impl Executor for MeltExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
         let df = self.input.execute(cache)?;
-        df.melt(&self.id_vars.as_slice(), &self.value_vars.as_slice())
+        df.melt_with(&self.args)
     }
 }
 
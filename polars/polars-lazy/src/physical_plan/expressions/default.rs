@@ -1,9 +1,10 @@
 use crate::logical_plan::Context;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
-use polars_core::frame::groupby::{fmt_groupby_column, GroupByMethod, GroupTuples};
+use polars_core::frame::groupby::{fmt_groupby_column, GroupByMethod, GroupTuples, GroupsIdx};
 use polars_core::prelude::*;
 use polars_core::utils::{slice_offsets, NoNull};
+use polars_core::POOL;
 use rayon::prelude::*;
 use std::borrow::Cow;
 use std::ops::Deref;
@@ -237,6 +238,23 @@ impl SortExpr {
     }
 }
 
+/// Order the row indexes within every group by the values of `by` in that group.
+fn argsort_groups(groups: &GroupTuples, by: &Series, reverse: bool) -> GroupTuples {
+    let lengths = groups.iter().map(|g| g.len() as u32).collect::<Vec<_>>();
+    GroupsIdx::from_lengths(&lengths, |i, idx| {
+        let g = groups.get(i);
+        // Safety:
+        // Group tuples are always in bounds
+        let group = unsafe { by.take_iter_unchecked(&mut g.iter()) };
+        let sorted_idx = group.argsort(reverse);
+        idx.iter_mut()
+            .zip(sorted_idx.cont_slice().unwrap())
+            .for_each(|(out, &i)| *out = g.get(i as usize));
+        idx.first().copied().unwrap_or_else(|| g.first())
+    })
+    .into()
+}
+
 impl PhysicalExpr for SortExpr {
     fn as_expression(&self) -> &Expr {
         &self.expr
@@ -247,7 +265,6 @@ impl PhysicalExpr for SortExpr {
         Ok(series.sort(self.reverse))
     }
 
-    #[allow(clippy::ptr_arg)]
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
@@ -255,29 +272,7 @@ impl PhysicalExpr for SortExpr {
     ) -> Result<(Series, Cow<'a, GroupTuples>)> {
         let (series, groups) = self.physical_expr.evaluate_on_groups(df, groups)?;
 
-        let groups = groups
-            .iter()
-            .map(|(_first, idx)| {
-                // Safety:
-                // Group tuples are always in bounds
-                let group =
-                    unsafe { series.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
-
-                let sorted_idx = group.argsort(self.reverse);
-
-                let new_idx: Vec<_> = sorted_idx
-                    .cont_slice()
-                    .unwrap()
-                    .iter()
-                    .map(|&i| {
-                        debug_assert!(idx.get(i as usize).is_some());
-                        unsafe { *idx.get_unchecked(i as usize) }
-                    })
-                    .collect();
-                (new_idx[0], new_idx)
-            })
-            .collect();
-
+        let groups = argsort_groups(&groups, &series, self.reverse);
         Ok((series, Cow::Owned(groups)))
     }
 
@@ -328,7 +323,6 @@ impl PhysicalExpr for SortByExpr {
         unsafe { series.take_unchecked(&sorted_idx) }
     }
 
-    #[allow(clippy::ptr_arg)]
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
@@ -337,30 +331,7 @@ impl PhysicalExpr for SortByExpr {
         let (series, _) = self.input.evaluate_on_groups(df, groups)?;
         let (series_sort_by, groups) = self.by.evaluate_on_groups(df, groups)?;
 
-        let groups = groups
-            .iter()
-            .map(|(_first, idx)| {
-                // Safety:
-                // Group tuples are always in bounds
-                let group = unsafe {
-                    series_sort_by.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize))
-                };
-
-                let sorted_idx = group.argsort(self.reverse);
-
-                let new_idx: Vec<_> = sorted_idx
-                    .cont_slice()
-                    .unwrap()
-                    .iter()
-                    .map(|&i| {
-                        debug_assert!(idx.get(i as usize).is_some());
-                        unsafe { *idx.get_unchecked(i as usize) }
-                    })
-                    .collect();
-                (new_idx[0], new_idx)
-            })
-            .collect();
-
+        let groups = argsort_groups(&groups, &series_sort_by, self.reverse);
         Ok((series, Cow::Owned(groups)))
     }
 
@@ -643,7 +614,8 @@ impl PhysicalExpr for ApplyExpr {
                 let input_field = self.input.to_field(input_schema)?;
                 Ok(Field::new(input_field.name(), output_type.clone()))
             }
-            None => self.input.to_field(input_schema),
+            // the output of functions may depend on the input, e.g. for the list functions
+            None => self.expr.to_field(input_schema, Context::Default),
         }
     }
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
@@ -652,66 +624,150 @@ impl PhysicalExpr for ApplyExpr {
 }
 
 pub struct WindowExpr {
-    /// the root column that the Function will be applied on.
+    /// the expressions that determine the partitions of the window.
+    pub(crate) group_by: Vec<Arc<dyn PhysicalExpr>>,
+    /// the expression that determines the order of the rows within a partition.
+    pub(crate) order_by: Option<Arc<dyn PhysicalExpr>>,
+    /// the root columns that the Function will be applied on.
     /// This will be used to create a smaller DataFrame to prevent taking unneeded columns by index
-    pub(crate) group_column: Arc<String>,
-    pub(crate) apply_column: Arc<String>,
+    pub(crate) apply_columns: Vec<String>,
     pub(crate) out_name: Arc<String>,
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) function: Expr,
+    pub(crate) phys_function: Arc<dyn PhysicalExpr>,
+}
+
+/// Sort the indexes within every group by the values of `by`.
+fn sort_groups(groups: &GroupTuples, by: &Series) -> GroupTuples {
+    // position of every row in the sorted order
+    let sort_idx = by.argsort(false);
+    let mut positions = vec![0u32; sort_idx.len()];
+    for (position, idx) in sort_idx.into_no_null_iter().enumerate() {
+        positions[idx as usize] = position as u32;
+    }
+
+    let lengths = groups.iter().map(|g| g.len() as u32).collect::<Vec<_>>();
+    GroupsIdx::from_lengths(&lengths, |i, idx| {
+        let g = groups.get(i);
+        idx.iter_mut()
+            .zip(g.iter())
+            .for_each(|(out, i)| *out = i as u32);
+        idx.sort_unstable_by_key(|&i| positions[i as usize]);
+        idx.first().copied().unwrap_or_else(|| g.first())
+    })
+    .into()
+}
+
+impl WindowExpr {
+    /// Aggregate every group and broadcast the aggregated value to all the rows of that group.
+    fn evaluate_aggregation(&self, df: &DataFrame, groups: &GroupTuples) -> Result<Series> {
+        let out = self
+            .phys_function
+            .as_agg_expr()?
+            .aggregate(df, groups)?
+            .ok_or_else(|| {
+                PolarsError::Other(
+                    format!(
+                        "the aggregation function did not succeed on {:?}",
+                        self.apply_columns
+                    )
+                    .into(),
+                )
+            })?;
+
+        let mut take_idx = vec![0u32; df.height()];
+        for (group_idx, g) in groups.iter().enumerate() {
+            for i in g.iter() {
+                take_idx[i] = group_idx as u32;
+            }
+        }
+        Ok(out.take(&UInt32Chunked::new_from_slice("", &take_idx)))
+    }
+
+    /// Apply the function on every group and put the results back at the rows of that group.
+    fn evaluate_per_group(&self, df: &DataFrame, groups: &GroupTuples) -> Result<Series> {
+        if groups.is_empty() {
+            return self.phys_function.evaluate(df);
+        }
+        // Take the rows once in group order, so that every group is a zero-copy slice.
+        let flat_idx: NoNull<UInt32Chunked> = groups
+            .iter()
+            .flat_map(|g| g.iter())
+            .map(|i| i as u32)
+            .collect();
+        let df = df.select(&self.apply_columns)?;
+        let grouped_df = df.take(&flat_idx.into_inner());
+
+        let mut offsets = Vec::with_capacity(groups.len());
+        let mut offset = 0;
+        for g in groups.iter() {
+            offsets.push((offset, g.len()));
+            offset += g.len();
+        }
+
+        let results = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let group_df = grouped_df.slice(offset as i64, len);
+                    let out = self.phys_function.evaluate(&group_df)?;
+                    if out.len() != len {
+                        return Err(PolarsError::ShapeMisMatch(
+                            format!(
+                                "the window function {:?} should return a value for every row of a group",
+                                self.function
+                            )
+                            .into(),
+                        ));
+                    }
+                    Ok(out)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let mut iter = results.into_iter();
+        let mut out = iter.next().unwrap();
+        for s in iter {
+            out.append(&s)?;
+        }
+
+        // `out` is ordered by group, so we determine for every row where its value is located.
+        let mut take_idx = vec![0u32; df.height()];
+        for (position, i) in groups.iter().flat_map(|g| g.iter()).enumerate() {
+            take_idx[i] = position as u32;
+        }
+        Ok(out.take(&UInt32Chunked::new_from_slice("", &take_idx)))
+    }
 }
 
 impl PhysicalExpr for WindowExpr {
-    // Note: this was first implemented with expression evaluation but this performed really bad.
-    // Therefore we choose the groupby -> apply -> self join approach
+    // Note: this was first implemented with expression evaluation per group, but this performed really bad
+    // for aggregations. Therefore aggregations are done with a groupby and are broadcasted to the rows
+    // of their groups. Only the functions that return a value per row are evaluated per group.
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
-        let gb = df
-            .groupby(self.group_column.as_str())?
-            .select(self.apply_column.as_str());
-
-        let out = match &self.function {
-            Expr::Udf { function, .. } => {
-                let mut df = gb.agg_list()?;
-                df.may_apply_at_idx(1, |s| function.call_udf(s.clone()))?;
-                Ok(df)
-            }
-            Expr::Agg(agg) => match agg {
-                AggExpr::Median(_) => gb.median(),
-                AggExpr::Mean(_) => gb.mean(),
-                AggExpr::Max(_) => gb.max(),
-                AggExpr::Min(_) => gb.min(),
-                AggExpr::Sum(_) => gb.sum(),
-                AggExpr::First(_) => gb.first(),
-                AggExpr::Last(_) => gb.last(),
-                AggExpr::Count(_) => gb.count(),
-                AggExpr::NUnique(_) => gb.n_unique(),
-                AggExpr::Quantile { quantile, .. } => gb.quantile(*quantile),
-                AggExpr::List(_) => gb.agg_list(),
-                AggExpr::AggGroups(_) => gb.groups(),
-                AggExpr::Std(_) => gb.std(),
-                AggExpr::Var(_) => gb.var(),
-            },
-            _ => Err(PolarsError::Other(
-                format!("{:?} function not supported", self.function).into(),
-            )),
+        let keys = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df))
+            .collect::<Result<Vec<_>>>()?;
+        let gb = df.groupby_with_series(keys, true)?;
+
+        let groups = match &self.order_by {
+            Some(order_by) => Cow::Owned(sort_groups(gb.get_groups(), &order_by.evaluate(df)?)),
+            None => Cow::Borrowed(gb.get_groups()),
+        };
+
+        let mut out = match &self.function {
+            Expr::Agg(_) => self.evaluate_aggregation(df, &groups),
+            _ => self.evaluate_per_group(df, &groups),
         }?;
-        let mut out = df
-            .select(self.group_column.as_str())?
-            .left_join(&out, self.group_column.as_str(), &self.group_column)?
-            .select_at_idx(1)
-            .unwrap_or_else(|| {
-                panic!(
-                    "the aggregation function did not succeed on {}",
-                    self.apply_column
-                )
-            })
-            .clone();
         out.rename(self.out_name.as_str());
         Ok(out)
     }
 
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        self.function.to_field(input_schema, Context::Default)
+        let field = self.function.to_field(input_schema, Context::Default)?;
+        Ok(Field::new(&self.out_name, field.data_type().clone()))
     }
 }
 
@@ -734,13 +790,33 @@ impl PhysicalExpr for SliceExpr {
     ) -> Result<(Series, Cow<'a, GroupTuples>)> {
         let s = self.input.evaluate(df)?;
 
-        let groups = groups
-            .iter()
-            .map(|(first, idx)| {
-                let (offset, len) = slice_offsets(self.offset, self.len, s.len());
-                (*first, idx[offset..offset + len].to_vec())
-            })
-            .collect();
+        let groups = match groups {
+            GroupTuples::Idx(_) => {
+                let lengths = groups
+                    .iter()
+                    .map(|g| slice_offsets(self.offset, self.len, g.len()).1 as u32)
+                    .collect::<Vec<_>>();
+                GroupsIdx::from_lengths(&lengths, |i, idx| {
+                    let g = groups.get(i);
+                    let (offset, _) = slice_offsets(self.offset, self.len, g.len());
+                    idx.iter_mut()
+                        .zip(g.iter().skip(offset))
+                        .for_each(|(out, i)| *out = i as u32);
+                    g.first()
+                })
+                .into()
+            }
+            GroupTuples::Slice(groups) => GroupTuples::Slice(
+                groups
+                    .iter()
+                    .map(|&[first, group_len]| {
+                        let (offset, len) =
+                            slice_offsets(self.offset, self.len, group_len as usize);
+                        [first + offset as u32, len as u32]
+                    })
+                    .collect(),
+            ),
+        };
 
         Ok((s, Cow::Owned(groups)))
     }
@@ -816,22 +892,22 @@ impl PhysicalExpr for FilterExpr {
         let predicate_s = self.by.evaluate(df)?;
         let predicate = predicate_s.bool()?;
 
-        let groups = groups
-            .par_iter()
-            .map(|(first, idx)| {
-                let idx: Vec<u32> = idx
-                    .iter()
-                    .filter_map(|i| match predicate.get(*i as usize) {
-                        Some(true) => Some(*i),
-                        _ => None,
-                    })
-                    .collect();
-
-                (*idx.get(0).unwrap_or(first), idx)
-            })
-            .collect();
-
-        Ok((s, Cow::Owned(groups)))
+        let is_selected = |i: &usize| predicate.get(*i) == Some(true);
+        let lengths = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| g.iter().filter(is_selected).count() as u32)
+                .collect::<Vec<_>>()
+        });
+        let groups = GroupsIdx::from_lengths(&lengths, |i, idx| {
+            let g = groups.get(i);
+            idx.iter_mut()
+                .zip(g.iter().filter(is_selected))
+                .for_each(|(out, i)| *out = i as u32);
+            idx.first().copied().unwrap_or_else(|| g.first())
+        });
+
+        Ok((s, Cow::Owned(groups.into())))
     }
 
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
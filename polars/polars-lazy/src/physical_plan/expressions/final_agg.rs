@@ -72,6 +72,29 @@ impl PhysicalAggregation for SortByExpr {
     }
 }
 
+/// Compute an aggregation that only depends on the values of every group. Returns an error if
+/// the aggregation is not supported for the data type of the Series.
+pub(crate) fn agg_values(
+    series: &Series,
+    groups: &GroupTuples,
+    agg_type: GroupByMethod,
+) -> Result<Series> {
+    match agg_type {
+        GroupByMethod::ArgMin => series.agg_arg_min(groups),
+        GroupByMethod::ArgMax => series.agg_arg_max(groups),
+        GroupByMethod::Mode => series.agg_mode(groups).ok_or_else(|| {
+            PolarsError::InvalidOperation(
+                format!("cannot compute the mode of data type {:?}", series.dtype()).into(),
+            )
+        }),
+        GroupByMethod::Product => series.agg_product(groups),
+        GroupByMethod::Any => series.agg_any(groups),
+        GroupByMethod::All => series.agg_all(groups),
+        GroupByMethod::NNulls => Ok(series.agg_n_nulls(groups).into_series()),
+        _ => unreachable!(),
+    }
+}
+
 fn rename_option_series(opt: Option<Series>, name: &str) -> Option<Series> {
     opt.map(|mut s| {
         s.rename(name);
@@ -98,16 +121,15 @@ impl PhysicalAggregation for AggregationExpr {
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Mean => {
-                let agg_s = series.agg_mean(&groups);
+                let agg_s = series.try_agg_mean(&groups)?;
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Sum => {
-                let agg_s = series.agg_sum(&groups);
+                let agg_s = series.try_agg_sum(&groups)?;
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Count => {
-                let mut ca: NoNull<UInt32Chunked> =
-                    groups.iter().map(|(_, g)| g.len() as u32).collect();
+                let mut ca: NoNull<UInt32Chunked> = groups.iter().map(|g| g.len() as u32).collect();
                 ca.rename(&new_name);
                 Ok(Some(ca.into_inner().into_series()))
             }
@@ -134,13 +156,7 @@ impl PhysicalAggregation for AggregationExpr {
                 Ok(rename_option_series(opt_agg, &new_name))
             }
             GroupByMethod::Groups => {
-                let mut column: ListChunked = groups
-                    .iter()
-                    .map(|(_first, idx)| {
-                        let ca: NoNull<UInt32Chunked> = idx.iter().map(|&v| v as u32).collect();
-                        ca.into_inner().into_series()
-                    })
-                    .collect();
+                let mut column = groups.to_list();
 
                 column.rename(&new_name);
                 Ok(Some(column.into_series()))
@@ -153,6 +169,17 @@ impl PhysicalAggregation for AggregationExpr {
                 let agg_s = series.agg_var(&groups);
                 Ok(rename_option_series(agg_s, &new_name))
             }
+            GroupByMethod::ArgMin
+            | GroupByMethod::ArgMax
+            | GroupByMethod::Mode
+            | GroupByMethod::Product
+            | GroupByMethod::Any
+            | GroupByMethod::All
+            | GroupByMethod::NNulls => {
+                let mut agg_s = agg_values(&series, &groups, self.agg_type)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s))
+            }
             GroupByMethod::Quantile(_) => {
                 unimplemented!()
             }
@@ -173,8 +200,7 @@ impl PhysicalAggregation for AggregationExpr {
                 if let Some(mut agg_s) = agg_s {
                     agg_s.rename(&new_name);
                     new_name.push_str("__POLARS_MEAN_COUNT");
-                    let ca: NoNull<UInt32Chunked> =
-                        groups.iter().map(|t| t.1.len() as u32).collect();
+                    let ca: NoNull<UInt32Chunked> = groups.iter().map(|g| g.len() as u32).collect();
                     let mut count_s = ca.into_inner().into_series();
                     count_s.rename(&new_name);
                     Ok(Some(vec![agg_s, count_s]))
@@ -223,10 +249,10 @@ impl PhysicalAggregation for AggregationExpr {
 
                 let mut builder =
                     get_list_builder(&values_type, ca.get_values_size(), ca.len(), &new_name);
-                for (_, idx) in groups {
+                for g in groups.iter() {
                     // Safety
                     // The indexes of the groupby operation are never out of bounds
-                    let ca = unsafe { ca.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+                    let ca = unsafe { ca.take_unchecked(g.iter().into()) };
                     let s = ca.explode_and_offsets()?.0;
                     builder.append_series(&s);
                 }
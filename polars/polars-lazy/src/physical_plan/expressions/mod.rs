@@ -1,5 +1,5 @@
 pub mod default;
-mod final_agg;
+pub(crate) mod final_agg;
 
 use crate::prelude::*;
 pub use default::*;
@@ -23,8 +23,6 @@ pub trait PhysicalExpr: Send + Sync {
     /// Think of sort, slice, etc.
     ///
     /// defaults to ignoring the group
-    // we allow this because we pass the vec to the Cow
-    #[allow(clippy::ptr_arg)]
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
@@ -76,12 +74,10 @@ impl PhysicalIoExpr for dyn PhysicalExpr {
 }
 
 pub trait PhysicalAggregation {
-    #[allow(clippy::ptr_arg)]
     /// Should be called on the final aggregation node like sum, min, max, etc.
     /// When called on a tail, slice, sort, etc. it should return a list-array
     fn aggregate(&self, df: &DataFrame, groups: &GroupTuples) -> Result<Option<Series>>;
 
-    #[allow(clippy::ptr_arg)]
     fn evaluate_partitioned(
         &self,
         df: &DataFrame,
@@ -91,7 +87,6 @@ pub trait PhysicalAggregation {
         self.aggregate(df, groups).map(|opt| opt.map(|s| vec![s]))
     }
 
-    #[allow(clippy::ptr_arg)]
     fn evaluate_partitioned_final(
         &self,
         final_df: &DataFrame,
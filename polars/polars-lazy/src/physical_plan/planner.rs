@@ -1,12 +1,14 @@
 use super::expressions as phys_expr;
 use crate::logical_plan::Context;
 use crate::physical_plan::executors::*;
+use crate::physical_plan::expressions::final_agg::agg_values;
 use crate::prelude::*;
 use crate::utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr};
 use ahash::RandomState;
 use itertools::Itertools;
+use polars_core::frame::groupby::{GroupByMethod, GroupTuples};
 use polars_core::prelude::*;
-use polars_core::{frame::groupby::GroupByMethod, utils::parallel_op};
+use polars_core::utils::parallel_op;
 use polars_io::ScanAggregation;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -93,20 +95,31 @@ impl DefaultPlanner {
         use ALogicalPlan::*;
         let logical_plan = lp_arena.take(root);
         match logical_plan {
-            Melt {
-                input,
-                id_vars,
-                value_vars,
-                ..
-            } => {
+            Melt { input, args, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                Ok(Box::new(MeltExec {
-                    input,
-                    id_vars,
-                    value_vars,
-                }))
+                Ok(Box::new(MeltExec { input, args }))
             }
             Slice { input, offset, len } => {
+                // a slice on top of a sort only needs the first `offset + len` sorted rows
+                if offset >= 0 {
+                    if let Sort {
+                        input: sort_input,
+                        by_column,
+                        reverse,
+                    } = lp_arena.get(input)
+                    {
+                        let (sort_input, by_column, reverse) =
+                            (*sort_input, by_column.clone(), *reverse);
+                        let sort_input =
+                            self.create_initial_physical_plan(sort_input, lp_arena, expr_arena)?;
+                        return Ok(Box::new(SortExec {
+                            input: sort_input,
+                            by_column,
+                            reverse,
+                            slice: Some((offset, len)),
+                        }));
+                    }
+                }
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(SliceExec { input, offset, len }))
             }
@@ -210,6 +223,7 @@ impl DefaultPlanner {
                     input,
                     by_column,
                     reverse,
+                    slice: None,
                 }))
             }
             Explode { input, columns } => {
@@ -251,6 +265,7 @@ impl DefaultPlanner {
                 apply,
                 ..
             } => {
+                let input_schema = lp_arena.get(input).schema(lp_arena).clone();
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
 
                 // We first check if we can partition the groupby on the latest moment.
@@ -269,6 +284,15 @@ impl DefaultPlanner {
                             partitionable = false;
                             break;
                         }
+                        // the partial results of decimals cannot be combined by the final aggregation
+                        if let Ok(DataType::Decimal(..)) = expr_arena.get(*agg).get_type(
+                            &input_schema,
+                            Context::Aggregation,
+                            expr_arena,
+                        ) {
+                            partitionable = false;
+                            break;
+                        }
 
                         let agg = node_to_exp(*agg, expr_arena);
 
@@ -325,13 +349,12 @@ impl DefaultPlanner {
                 how,
                 left_on,
                 right_on,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => {
-                let parallel = if force_par {
-                    force_par
-                } else if allow_par {
+                let parallel = if options.force_parallel {
+                    true
+                } else if options.allow_parallel {
                     // check if two DataFrames come from a separate source. If they don't we hope it is cached.
                     let mut sources_left =
                         HashSet::with_capacity_and_hasher(32, RandomState::default());
@@ -362,8 +385,32 @@ impl DefaultPlanner {
                     left_on,
                     right_on,
                     parallel,
+                    options,
                 )))
             }
+            Union { inputs, mode, .. } => {
+                // the inputs are computed in parallel if they don't share a source.
+                let mut sources = HashSet::with_capacity_and_hasher(32, RandomState::default());
+                let mut parallel = true;
+                for &input in &inputs {
+                    let mut input_sources =
+                        HashSet::with_capacity_and_hasher(32, RandomState::default());
+                    agg_source_paths(input, &mut input_sources, lp_arena);
+                    for path in input_sources {
+                        parallel &= sources.insert(path);
+                    }
+                }
+
+                let inputs = inputs
+                    .into_iter()
+                    .map(|input| self.create_initial_physical_plan(input, lp_arena, expr_arena))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(UnionExec {
+                    inputs,
+                    mode,
+                    parallel,
+                }))
+            }
             HStack { input, exprs, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 let phys_expr =
@@ -391,30 +438,56 @@ impl DefaultPlanner {
             Window {
                 mut function,
                 partition_by,
-                order_by: _,
+                order_by,
             } => {
-                // TODO! Order by
-                let group_column = aexpr_to_root_names(partition_by, expr_arena)
-                    .pop()
-                    .expect("need a partition_by column for a window function");
+                let group_by = partition_by
+                    .into_iter()
+                    .map(|node| self.create_physical_expr(node, Context::Default, expr_arena))
+                    .collect::<Result<Vec<_>>>()?;
+                if group_by.is_empty() {
+                    return Err(PolarsError::Other(
+                        "need a partition_by column for a window function".into(),
+                    ));
+                }
+                let order_by = order_by
+                    .map(|node| self.create_physical_expr(node, Context::Default, expr_arena))
+                    .transpose()?;
+
                 let out_name;
-                let apply_column = aexpr_to_root_names(function, expr_arena)
-                    .pop()
-                    .expect("need a root column for a window function");
+                let mut apply_columns = aexpr_to_root_names(function, expr_arena)
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>();
+                apply_columns.sort();
+                apply_columns.dedup();
 
                 if let Alias(expr, name) = expr_arena.get(function) {
                     function = *expr;
                     out_name = name.clone();
                 } else {
-                    out_name = group_column.clone();
+                    out_name = aexpr_to_root_names(function, expr_arena)
+                        .pop()
+                        .ok_or_else(|| {
+                            PolarsError::ValueError(
+                                "need a root column for a window function, e.g. col(\"a\").sum().over(col(\"b\"))".into(),
+                            )
+                        })?;
                 }
+                // aggregations are computed per group, other functions are evaluated on the rows of a group
+                let ctxt = match expr_arena.get(function) {
+                    Agg(_) => Context::Aggregation,
+                    _ => Context::Default,
+                };
+                let phys_function = self.create_physical_expr(function, ctxt, expr_arena)?;
                 let function = node_to_exp(function, expr_arena);
 
                 Ok(Arc::new(WindowExpr {
-                    group_column,
-                    apply_column,
+                    group_by,
+                    order_by,
+                    apply_columns,
                     out_name,
                     function,
+                    phys_function,
                 }))
             }
             Literal(value) => Ok(Arc::new(LiteralExpr::new(
@@ -542,7 +615,7 @@ impl DefaultPlanner {
                             }
                             Context::Default => {
                                 let function = NoEq::new(Arc::new(move |s: Series| {
-                                    parallel_op(|s| Ok(s.sum_as_series()), s, None)
+                                    parallel_op(|s| s.try_sum_as_series(), s, None)
                                 })
                                     as Arc<dyn SeriesUdf>);
                                 Ok(Arc::new(ApplyExpr {
@@ -601,7 +674,7 @@ impl DefaultPlanner {
                             Context::Default => {
                                 let function = NoEq::new(Arc::new(move |s: Series| {
                                     let len = s.len() as f64;
-                                    parallel_op(|s| Ok(s.sum_as_series()), s, None)
+                                    parallel_op(|s| s.try_sum_as_series(), s, None)
                                         .map(|s| s.cast::<Float64Type>().unwrap() / len)
                                 })
                                     as Arc<dyn SeriesUdf>);
@@ -761,6 +834,45 @@ impl DefaultPlanner {
                             }
                         }
                     }
+                    AAggExpr::ArgMin(expr)
+                    | AAggExpr::ArgMax(expr)
+                    | AAggExpr::Mode(expr)
+                    | AAggExpr::Product(expr)
+                    | AAggExpr::Any(expr)
+                    | AAggExpr::All(expr)
+                    | AAggExpr::NNulls(expr) => {
+                        let agg_type = match agg {
+                            AAggExpr::ArgMin(_) => GroupByMethod::ArgMin,
+                            AAggExpr::ArgMax(_) => GroupByMethod::ArgMax,
+                            AAggExpr::Mode(_) => GroupByMethod::Mode,
+                            AAggExpr::Product(_) => GroupByMethod::Product,
+                            AAggExpr::Any(_) => GroupByMethod::Any,
+                            AAggExpr::All(_) => GroupByMethod::All,
+                            _ => GroupByMethod::NNulls,
+                        };
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                Ok(Arc::new(AggregationExpr::new(input, agg_type)))
+                            }
+                            Context::Default => {
+                                // the whole Series is aggregated as a single group
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    let groups = GroupTuples::Slice(vec![[0, s.len() as u32]]);
+                                    let mut out = agg_values(&s, &groups, agg_type)?;
+                                    out.rename(s.name());
+                                    Ok(out)
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                }))
+                            }
+                        }
+                    }
                 }
             }
             Cast { expr, data_type } => {
@@ -783,15 +895,13 @@ impl DefaultPlanner {
                 }))
             }
             Udf {
-                input,
-                function,
-                output_type,
+                input, function, ..
             } => {
                 let input = self.create_physical_expr(input, ctxt, expr_arena)?;
                 Ok(Arc::new(ApplyExpr {
                     input,
                     function,
-                    output_type,
+                    output_type: None,
                     expr: node_to_exp(expression, expr_arena),
                 }))
             }
@@ -8,7 +8,7 @@ pub use crate::{
     frame::*,
     logical_plan::{
         optimizer::{type_coercion::TypeCoercionRule, Optimize, *},
-        DataFrameUdf, LiteralValue, LogicalPlan, LogicalPlanBuilder,
+        DataFrameUdf, GroupByApply, LiteralValue, LogicalPlan, LogicalPlanBuilder,
     },
     physical_plan::{
         executors::{CsvExec, DataFrameExec, FilterExec, GroupByExec, StandardExec},
@@ -16,4 +16,5 @@ pub use crate::{
         planner::DefaultPlanner,
         Executor, PhysicalPlanner,
     },
+    sql::SQLContext,
 };
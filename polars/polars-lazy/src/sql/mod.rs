@@ -0,0 +1,999 @@
+//! Query `DataFrame`s and lazy scans with SQL.
+//!
+//! A SQL query is parsed and translated to a [LazyFrame](crate::frame::LazyFrame), so the query
+//! is optimized by the same query optimizer as the lazy API.
+mod parser;
+
+use crate::prelude::*;
+use crate::sql::parser::*;
+use ahash::RandomState;
+use polars_core::prelude::*;
+use std::collections::HashMap;
+
+/// Aggregation functions that are supported in SQL queries.
+const AGGREGATIONS: &[&str] = &[
+    "COUNT",
+    "SUM",
+    "AVG",
+    "MEAN",
+    "MIN",
+    "MAX",
+    "MEDIAN",
+    "FIRST",
+    "LAST",
+    "STD",
+    "STDDEV",
+    "STDDEV_SAMP",
+    "VAR",
+    "VARIANCE",
+    "VAR_SAMP",
+];
+
+fn is_aggregation(e: &SqlExpr) -> bool {
+    matches!(e, SqlExpr::Function { name, .. } if AGGREGATIONS.contains(&name.as_str()))
+}
+
+/// Call `f` on the direct children of an expression and rebuild the expression with the results.
+fn map_children<F>(e: &SqlExpr, mut f: F) -> Result<SqlExpr>
+where
+    F: FnMut(&SqlExpr) -> Result<SqlExpr>,
+{
+    use SqlExpr::*;
+    let mut f_box = |e: &SqlExpr| f(e).map(Box::new);
+    let out = match e {
+        BinaryOp { left, op, right } => BinaryOp {
+            left: f_box(left)?,
+            op: *op,
+            right: f_box(right)?,
+        },
+        Not(e) => Not(f_box(e)?),
+        Negative(e) => Negative(f_box(e)?),
+        IsNull { expr, negated } => IsNull {
+            expr: f_box(expr)?,
+            negated: *negated,
+        },
+        Between {
+            expr,
+            low,
+            high,
+            negated,
+        } => Between {
+            expr: f_box(expr)?,
+            low: f_box(low)?,
+            high: f_box(high)?,
+            negated: *negated,
+        },
+        InList {
+            expr,
+            list,
+            negated,
+        } => InList {
+            expr: f_box(expr)?,
+            list: list
+                .iter()
+                .map(|e| f_box(e).map(|e| *e))
+                .collect::<Result<_>>()?,
+            negated: *negated,
+        },
+        Function {
+            name,
+            args,
+            distinct,
+        } => Function {
+            name: name.clone(),
+            args: args
+                .iter()
+                .map(|e| f_box(e).map(|e| *e))
+                .collect::<Result<_>>()?,
+            distinct: *distinct,
+        },
+        Case {
+            operand,
+            conditions,
+            else_result,
+        } => Case {
+            operand: operand.as_ref().map(|e| f_box(e)).transpose()?,
+            conditions: conditions
+                .iter()
+                .map(|(c, r)| Ok((*f_box(c)?, *f_box(r)?)))
+                .collect::<Result<_>>()?,
+            else_result: else_result.as_ref().map(|e| f_box(e)).transpose()?,
+        },
+        Cast { expr, data_type } => Cast {
+            expr: f_box(expr)?,
+            data_type: data_type.clone(),
+        },
+        e => e.clone(),
+    };
+    Ok(out)
+}
+
+fn contains_aggregation(e: &SqlExpr) -> bool {
+    let mut found = false;
+    let _ = map_children(e, |child| {
+        found |= contains_aggregation(child);
+        Ok(child.clone())
+    });
+    found || is_aggregation(e)
+}
+
+/// The name of a select item without an alias.
+fn default_name(e: &SqlExpr) -> Option<String> {
+    use SqlExpr::*;
+    match e {
+        Column { name, .. } => Some(name.clone()),
+        Function { name, .. } => Some(name.to_lowercase()),
+        Cast { expr, .. } | Negative(expr) | Not(expr) | IsNull { expr, .. } => default_name(expr),
+        BinaryOp { left, right, .. } => default_name(left).or_else(|| default_name(right)),
+        _ => None,
+    }
+}
+
+fn column_ref(name: &str) -> SqlExpr {
+    SqlExpr::Column {
+        table: None,
+        name: name.to_string(),
+    }
+}
+
+/// Resolves the column references in a query to the columns of a `LazyFrame`.
+#[derive(Default)]
+struct Scope {
+    /// The tables in the query with a mapping from their column names to the
+    /// column names in the `LazyFrame`. Columns are renamed when they collide in a join.
+    tables: Vec<(String, Vec<(String, String)>)>,
+    /// The columns of the `LazyFrame`.
+    columns: Vec<String>,
+}
+
+impl Scope {
+    fn from_columns(columns: Vec<String>) -> Self {
+        Scope {
+            tables: vec![],
+            columns,
+        }
+    }
+
+    fn from_table(reference: &str, columns: Vec<String>) -> Self {
+        let mapping = columns.iter().map(|c| (c.clone(), c.clone())).collect();
+        Scope {
+            tables: vec![(reference.to_string(), mapping)],
+            columns,
+        }
+    }
+
+    fn table(&self, reference: &str) -> Result<&[(String, String)]> {
+        self.tables
+            .iter()
+            .find(|(table, _)| table == reference)
+            .map(|(_, mapping)| mapping.as_slice())
+            .ok_or_else(|| PolarsError::NotFound(format!("table {}", reference)))
+    }
+
+    fn resolve(&self, table: Option<&str>, name: &str) -> Result<String> {
+        match table {
+            Some(table) => self
+                .table(table)?
+                .iter()
+                .find(|(original, _)| original == name)
+                .map(|(_, resolved)| resolved.clone())
+                .ok_or_else(|| PolarsError::NotFound(format!("column {}.{}", table, name))),
+            None if self.columns.iter().any(|c| c == name) => Ok(name.to_string()),
+            None => self
+                .tables
+                .iter()
+                .flat_map(|(_, mapping)| mapping.iter())
+                .find(|(original, _)| original == name)
+                .map(|(_, resolved)| resolved.clone())
+                .ok_or_else(|| PolarsError::NotFound(format!("column {}", name))),
+        }
+    }
+
+    /// Check if the expression refers to a column of the given table.
+    fn refers_to(&self, e: &SqlExpr, reference: &str) -> bool {
+        match e {
+            SqlExpr::Column {
+                table: Some(table), ..
+            } => table == reference,
+            SqlExpr::Column { table: None, name } => self
+                .table(reference)
+                .map(|mapping| mapping.iter().any(|(original, _)| original == name))
+                .unwrap_or(false),
+            e => {
+                let mut found = false;
+                let _ = map_children(e, |child| {
+                    found |= self.refers_to(child, reference);
+                    Ok(child.clone())
+                });
+                found
+            }
+        }
+    }
+
+    fn translate(&self, e: &SqlExpr) -> Result<Expr> {
+        use SqlExpr::*;
+        let expr = match e {
+            Column { table, name } => col(&self.resolve(table.as_deref(), name)?),
+            Wildcard => {
+                return Err(PolarsError::ValueError(
+                    "`*` is only allowed in COUNT(*)".into(),
+                ))
+            }
+            Number(n) => parse_number(n, false)?,
+            String(s) => lit(s.as_str()),
+            Boolean(b) => lit(*b),
+            Null => lit(crate::dsl::Null {}),
+            BinaryOp { left, op, right } => {
+                binary_expr(self.translate(left)?, *op, self.translate(right)?)
+            }
+            Not(e) => self.translate(e)?.not(),
+            Negative(e) => match &**e {
+                Number(n) => parse_number(n, true)?,
+                e => lit(0) - self.translate(e)?,
+            },
+            IsNull { expr, negated } => {
+                let expr = self.translate(expr)?;
+                if *negated {
+                    expr.is_not_null()
+                } else {
+                    expr.is_null()
+                }
+            }
+            Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let expr = self.translate(expr)?;
+                let between = expr
+                    .clone()
+                    .gt_eq(self.translate(low)?)
+                    .and(expr.lt_eq(self.translate(high)?));
+                if *negated {
+                    between.not()
+                } else {
+                    between
+                }
+            }
+            InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let expr = self.translate(expr)?;
+                let mut is_in = None;
+                for e in list {
+                    let eq = expr.clone().eq(self.translate(e)?);
+                    is_in = Some(match is_in {
+                        Some(is_in) => eq.or(is_in),
+                        None => eq,
+                    })
+                }
+                let is_in = is_in.expect("list is not empty");
+                if *negated {
+                    is_in.not()
+                } else {
+                    is_in
+                }
+            }
+            Function {
+                name,
+                args,
+                distinct,
+            } => self.translate_function(name, args, *distinct)?,
+            Case {
+                operand,
+                conditions,
+                else_result,
+            } => {
+                let mut expr = match else_result {
+                    Some(e) => self.translate(e)?,
+                    None => lit(crate::dsl::Null {}),
+                };
+                for (condition, result) in conditions.iter().rev() {
+                    let condition = match operand {
+                        Some(operand) => self.translate(operand)?.eq(self.translate(condition)?),
+                        None => self.translate(condition)?,
+                    };
+                    expr = when(condition)
+                        .then(self.translate(result)?)
+                        .otherwise(expr);
+                }
+                expr
+            }
+            Cast { expr, data_type } => self.translate(expr)?.cast(data_type.clone()),
+        };
+        Ok(expr)
+    }
+
+    fn translate_function(&self, name: &str, args: &[SqlExpr], distinct: bool) -> Result<Expr> {
+        let wrong_args = || {
+            Err(PolarsError::ValueError(
+                format!("wrong number of arguments for sql function {}", name).into(),
+            ))
+        };
+        if AGGREGATIONS.contains(&name) {
+            if args.len() != 1 {
+                return wrong_args();
+            }
+            if args.iter().any(contains_aggregation) {
+                return Err(PolarsError::ValueError(
+                    format!("aggregations cannot be nested in {}", name).into(),
+                ));
+            }
+        }
+
+        let expr = match (name, args) {
+            ("COUNT", [SqlExpr::Wildcard]) => {
+                // count the rows of the group
+                let first = self.columns.first().ok_or_else(|| {
+                    PolarsError::NoData("cannot count the rows of a table without columns".into())
+                })?;
+                col(first).count()
+            }
+            ("COUNT", [e]) if distinct => self.translate(e)?.n_unique(),
+            ("COUNT", [e]) => self.translate(e)?.count(),
+            ("SUM", [e]) => self.translate(e)?.sum(),
+            ("AVG", [e]) | ("MEAN", [e]) => self.translate(e)?.mean(),
+            ("MIN", [e]) => self.translate(e)?.min(),
+            ("MAX", [e]) => self.translate(e)?.max(),
+            ("MEDIAN", [e]) => self.translate(e)?.median(),
+            ("FIRST", [e]) => self.translate(e)?.first(),
+            ("LAST", [e]) => self.translate(e)?.last(),
+            ("STD", [e]) | ("STDDEV", [e]) | ("STDDEV_SAMP", [e]) => self.translate(e)?.std(),
+            ("VAR", [e]) | ("VARIANCE", [e]) | ("VAR_SAMP", [e]) => self.translate(e)?.var(),
+            ("COALESCE", [first, rest @ ..]) => {
+                let mut expr = self.translate(first)?;
+                for e in rest {
+                    expr = when(expr.clone().is_not_null())
+                        .then(expr)
+                        .otherwise(self.translate(e)?);
+                }
+                expr
+            }
+            ("POWER", [e, SqlExpr::Number(exponent)]) | ("POW", [e, SqlExpr::Number(exponent)]) => {
+                let exponent = exponent.parse::<f64>().map_err(|_| {
+                    PolarsError::ValueError(format!("could not parse number {}", exponent).into())
+                })?;
+                self.translate(e)?.pow(exponent)
+            }
+            ("COALESCE", _) | ("POWER", _) | ("POW", _) => return wrong_args(),
+            _ => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("sql function {} is not supported", name).into(),
+                ))
+            }
+        };
+        Ok(expr)
+    }
+}
+
+fn parse_number(n: &str, negative: bool) -> Result<Expr> {
+    let err = || PolarsError::ValueError(format!("could not parse number {}", n).into());
+    let sign = if negative { -1 } else { 1 };
+    if n.contains('.') {
+        let v = n.parse::<f64>().map_err(|_| err())?;
+        Ok(lit(sign as f64 * v))
+    } else {
+        let v = sign * n.parse::<i64>().map_err(|_| err())?;
+        if v >= i32::MIN as i64 && v <= i32::MAX as i64 {
+            Ok(lit(v as i32))
+        } else {
+            Ok(lit(v))
+        }
+    }
+}
+
+/// State of a query with aggregations. The aggregations and the group keys are replaced by
+/// references to the columns of the aggregated `LazyFrame`.
+struct Aggregation<'a> {
+    scope: &'a Scope,
+    /// The group keys and their column names in the aggregated `LazyFrame`.
+    keys: Vec<(Expr, String)>,
+    aggs: Vec<Expr>,
+}
+
+impl<'a> Aggregation<'a> {
+    fn agg_name(idx: usize) -> String {
+        format!("__agg_{}", idx)
+    }
+
+    fn rewrite(&mut self, e: &SqlExpr) -> Result<SqlExpr> {
+        if is_aggregation(e) {
+            let agg = self.scope.translate(e)?;
+            let idx = match self.aggs.iter().position(|a| a == &agg) {
+                Some(idx) => idx,
+                None => {
+                    self.aggs.push(agg);
+                    self.aggs.len() - 1
+                }
+            };
+            return Ok(column_ref(&Self::agg_name(idx)));
+        }
+        if !contains_aggregation(e) {
+            let expr = self.scope.translate(e)?;
+            if let Some((_, name)) = self.keys.iter().find(|(key, _)| key == &expr) {
+                return Ok(column_ref(name));
+            }
+            if let SqlExpr::Column { name, .. } = e {
+                return Err(PolarsError::ValueError(
+                    format!(
+                        "column {} must appear in the GROUP BY clause or be used in an aggregate function",
+                        name
+                    )
+                    .into(),
+                ));
+            }
+        }
+        map_children(e, |child| self.rewrite(child))
+    }
+
+    /// The columns of the aggregated `LazyFrame`.
+    fn columns(&self) -> Vec<String> {
+        self.keys
+            .iter()
+            .map(|(_, name)| name.clone())
+            .chain((0..self.aggs.len()).map(Self::agg_name))
+            .collect()
+    }
+}
+
+/// A context to register `DataFrame`s and lazy scans as tables and query them with SQL.
+///
+/// The supported syntax is
+/// `SELECT [DISTINCT] ... FROM ... [[INNER | LEFT | FULL] JOIN ... ON ...] [WHERE ...]
+/// [GROUP BY ...] [HAVING ...] [ORDER BY ... [ASC | DESC]] [LIMIT ...] [OFFSET ...]`.
+/// Joins are equi-joins; the conditions in the `ON` clause are equalities combined with `AND`.
+/// Note that `COUNT` counts all rows of a group, including null values.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// fn example(df: DataFrame) -> Result<DataFrame> {
+///     let mut ctx = SQLContext::new();
+///     ctx.register("iris", df.lazy());
+///     ctx.execute(
+///         "SELECT variety, AVG(\"sepal.width\") AS width FROM iris \
+///          WHERE \"sepal.length\" > 5.0 GROUP BY variety ORDER BY width DESC",
+///     )?
+///     .collect()
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct SQLContext {
+    tables: HashMap<String, LazyFrame, RandomState>,
+}
+
+impl SQLContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a `LazyFrame` as a table. A `DataFrame` can be registered with `df.lazy()`.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_string(), lf);
+    }
+
+    /// Remove a table from the context.
+    pub fn unregister(&mut self, name: &str) {
+        self.tables.remove(name);
+    }
+
+    fn get_table(&self, name: &str) -> Result<LazyFrame> {
+        self.tables
+            .get(name)
+            .cloned()
+            .ok_or_else(|| PolarsError::NotFound(format!("table {}", name)))
+    }
+
+    /// Translate a SQL query to a `LazyFrame`.
+    pub fn execute(&self, query: &str) -> Result<LazyFrame> {
+        let query = parse_sql(query)?;
+
+        let mut lf = self.get_table(&query.from.name)?;
+        let mut scope = Scope::from_table(query.from.reference(), column_names(&lf));
+        for join in &query.joins {
+            lf = self.join(lf, join, &mut scope)?;
+        }
+
+        if let Some(selection) = &query.selection {
+            if contains_aggregation(selection) {
+                return Err(PolarsError::ValueError(
+                    "aggregations are not allowed in a WHERE clause".into(),
+                ));
+            }
+            lf = lf.filter(scope.translate(selection)?);
+        }
+
+        // expand the wildcards
+        let mut projection = vec![];
+        for item in &query.projection {
+            match item {
+                SelectItem::Wildcard => projection.extend(
+                    scope
+                        .columns
+                        .iter()
+                        .map(|name| (column_ref(name), None::<String>)),
+                ),
+                SelectItem::QualifiedWildcard(table) => {
+                    for (_, resolved) in scope.table(table)? {
+                        // the join keys of a right table map to the left keys
+                        if !projection.iter().any(|(e, _)| e == &column_ref(resolved)) {
+                            projection.push((column_ref(resolved), None))
+                        }
+                    }
+                }
+                SelectItem::Expr { expr, alias } => projection.push((expr.clone(), alias.clone())),
+            }
+        }
+
+        let is_aggregation = !query.group_by.is_empty()
+            || query.having.is_some()
+            || projection.iter().any(|(e, _)| contains_aggregation(e));
+
+        // The keys are either all sorted after the projection, or, if a key is not selected, all
+        // sorted before the projection.
+        let sort_keys = self.sort_keys(&query, &projection)?;
+        let mut sort_by = vec![];
+        if sort_keys
+            .iter()
+            .all(|(key, _)| matches!(key, SortKey::Output(_)))
+        {
+            sort_by = sort_keys
+                .into_iter()
+                .filter_map(|(key, reverse)| match key {
+                    SortKey::Output(idx) => Some((idx, reverse)),
+                    SortKey::Input { .. } => None,
+                })
+                .collect();
+        } else {
+            if is_aggregation {
+                return Err(PolarsError::ValueError(
+                    "the ORDER BY expression should be in the SELECT list of an aggregation".into(),
+                ));
+            }
+            let mut by_columns = Vec::with_capacity(sort_keys.len());
+            let mut reverse = Vec::with_capacity(sort_keys.len());
+            for (key, descending) in sort_keys {
+                let name = match key {
+                    SortKey::Input { table, name } => scope.resolve(table.as_deref(), &name)?,
+                    // a selected column or expression is sorted on the column it selects
+                    SortKey::Output(idx) => match &projection[idx].0 {
+                        SqlExpr::Column { table, name } => {
+                            scope.resolve(table.as_deref(), name)?
+                        }
+                        _ => {
+                            return Err(PolarsError::InvalidOperation(
+                                "cannot ORDER BY a computed column together with a column that is not selected".into(),
+                            ))
+                        }
+                    },
+                };
+                by_columns.push(name);
+                reverse.push(descending);
+            }
+            lf = lf.sort_by_columns(by_columns, reverse);
+        }
+
+        lf = if is_aggregation {
+            self.aggregate(lf, &query, &scope, &projection)?
+        } else {
+            let exprs = projection
+                .iter()
+                .map(|(e, alias)| {
+                    let expr = scope.translate(e)?;
+                    Ok(match alias {
+                        Some(alias) => expr.alias(alias),
+                        None => expr,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            lf.select(exprs)
+        };
+
+        if query.distinct {
+            lf = lf.drop_duplicates(true, None);
+        }
+        if !sort_by.is_empty() {
+            let names = column_names(&lf);
+            let (by_columns, reverse) = sort_by
+                .into_iter()
+                .map(|(idx, reverse)| (names[idx].clone(), reverse))
+                .unzip();
+            lf = lf.sort_by_columns(by_columns, reverse);
+        }
+        match (query.offset, query.limit) {
+            (Some(offset), limit) => lf = lf.slice(offset as i64, limit.unwrap_or(usize::MAX)),
+            (None, Some(limit)) => lf = lf.limit(limit),
+            (None, None) => {}
+        }
+        Ok(lf)
+    }
+
+    fn join(&self, lf: LazyFrame, join: &Join, scope: &mut Scope) -> Result<LazyFrame> {
+        let right = self.get_table(&join.table.name)?;
+        let reference = join.table.reference();
+        if scope.table(reference).is_ok() {
+            return Err(PolarsError::Duplicate(
+                format!("table {} is used more than once, use an alias", reference).into(),
+            ));
+        }
+        let right_columns = column_names(&right);
+        let right_scope = Scope::from_table(reference, right_columns.clone());
+
+        let mut conditions = vec![];
+        split_conjunction(&join.on, &mut conditions);
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        // maps the right key columns to the left key columns
+        let mut right_keys = vec![];
+        for condition in conditions {
+            match condition {
+                SqlExpr::BinaryOp {
+                    left,
+                    op: Operator::Eq,
+                    right,
+                } => {
+                    let (left, right) = if right_scope.refers_to(left, reference)
+                        && !right_scope.refers_to(right, reference)
+                    {
+                        (right, left)
+                    } else {
+                        (left, right)
+                    };
+                    let left_expr = scope.translate(left)?;
+                    if let (SqlExpr::Column { name, .. }, Expr::Column(left_name)) =
+                        (&**right, &left_expr)
+                    {
+                        right_keys.push((name.clone(), (**left_name).clone()))
+                    }
+                    left_on.push(left_expr);
+                    right_on.push(right_scope.translate(right)?);
+                }
+                _ => return Err(PolarsError::InvalidOperation(
+                    "only equality conditions combined with AND are supported in a JOIN ON clause"
+                        .into(),
+                )),
+            }
+        }
+
+        let mut mapping = Vec::with_capacity(right_columns.len());
+        for name in right_columns {
+            let resolved = match right_keys.iter().find(|(right, _)| right == &name) {
+                Some((_, left)) => left.clone(),
+                None if scope.columns.contains(&name) => format!("{}_right", name),
+                None => name.clone(),
+            };
+            mapping.push((name, resolved))
+        }
+
+        let lf = lf.join(right, left_on, right_on, None, join.how);
+        scope.columns = column_names(&lf);
+        scope.tables.push((reference.to_string(), mapping));
+        Ok(lf)
+    }
+
+    /// Determine the columns to sort by and whether the sort is descending per column.
+    fn sort_keys(
+        &self,
+        query: &Query,
+        projection: &[(SqlExpr, Option<String>)],
+    ) -> Result<Vec<(SortKey, bool)>> {
+        query
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let position = match &order_by.expr {
+                    SqlExpr::Number(n) => match n.parse::<usize>() {
+                        Ok(idx) if idx >= 1 && idx <= projection.len() => Some(idx - 1),
+                        _ => {
+                            return Err(PolarsError::OutOfBounds(
+                                format!("ORDER BY position {} is not in the SELECT list", n).into(),
+                            ))
+                        }
+                    },
+                    SqlExpr::Column { table: None, name } => {
+                        projection.iter().position(|(e, alias)| match alias {
+                            Some(alias) => alias == name,
+                            None => e == &order_by.expr,
+                        })
+                    }
+                    e => projection.iter().position(|(item, _)| item == e),
+                };
+                let key = match (position, &order_by.expr) {
+                    (Some(idx), _) => SortKey::Output(idx),
+                    (None, SqlExpr::Column { table, name }) => SortKey::Input {
+                        table: table.clone(),
+                        name: name.clone(),
+                    },
+                    _ => {
+                        return Err(PolarsError::ValueError(
+                            "the ORDER BY expression should be in the SELECT list".into(),
+                        ))
+                    }
+                };
+                Ok((key, order_by.descending))
+            })
+            .collect()
+    }
+
+    fn aggregate(
+        &self,
+        lf: LazyFrame,
+        query: &Query,
+        scope: &Scope,
+        projection: &[(SqlExpr, Option<String>)],
+    ) -> Result<LazyFrame> {
+        let mut keys = Vec::with_capacity(query.group_by.len());
+        for (i, key) in query.group_by.iter().enumerate() {
+            // a group key can refer to the position or the alias of a select item
+            let key = match key {
+                SqlExpr::Number(n) => n
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|idx| projection.get(idx.wrapping_sub(1)))
+                    .map(|(e, _)| e)
+                    .ok_or_else(|| {
+                        PolarsError::OutOfBounds(
+                            format!("GROUP BY position {} is not in the SELECT list", n).into(),
+                        )
+                    })?,
+                SqlExpr::Column { table: None, name } if scope.resolve(None, name).is_err() => {
+                    projection
+                        .iter()
+                        .find(|(_, alias)| alias.as_ref() == Some(name))
+                        .map(|(e, _)| e)
+                        .unwrap_or(key)
+                }
+                key => key,
+            };
+            if contains_aggregation(key) {
+                return Err(PolarsError::ValueError(
+                    "aggregations are not allowed in a GROUP BY clause".into(),
+                ));
+            }
+            let expr = scope.translate(key)?;
+            let name = match &expr {
+                Expr::Column(name) => (**name).clone(),
+                _ => format!("__key_{}", i),
+            };
+            keys.push((expr, name));
+        }
+
+        let mut aggregation = Aggregation {
+            scope,
+            keys,
+            aggs: vec![],
+        };
+        let projection = projection
+            .iter()
+            .enumerate()
+            .map(|(i, (e, alias))| {
+                let name = alias
+                    .clone()
+                    .or_else(|| default_name(e))
+                    .unwrap_or_else(|| format!("column_{}", i));
+                Ok((aggregation.rewrite(e)?, name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let having = query
+            .having
+            .as_ref()
+            .map(|having| aggregation.rewrite(having))
+            .transpose()?;
+
+        let aggs = aggregation
+            .aggs
+            .iter()
+            .enumerate()
+            .map(|(i, agg)| agg.clone().alias(&Aggregation::agg_name(i)))
+            .collect::<Vec<_>>();
+        let mut lf = if aggregation.keys.is_empty() {
+            lf.select(aggs)
+        } else {
+            let keys = aggregation
+                .keys
+                .iter()
+                .map(|(key, name)| match key {
+                    Expr::Column(_) => key.clone(),
+                    _ => key.clone().alias(name),
+                })
+                .collect();
+            lf.groupby(keys).agg(aggs)
+        };
+
+        let scope = Scope::from_columns(aggregation.columns());
+        if let Some(having) = having {
+            lf = lf.filter(scope.translate(&having)?);
+        }
+        let exprs = projection
+            .iter()
+            .map(|(e, name)| Ok(scope.translate(e)?.alias(name)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(lf.select(exprs))
+    }
+}
+
+/// The column of an ORDER BY clause.
+enum SortKey {
+    /// Index of a column in the output.
+    Output(usize),
+    /// A column that is not selected and is sorted before the projection.
+    Input { table: Option<String>, name: String },
+}
+
+fn column_names(lf: &LazyFrame) -> Vec<String> {
+    lf.logical_plan
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect()
+}
+
+/// Split an expression on `AND` operators.
+fn split_conjunction<'a>(e: &'a SqlExpr, out: &mut Vec<&'a SqlExpr>) {
+    match e {
+        SqlExpr::BinaryOp {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            split_conjunction(left, out);
+            split_conjunction(right, out);
+        }
+        e => out.push(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    fn get_context() -> SQLContext {
+        let sales = df! {
+            "store" => [1, 1, 2, 2, 3, 1],
+            "product" => ["a", "b", "a", "c", "b", "a"],
+            "amount" => [10, 20, 30, 40, 50, 60]
+        }
+        .unwrap();
+        let stores = df! {
+            "store" => [1, 2, 3],
+            "city" => ["Amsterdam", "Berlin", "Paris"]
+        }
+        .unwrap();
+
+        let mut ctx = SQLContext::new();
+        ctx.register("sales", sales.lazy());
+        ctx.register("stores", stores.lazy());
+        ctx
+    }
+
+    #[test]
+    fn test_sql_select_where_order_limit() {
+        let ctx = get_context();
+        let out = ctx
+            .execute(
+                "SELECT product, amount * 2 AS double FROM sales \
+                 WHERE amount BETWEEN 20 AND 50 AND product <> 'c' ORDER BY double DESC LIMIT 2",
+            )
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["product", "double"]);
+        assert_eq!(
+            Vec::from(out.column("double").unwrap().i32().unwrap()),
+            &[Some(100), Some(60)]
+        );
+
+        // sort on a column that is not selected
+        let out = ctx
+            .execute("SELECT product FROM sales ORDER BY amount DESC LIMIT 1")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("product").unwrap().utf8().unwrap()),
+            &[Some("a")]
+        );
+    }
+
+    #[test]
+    fn test_sql_order_by_multiple_columns() {
+        let ctx = get_context();
+        let out = ctx
+            .execute("SELECT store, amount FROM sales ORDER BY store DESC, 2")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("amount").unwrap().i32().unwrap()),
+            &[Some(50), Some(30), Some(40), Some(10), Some(20), Some(60)]
+        );
+
+        // a key that is not selected
+        let out = ctx
+            .execute("SELECT product, store FROM sales ORDER BY product, amount DESC")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("store").unwrap().i32().unwrap()),
+            &[Some(1), Some(2), Some(1), Some(3), Some(1), Some(2)]
+        );
+
+        let out = ctx.execute("SELECT amount * 2 AS double FROM sales ORDER BY double, store");
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn test_sql_groupby_having() {
+        let ctx = get_context();
+        let out = ctx
+            .execute(
+                "SELECT store, SUM(amount) AS total, COUNT(*) AS n FROM sales \
+                 GROUP BY store HAVING COUNT(*) > 1 ORDER BY store",
+            )
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(out.get_column_names(), &["store", "total", "n"]);
+        assert_eq!(
+            Vec::from(out.column("store").unwrap().i32().unwrap()),
+            &[Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("total").unwrap().i32().unwrap()),
+            &[Some(90), Some(70)]
+        );
+        assert_eq!(
+            Vec::from(out.column("n").unwrap().u32().unwrap()),
+            &[Some(3), Some(2)]
+        );
+
+        // aggregation without group keys
+        let out = ctx
+            .execute("SELECT MAX(amount) - MIN(amount) AS range FROM sales")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("range").unwrap().i32().unwrap()),
+            &[Some(50)]
+        );
+
+        // columns should be aggregated or be a group key
+        assert!(ctx
+            .execute("SELECT store, amount FROM sales GROUP BY store")
+            .is_err());
+    }
+
+    #[test]
+    fn test_sql_join() {
+        let ctx = get_context();
+        let out = ctx
+            .execute(
+                "SELECT st.city, SUM(s.amount) AS total FROM sales s \
+                 JOIN stores st ON s.store = st.store \
+                 WHERE s.product = 'a' GROUP BY st.city ORDER BY total DESC",
+            )
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("city").unwrap().utf8().unwrap()),
+            &[Some("Amsterdam"), Some("Berlin")]
+        );
+        assert_eq!(
+            Vec::from(out.column("total").unwrap().i32().unwrap()),
+            &[Some(70), Some(30)]
+        );
+
+        assert!(ctx.execute("SELECT * FROM unknown").is_err());
+    }
+}
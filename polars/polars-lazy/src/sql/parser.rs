@@ -0,0 +1,839 @@
+//! A small SQL parser that parses `SELECT` queries into an abstract syntax tree.
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word { value: String, quoted: bool },
+    Number(String),
+    String(String),
+    Comma,
+    Dot,
+    LParen,
+    RParen,
+    Star,
+    Plus,
+    Minus,
+    Slash,
+    Percent,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    SemiColon,
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>> {
+    let chars = sql.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            // line comment
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1
+                }
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()))
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1
+                }
+                tokens.push(Token::Word {
+                    value: chars[start..i].iter().collect(),
+                    quoted: false,
+                })
+            }
+            '\'' | '"' => {
+                let quote = c;
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        // a doubled quote is an escaped quote
+                        Some(&c) if c == quote && chars.get(i + 1) == Some(&quote) => {
+                            value.push(quote);
+                            i += 2;
+                        }
+                        Some(&c) if c == quote => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&c) => {
+                            value.push(c);
+                            i += 1;
+                        }
+                        None => {
+                            return Err(PolarsError::ValueError(
+                                format!("unterminated quote in sql: {}", sql).into(),
+                            ))
+                        }
+                    }
+                }
+                if quote == '\'' {
+                    tokens.push(Token::String(value));
+                } else {
+                    tokens.push(Token::Word {
+                        value,
+                        quoted: true,
+                    });
+                }
+            }
+            _ => {
+                let next = chars.get(i + 1).copied();
+                let (token, len) = match (c, next) {
+                    ('<', Some('=')) => (Token::LtEq, 2),
+                    ('>', Some('=')) => (Token::GtEq, 2),
+                    ('<', Some('>')) | ('!', Some('=')) => (Token::NotEq, 2),
+                    ('=', Some('=')) => (Token::Eq, 2),
+                    ('<', _) => (Token::Lt, 1),
+                    ('>', _) => (Token::Gt, 1),
+                    ('=', _) => (Token::Eq, 1),
+                    (',', _) => (Token::Comma, 1),
+                    ('.', _) => (Token::Dot, 1),
+                    ('(', _) => (Token::LParen, 1),
+                    (')', _) => (Token::RParen, 1),
+                    ('*', _) => (Token::Star, 1),
+                    ('+', _) => (Token::Plus, 1),
+                    ('-', _) => (Token::Minus, 1),
+                    ('/', _) => (Token::Slash, 1),
+                    ('%', _) => (Token::Percent, 1),
+                    (';', _) => (Token::SemiColon, 1),
+                    _ => {
+                        return Err(PolarsError::ValueError(
+                            format!("unexpected character '{}' in sql: {}", c, sql).into(),
+                        ))
+                    }
+                };
+                tokens.push(token);
+                i += len;
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Keywords that end an expression and therefore cannot be used as an alias without `AS`.
+const RESERVED: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "GROUP", "HAVING", "ORDER", "LIMIT", "OFFSET", "JOIN", "INNER",
+    "LEFT", "FULL", "OUTER", "ON", "AS", "AND", "OR", "NOT", "IS", "IN", "BETWEEN", "CASE", "WHEN",
+    "THEN", "ELSE", "END", "ASC", "DESC", "BY", "DISTINCT", "NULL", "TRUE", "FALSE",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SqlExpr {
+    Column {
+        table: Option<String>,
+        name: String,
+    },
+    /// The `*` in `COUNT(*)`
+    Wildcard,
+    Number(String),
+    String(String),
+    Boolean(bool),
+    Null,
+    BinaryOp {
+        left: Box<SqlExpr>,
+        op: Operator,
+        right: Box<SqlExpr>,
+    },
+    Not(Box<SqlExpr>),
+    Negative(Box<SqlExpr>),
+    IsNull {
+        expr: Box<SqlExpr>,
+        negated: bool,
+    },
+    Between {
+        expr: Box<SqlExpr>,
+        low: Box<SqlExpr>,
+        high: Box<SqlExpr>,
+        negated: bool,
+    },
+    InList {
+        expr: Box<SqlExpr>,
+        list: Vec<SqlExpr>,
+        negated: bool,
+    },
+    /// Function call, the name is upper case.
+    Function {
+        name: String,
+        args: Vec<SqlExpr>,
+        distinct: bool,
+    },
+    Case {
+        operand: Option<Box<SqlExpr>>,
+        conditions: Vec<(SqlExpr, SqlExpr)>,
+        else_result: Option<Box<SqlExpr>>,
+    },
+    Cast {
+        expr: Box<SqlExpr>,
+        data_type: DataType,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SelectItem {
+    Wildcard,
+    QualifiedWildcard(String),
+    Expr {
+        expr: SqlExpr,
+        alias: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TableRef {
+    pub(crate) name: String,
+    pub(crate) alias: Option<String>,
+}
+
+impl TableRef {
+    /// The name that is used to refer to the table in the query.
+    pub(crate) fn reference(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Join {
+    pub(crate) table: TableRef,
+    pub(crate) how: JoinType,
+    pub(crate) on: SqlExpr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OrderByExpr {
+    pub(crate) expr: SqlExpr,
+    pub(crate) descending: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Query {
+    pub(crate) distinct: bool,
+    pub(crate) projection: Vec<SelectItem>,
+    pub(crate) from: TableRef,
+    pub(crate) joins: Vec<Join>,
+    pub(crate) selection: Option<SqlExpr>,
+    pub(crate) group_by: Vec<SqlExpr>,
+    pub(crate) having: Option<SqlExpr>,
+    pub(crate) order_by: Vec<OrderByExpr>,
+    pub(crate) limit: Option<usize>,
+    pub(crate) offset: Option<usize>,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn error<T>(&self, expected: &str) -> Result<T> {
+        let found = match self.peek() {
+            Some(token) => format!("{:?}", token),
+            None => "end of query".to_string(),
+        };
+        Err(PolarsError::ValueError(
+            format!("sql parser error: expected {}, found {}", expected, found).into(),
+        ))
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::Word { value, quoted: false }) if value.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    /// Consume the keyword if it is the next token.
+    fn parse_keyword(&mut self, keyword: &str) -> bool {
+        if self.is_keyword(keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.parse_keyword(keyword) {
+            Ok(())
+        } else {
+            self.error(keyword)
+        }
+    }
+
+    /// Consume the token if it is the next token.
+    fn consume(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if self.consume(token) {
+            Ok(())
+        } else {
+            self.error(&format!("{:?}", token))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::Word { value, quoted }) => {
+                if !quoted && RESERVED.iter().any(|kw| value.eq_ignore_ascii_case(kw)) {
+                    return self.error("identifier");
+                }
+                let value = value.clone();
+                self.pos += 1;
+                Ok(value)
+            }
+            _ => self.error("identifier"),
+        }
+    }
+
+    /// Parse an optional alias: `[AS] alias`.
+    fn parse_alias(&mut self) -> Result<Option<String>> {
+        if self.parse_keyword("AS") {
+            return self.parse_identifier().map(Some);
+        }
+        match self.peek() {
+            Some(Token::Word { value, quoted }) => {
+                if *quoted || !RESERVED.iter().any(|kw| value.eq_ignore_ascii_case(kw)) {
+                    self.parse_identifier().map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_usize(&mut self) -> Result<usize> {
+        match self.next_token() {
+            Some(Token::Number(n)) => n.parse::<usize>().map_err(|_| {
+                PolarsError::ValueError(format!("expected a positive integer, found {}", n).into())
+            }),
+            _ => {
+                self.pos -= 1;
+                self.error("a positive integer")
+            }
+        }
+    }
+
+    fn parse_comma_separated<T, F>(&mut self, mut f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> Result<T>,
+    {
+        let mut out = vec![f(self)?];
+        while self.consume(&Token::Comma) {
+            out.push(f(self)?)
+        }
+        Ok(out)
+    }
+
+    fn parse_query(&mut self) -> Result<Query> {
+        self.expect_keyword("SELECT")?;
+        let distinct = self.parse_keyword("DISTINCT");
+        let projection = self.parse_comma_separated(Self::parse_select_item)?;
+        self.expect_keyword("FROM")?;
+        let from = self.parse_table_ref()?;
+
+        let mut joins = vec![];
+        loop {
+            let how = if self.parse_keyword("JOIN") {
+                JoinType::Inner
+            } else if self.parse_keyword("INNER") {
+                self.expect_keyword("JOIN")?;
+                JoinType::Inner
+            } else if self.parse_keyword("LEFT") {
+                self.parse_keyword("OUTER");
+                self.expect_keyword("JOIN")?;
+                JoinType::Left
+            } else if self.parse_keyword("FULL") {
+                self.parse_keyword("OUTER");
+                self.expect_keyword("JOIN")?;
+                JoinType::Outer
+            } else if self.parse_keyword("OUTER") {
+                self.expect_keyword("JOIN")?;
+                JoinType::Outer
+            } else {
+                break;
+            };
+            let table = self.parse_table_ref()?;
+            self.expect_keyword("ON")?;
+            let on = self.parse_expr()?;
+            joins.push(Join { table, how, on })
+        }
+
+        let selection = if self.parse_keyword("WHERE") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let group_by = if self.parse_keyword("GROUP") {
+            self.expect_keyword("BY")?;
+            self.parse_comma_separated(Self::parse_expr)?
+        } else {
+            vec![]
+        };
+        let having = if self.parse_keyword("HAVING") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let order_by = if self.parse_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            self.parse_comma_separated(|parser| {
+                let expr = parser.parse_expr()?;
+                let descending = if parser.parse_keyword("DESC") {
+                    true
+                } else {
+                    parser.parse_keyword("ASC");
+                    false
+                };
+                Ok(OrderByExpr { expr, descending })
+            })?
+        } else {
+            vec![]
+        };
+        let limit = if self.parse_keyword("LIMIT") {
+            Some(self.parse_usize()?)
+        } else {
+            None
+        };
+        let offset = if self.parse_keyword("OFFSET") {
+            Some(self.parse_usize()?)
+        } else {
+            None
+        };
+        self.consume(&Token::SemiColon);
+        if self.peek().is_some() {
+            return self.error("end of query");
+        }
+
+        Ok(Query {
+            distinct,
+            projection,
+            from,
+            joins,
+            selection,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
+        })
+    }
+
+    fn parse_select_item(&mut self) -> Result<SelectItem> {
+        if self.consume(&Token::Star) {
+            return Ok(SelectItem::Wildcard);
+        }
+        // qualified wildcard: `table.*`
+        if let (Some(Token::Word { value, .. }), Some(Token::Dot), Some(Token::Star)) = (
+            self.tokens.get(self.pos),
+            self.tokens.get(self.pos + 1),
+            self.tokens.get(self.pos + 2),
+        ) {
+            let table = value.clone();
+            self.pos += 3;
+            return Ok(SelectItem::QualifiedWildcard(table));
+        }
+        let expr = self.parse_expr()?;
+        let alias = self.parse_alias()?;
+        Ok(SelectItem::Expr { expr, alias })
+    }
+
+    fn parse_table_ref(&mut self) -> Result<TableRef> {
+        let name = self.parse_identifier()?;
+        let alias = self.parse_alias()?;
+        Ok(TableRef { name, alias })
+    }
+
+    fn parse_expr(&mut self) -> Result<SqlExpr> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_and()?;
+        while self.parse_keyword("OR") {
+            let right = self.parse_and()?;
+            expr = binary(expr, Operator::Or, right);
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_not()?;
+        while self.parse_keyword("AND") {
+            let right = self.parse_not()?;
+            expr = binary(expr, Operator::And, right);
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<SqlExpr> {
+        if self.parse_keyword("NOT") {
+            Ok(SqlExpr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_comparison()
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<SqlExpr> {
+        let expr = self.parse_additive()?;
+
+        if self.parse_keyword("IS") {
+            let negated = self.parse_keyword("NOT");
+            self.expect_keyword("NULL")?;
+            return Ok(SqlExpr::IsNull {
+                expr: Box::new(expr),
+                negated,
+            });
+        }
+        let negated = self.parse_keyword("NOT");
+        if self.parse_keyword("BETWEEN") {
+            let low = self.parse_additive()?;
+            self.expect_keyword("AND")?;
+            let high = self.parse_additive()?;
+            return Ok(SqlExpr::Between {
+                expr: Box::new(expr),
+                low: Box::new(low),
+                high: Box::new(high),
+                negated,
+            });
+        }
+        if self.parse_keyword("IN") {
+            self.expect(&Token::LParen)?;
+            let list = self.parse_comma_separated(Self::parse_expr)?;
+            self.expect(&Token::RParen)?;
+            return Ok(SqlExpr::InList {
+                expr: Box::new(expr),
+                list,
+                negated,
+            });
+        }
+        if negated {
+            return self.error("BETWEEN or IN");
+        }
+
+        let op = match self.peek() {
+            Some(Token::Eq) => Operator::Eq,
+            Some(Token::NotEq) => Operator::NotEq,
+            Some(Token::Lt) => Operator::Lt,
+            Some(Token::LtEq) => Operator::LtEq,
+            Some(Token::Gt) => Operator::Gt,
+            Some(Token::GtEq) => Operator::GtEq,
+            _ => return Ok(expr),
+        };
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        Ok(binary(expr, op, right))
+    }
+
+    fn parse_additive(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => Operator::Plus,
+                Some(Token::Minus) => Operator::Minus,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            expr = binary(expr, op, right);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => Operator::Multiply,
+                Some(Token::Slash) => Operator::Divide,
+                Some(Token::Percent) => Operator::Modulus,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            expr = binary(expr, op, right);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<SqlExpr> {
+        if self.consume(&Token::Minus) {
+            Ok(SqlExpr::Negative(Box::new(self.parse_unary()?)))
+        } else if self.consume(&Token::Plus) {
+            self.parse_unary()
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<SqlExpr> {
+        match self.peek().cloned() {
+            Some(Token::Number(n)) => {
+                self.pos += 1;
+                Ok(SqlExpr::Number(n))
+            }
+            Some(Token::String(s)) => {
+                self.pos += 1;
+                Ok(SqlExpr::String(s))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.parse_expr()?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::Word {
+                value,
+                quoted: false,
+            }) if RESERVED.iter().any(|kw| value.eq_ignore_ascii_case(kw)) => {
+                match value.to_uppercase().as_str() {
+                    "NULL" => {
+                        self.pos += 1;
+                        Ok(SqlExpr::Null)
+                    }
+                    "TRUE" => {
+                        self.pos += 1;
+                        Ok(SqlExpr::Boolean(true))
+                    }
+                    "FALSE" => {
+                        self.pos += 1;
+                        Ok(SqlExpr::Boolean(false))
+                    }
+                    "CASE" => {
+                        self.pos += 1;
+                        self.parse_case()
+                    }
+                    _ => self.error("an expression"),
+                }
+            }
+            Some(Token::Word { value, quoted }) => {
+                self.pos += 1;
+                if !quoted && self.peek() == Some(&Token::LParen) {
+                    self.pos += 1;
+                    let name = value.to_uppercase();
+                    if name == "CAST" {
+                        return self.parse_cast();
+                    }
+                    self.parse_function(name)
+                } else if self.consume(&Token::Dot) {
+                    let name = self.parse_identifier()?;
+                    Ok(SqlExpr::Column {
+                        table: Some(value),
+                        name,
+                    })
+                } else {
+                    Ok(SqlExpr::Column {
+                        table: None,
+                        name: value,
+                    })
+                }
+            }
+            _ => self.error("an expression"),
+        }
+    }
+
+    /// Parse the arguments of a function, the opening parenthesis is already consumed.
+    fn parse_function(&mut self, name: String) -> Result<SqlExpr> {
+        let distinct = self.parse_keyword("DISTINCT");
+        let args = if self.consume(&Token::RParen) {
+            vec![]
+        } else {
+            let args = self.parse_comma_separated(|parser| {
+                if parser.consume(&Token::Star) {
+                    Ok(SqlExpr::Wildcard)
+                } else {
+                    parser.parse_expr()
+                }
+            })?;
+            self.expect(&Token::RParen)?;
+            args
+        };
+        Ok(SqlExpr::Function {
+            name,
+            args,
+            distinct,
+        })
+    }
+
+    /// Parse `CAST(expr AS type)`, the opening parenthesis is already consumed.
+    fn parse_cast(&mut self) -> Result<SqlExpr> {
+        let expr = self.parse_expr()?;
+        self.expect_keyword("AS")?;
+        let type_name = self.parse_identifier()?.to_uppercase();
+        let data_type = match type_name.as_str() {
+            "BOOL" | "BOOLEAN" => DataType::Boolean,
+            "INT" | "INTEGER" => DataType::Int32,
+            "BIGINT" => DataType::Int64,
+            "REAL" => DataType::Float32,
+            "FLOAT" | "DOUBLE" => {
+                self.parse_keyword("PRECISION");
+                DataType::Float64
+            }
+            "VARCHAR" | "TEXT" | "STRING" => DataType::Utf8,
+            _ => {
+                return Err(PolarsError::ValueError(
+                    format!("sql type {} is not supported", type_name).into(),
+                ))
+            }
+        };
+        // ignore the length of types such as VARCHAR(10)
+        if self.consume(&Token::LParen) {
+            self.parse_usize()?;
+            self.expect(&Token::RParen)?;
+        }
+        self.expect(&Token::RParen)?;
+        Ok(SqlExpr::Cast {
+            expr: Box::new(expr),
+            data_type,
+        })
+    }
+
+    /// Parse a `CASE` expression, the `CASE` keyword is already consumed.
+    fn parse_case(&mut self) -> Result<SqlExpr> {
+        let operand = if self.is_keyword("WHEN") {
+            None
+        } else {
+            Some(Box::new(self.parse_expr()?))
+        };
+        let mut conditions = vec![];
+        while self.parse_keyword("WHEN") {
+            let condition = self.parse_expr()?;
+            self.expect_keyword("THEN")?;
+            let result = self.parse_expr()?;
+            conditions.push((condition, result));
+        }
+        if conditions.is_empty() {
+            return self.error("WHEN");
+        }
+        let else_result = if self.parse_keyword("ELSE") {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        self.expect_keyword("END")?;
+        Ok(SqlExpr::Case {
+            operand,
+            conditions,
+            else_result,
+        })
+    }
+}
+
+fn binary(left: SqlExpr, op: Operator, right: SqlExpr) -> SqlExpr {
+    SqlExpr::BinaryOp {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    }
+}
+
+/// Parse a SQL `SELECT` query.
+pub(crate) fn parse_sql(sql: &str) -> Result<Query> {
+    let tokens = tokenize(sql)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    parser.parse_query()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_sql() {
+        let query = parse_sql(
+            "SELECT a, SUM(b) AS total FROM df WHERE c > 1 AND d IS NOT NULL \
+             GROUP BY a HAVING COUNT(*) >= 2 ORDER BY total DESC LIMIT 10",
+        )
+        .unwrap();
+        assert_eq!(query.projection.len(), 2);
+        assert_eq!(
+            query.projection[1],
+            SelectItem::Expr {
+                expr: SqlExpr::Function {
+                    name: "SUM".into(),
+                    args: vec![SqlExpr::Column {
+                        table: None,
+                        name: "b".into()
+                    }],
+                    distinct: false
+                },
+                alias: Some("total".into())
+            }
+        );
+        assert_eq!(query.from.name, "df");
+        assert!(matches!(
+            query.selection,
+            Some(SqlExpr::BinaryOp {
+                op: Operator::And,
+                ..
+            })
+        ));
+        assert_eq!(query.group_by.len(), 1);
+        assert!(query.having.is_some());
+        assert!(query.order_by[0].descending);
+        assert_eq!(query.limit, Some(10));
+
+        // operator precedence
+        let query = parse_sql("SELECT 1 + 2 * 3 FROM df").unwrap();
+        match &query.projection[0] {
+            SelectItem::Expr {
+                expr: SqlExpr::BinaryOp { op, right, .. },
+                ..
+            } => {
+                assert_eq!(*op, Operator::Plus);
+                assert!(matches!(
+                    **right,
+                    SqlExpr::BinaryOp {
+                        op: Operator::Multiply,
+                        ..
+                    }
+                ));
+            }
+            _ => panic!(),
+        }
+
+        let query =
+            parse_sql("SELECT f.*, d.name FROM facts f LEFT JOIN dim AS d ON f.id = d.id").unwrap();
+        assert_eq!(
+            query.projection[0],
+            SelectItem::QualifiedWildcard("f".into())
+        );
+        assert_eq!(query.from.reference(), "f");
+        assert_eq!(query.joins[0].how, JoinType::Left);
+        assert_eq!(query.joins[0].table.reference(), "d");
+
+        assert!(parse_sql("SELECT a FROM").is_err());
+        assert!(parse_sql("SELECT a FROM df WHERE").is_err());
+        assert!(parse_sql("SELECT 'a FROM df").is_err());
+    }
+}
@@ -232,6 +232,11 @@ pub(crate) fn agg_source_paths(
         HStack { input, .. } => {
             agg_source_paths(*input, paths, lp_arena);
         }
+        Union { inputs, .. } => {
+            for input in inputs {
+                agg_source_paths(*input, paths, lp_arena);
+            }
+        }
         Melt { input, .. } => {
             agg_source_paths(*input, paths, lp_arena);
         }
@@ -2,6 +2,7 @@ try:
     from .polars import (  # noqa: F401
         PyDataFrame,
         PySeries,
+        PyStringCacheHolder,
         toggle_string_cache as pytoggle_string_cache,
         version,
     )
@@ -2084,18 +2085,20 @@ class StringCache:
     """
     Context manager that allows to data sources to share the same categorical features.
     This will temporarily cache the string categories until the context manager is finished.
+    Context managers can be nested; the cache is cleared when the outermost one is finished.
 
     """
 
     def __init__(self):
-        pass
+        self._holder = None
 
     def __enter__(self):
-        pytoggle_string_cache(True)
+        self._holder = PyStringCacheHolder()
         return self
 
     def __exit__(self, exc_type, exc_val, exc_tb):
-        pytoggle_string_cache(False)
+        self._holder.release()
+        self._holder = None
 
 
 def toggle_string_cache(toggle: bool):
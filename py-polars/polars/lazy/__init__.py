@@ -935,7 +935,11 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.list())
 
-    def over(self, expr: "Union[str, Expr]") -> "Expr":
+    def over(
+        self,
+        expr: "Union[str, Expr, List[Union[str, Expr]]]",
+        order_by: "Optional[Union[str, Expr]]" = None,
+    ) -> "Expr":
         """
         Apply window function over a subgroup.
         This is similar to a groupby + aggregation + self join.
@@ -944,7 +948,11 @@ class Expr:
         Parameters
         ----------
         expr
-            Expression that evaluates to a column of groups
+            Expression(s) that evaluate to the columns of groups
+        order_by
+            Sort the rows in every group by this column before the window function is
+            applied. This determines the result of order dependent expressions, such as
+            `cum_sum`, `shift`, `first` and `last`.
 
         Examples
         --------
@@ -993,10 +1001,13 @@ class Expr:
 
         ```
         """
-        if isinstance(expr, str):
-            expr = col(expr)
+        pyexprs = _selection_to_pyexpr_list(expr)
+        if isinstance(order_by, str):
+            order_by = col(order_by)
+        if order_by is not None:
+            order_by = order_by._pyexpr
 
-        return wrap_expr(self._pyexpr.over(expr._pyexpr))
+        return wrap_expr(self._pyexpr.over(pyexprs, order_by))
 
     def is_unique(self) -> "Expr":
         """
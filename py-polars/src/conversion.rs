@@ -3,7 +3,7 @@ use crate::series::PySeries;
 use polars::prelude::AnyValue;
 use pyo3::conversion::{FromPyObject, IntoPy};
 use pyo3::prelude::*;
-use pyo3::types::PySequence;
+use pyo3::types::{PyBytes, PyDict, PySequence};
 use pyo3::{PyAny, PyResult};
 
 pub struct Wrap<T>(pub T);
@@ -102,6 +102,21 @@ impl IntoPy<PyObject> for Wrap<AnyValue<'_>> {
                 python_series_wrapper.into()
             }
             AnyValue::Object(v) => v.into_py(py),
+            AnyValue::Struct(values, fields) => {
+                let dict = PyDict::new(py);
+                for (v, fld) in values.into_iter().zip(fields) {
+                    dict.set_item(fld.name(), Wrap(v).into_py(py)).unwrap();
+                }
+                dict.into_py(py)
+            }
+            av @ AnyValue::Decimal(_, _) => {
+                let decimal = PyModule::import(py, "decimal").expect("stdlib decimal");
+                decimal
+                    .call1("Decimal", (format!("{}", av),))
+                    .unwrap()
+                    .into()
+            }
+            AnyValue::Binary(v) => PyBytes::new(py, v).into_py(py),
         }
     }
 }
@@ -216,6 +216,7 @@ impl PyLazyFrame {
         let options = JoinOptions {
             allow_parallel,
             force_parallel,
+            ..Default::default()
         };
         let left_on = left_on.into_iter().map(|pyexpr| pyexpr.inner).collect();
         let right_on = right_on.into_iter().map(|pyexpr| pyexpr.inner).collect();
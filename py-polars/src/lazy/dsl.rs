@@ -1,3 +1,4 @@
+use crate::lazy::utils::py_exprs_to_exprs;
 use crate::series::PySeries;
 use crate::utils::str_to_polarstype;
 use polars::lazy::dsl;
@@ -199,8 +200,16 @@ impl PyExpr {
         self.clone().inner.is_duplicated().into()
     }
 
-    pub fn over(&self, partition_by: PyExpr) -> PyExpr {
-        self.clone().inner.over(partition_by.inner).into()
+    pub fn over(&self, partition_by: Vec<PyExpr>, order_by: Option<PyExpr>) -> PyExpr {
+        let partition_by = py_exprs_to_exprs(partition_by);
+        match order_by {
+            Some(order_by) => self
+                .clone()
+                .inner
+                .over_ordered(partition_by, order_by.inner)
+                .into(),
+            None => self.clone().inner.over_multi(partition_by).into(),
+        }
     }
 
     pub fn _and(&self, expr: PyExpr) -> PyExpr {
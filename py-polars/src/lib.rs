@@ -93,6 +93,26 @@ fn toggle_string_cache(toggle: bool) {
     polars::toggle_string_cache(toggle)
 }
 
+/// Keeps the global string cache alive until `release` is called or it is garbage collected.
+#[pyclass]
+struct PyStringCacheHolder {
+    inner: Option<polars::StringCacheHolder>,
+}
+
+#[pymethods]
+impl PyStringCacheHolder {
+    #[new]
+    fn new() -> Self {
+        PyStringCacheHolder {
+            inner: Some(polars::StringCacheHolder::hold()),
+        }
+    }
+
+    fn release(&mut self) {
+        self.inner = None;
+    }
+}
+
 #[pymodule]
 fn polars(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PySeries>().unwrap();
@@ -100,6 +120,7 @@ fn polars(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyLazyFrame>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
     m.add_class::<dsl::PyExpr>().unwrap();
+    m.add_class::<PyStringCacheHolder>().unwrap();
     m.add_wrapped(wrap_pyfunction!(col)).unwrap();
     m.add_wrapped(wrap_pyfunction!(lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(binary_expr)).unwrap();
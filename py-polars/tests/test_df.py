@@ -453,6 +453,17 @@ def test_read_csv_categorical():
     assert df["col1"].dtype == pl.Categorical
 
 
+def test_string_cache_join():
+    with pl.StringCache():
+        with pl.StringCache():
+            a = pl.DataFrame({"key": ["a", "b"], "x": [1, 2]})
+            a["key"] = a["key"].cast(pl.Categorical)
+        b = pl.DataFrame({"key": ["b", "a"], "y": [3, 4]})
+        b["key"] = b["key"].cast(pl.Categorical)
+        out = a.join(b, on="key")
+    assert out["y"].to_list() == [4, 3]
+
+
 def test_df_apply():
     df = pl.DataFrame({"a": ["foo", "bar", "2"], "b": [1, 2, 3], "c": [1.0, 2.0, 3.0]})
     out = df.apply(lambda x: len(x), None)
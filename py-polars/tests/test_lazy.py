@@ -148,3 +148,26 @@ def test_arange():
     result = df.filter(pl.lazy.col("a") >= pl.lazy.arange(0, 3)).collect()
     expected = pl.DataFrame({"a": [1, 1]})
     assert result.frame_equal(expected)
+
+
+def test_window_multi_key_and_order():
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, 2, 1],
+            "b": [1, 1, 1, 2],
+            "t": [2, 1, 1, 1],
+            "v": [10, 20, 30, 40],
+        }
+    )
+    out = (
+        df.lazy()
+        .select(
+            [
+                col("v").sum().over(["a", "b"]).alias("sum"),
+                col("v").cum_sum(False).over(["a", "b"], order_by="t").alias("cum"),
+            ]
+        )
+        .collect()
+    )
+    assert out["sum"].to_list() == [30, 30, 30, 40]
+    assert out["cum"].to_list() == [30, 20, 30, 40]